@@ -0,0 +1,76 @@
+use crate::{days_in_month, DateTime, TimeDelta, Weekday};
+
+// timekit zones use fixed UTC offsets, so every local wall time, including midnight, exists
+// exactly once; the helpers below never need to skip or repeat a local time.
+impl DateTime {
+    /// Returns 00:00:00 of the same local day.
+    pub fn start_of_day(&self) -> Result<Self, String> {
+        DateTime::new(self.year, self.month, self.day, 0, 0, 0, self.timezone)
+    }
+
+    /// Returns 23:59:59 of the same local day.
+    pub fn end_of_day(&self) -> Result<Self, String> {
+        DateTime::new(self.year, self.month, self.day, 23, 59, 59, self.timezone)
+    }
+
+    /// Returns 00:00:00 of the most recent `first_weekday`, which is today if today is
+    /// `first_weekday`.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{DateTime, TimeZone, Weekday};
+    /// let friday = DateTime::new(2024, 5, 17, 15, 0, 0, TimeZone::KST).unwrap();
+    /// let week = friday.start_of_week(Weekday::Sunday).unwrap();
+    /// assert_eq!(week.to_string(), "2024-05-12 00:00:00");
+    /// ```
+    pub fn start_of_week(&self, first_weekday: Weekday) -> Result<Self, String> {
+        let days = self.weekday().days_since(first_weekday) as i64;
+        self.start_of_day()?.sub_timedelta(TimeDelta {
+            days,
+            ..Default::default()
+        })
+    }
+
+    /// Returns 23:59:59 of the last day of the week that starts on `first_weekday`.
+    pub fn end_of_week(&self, first_weekday: Weekday) -> Result<Self, String> {
+        let days = 6 - self.weekday().days_since(first_weekday) as i64;
+        self.end_of_day()?.add_timedelta(TimeDelta {
+            days,
+            ..Default::default()
+        })
+    }
+
+    /// Returns 00:00:00 of the first day of the local month.
+    pub fn start_of_month(&self) -> Result<Self, String> {
+        DateTime::new(self.year, self.month, 1, 0, 0, 0, self.timezone)
+    }
+
+    /// Returns 23:59:59 of the last day of the local month.
+    pub fn end_of_month(&self) -> Result<Self, String> {
+        let last_day = days_in_month(self.month, self.year);
+        DateTime::new(self.year, self.month, last_day, 23, 59, 59, self.timezone)
+    }
+
+    /// Returns 00:00:00 of the first day of the local calendar quarter.
+    pub fn start_of_quarter(&self) -> Result<Self, String> {
+        let first_month = (self.month - 1) / 3 * 3 + 1;
+        DateTime::new(self.year, first_month, 1, 0, 0, 0, self.timezone)
+    }
+
+    /// Returns 23:59:59 of the last day of the local calendar quarter.
+    pub fn end_of_quarter(&self) -> Result<Self, String> {
+        let last_month = (self.month - 1) / 3 * 3 + 3;
+        let last_day = days_in_month(last_month, self.year);
+        DateTime::new(self.year, last_month, last_day, 23, 59, 59, self.timezone)
+    }
+
+    /// Returns 00:00:00 of January 1st of the local year.
+    pub fn start_of_year(&self) -> Result<Self, String> {
+        DateTime::new(self.year, 1, 1, 0, 0, 0, self.timezone)
+    }
+
+    /// Returns 23:59:59 of December 31st of the local year.
+    pub fn end_of_year(&self) -> Result<Self, String> {
+        DateTime::new(self.year, 12, 31, 23, 59, 59, self.timezone)
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{Date, Period, RangeBound, Step, Weekday};
+use std::collections::BTreeSet;
+
+/// A calendar of business days: every day that is neither a weekend day nor a holiday.
+///
+/// Methods that move between days accept both `Date` and `DateTime`; a `DateTime` keeps its
+/// local time of day and zone, and only its local date is checked against the calendar.
+///
+/// # Example:
+/// ```
+/// use timekit::{BusinessCalendar, Date};
+/// let date = |day| Date::new(2024, 5, day).unwrap();
+/// // Children's Day (May 5th) fell on a Sunday in 2024, so Monday the 6th was a holiday
+/// let calendar = BusinessCalendar::default().with_holidays([date(6), date(15)]);
+///
+/// let friday = date(3);
+/// assert!(!calendar.is_business_day(date(6)));
+/// assert_eq!(calendar.next_business_day(friday).unwrap(), date(7));
+/// assert_eq!(calendar.add_business_days(friday, 3).unwrap(), date(9));
+/// assert_eq!(calendar.business_days_between(date(1), date(31)), 20);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusinessCalendar {
+    weekend: Vec<Weekday>,
+    holidays: BTreeSet<Date>,
+}
+
+impl BusinessCalendar {
+    /// Creates a calendar with the given weekend days and no holidays.
+    pub fn new(weekend: &[Weekday]) -> Self {
+        let mut weekend = weekend.to_vec();
+        weekend.sort();
+        weekend.dedup();
+        Self {
+            weekend,
+            holidays: BTreeSet::new(),
+        }
+    }
+
+    /// Creates a calendar with a Saturday and Sunday weekend.
+    pub fn saturday_sunday() -> Self {
+        Self::new(&[Weekday::Saturday, Weekday::Sunday])
+    }
+
+    /// Creates a calendar with a Friday and Saturday weekend.
+    pub fn friday_saturday() -> Self {
+        Self::new(&[Weekday::Friday, Weekday::Saturday])
+    }
+
+    /// Returns the calendar with the given holidays added.
+    pub fn with_holidays<I: IntoIterator<Item = Date>>(mut self, holidays: I) -> Self {
+        self.holidays.extend(holidays);
+        self
+    }
+
+    /// Adds a holiday.
+    pub fn add_holiday(&mut self, date: Date) {
+        self.holidays.insert(date);
+    }
+
+    /// Returns the weekend days in order from Monday.
+    pub fn weekend(&self) -> &[Weekday] {
+        &self.weekend
+    }
+
+    /// Returns the holidays in chronological order.
+    pub fn holidays(&self) -> impl Iterator<Item = &Date> {
+        self.holidays.iter()
+    }
+
+    /// Returns `true` if the date falls on a weekend day.
+    pub fn is_weekend<T: Into<Date>>(&self, date: T) -> bool {
+        self.weekend.contains(&date.into().weekday())
+    }
+
+    /// Returns `true` if the date is one of the calendar's holidays.
+    pub fn is_holiday<T: Into<Date>>(&self, date: T) -> bool {
+        self.holidays.contains(&date.into())
+    }
+
+    /// Returns `true` if the date is neither a weekend day nor a holiday.
+    pub fn is_business_day<T: Into<Date>>(&self, date: T) -> bool {
+        let date = date.into();
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+
+    /// Returns the first business day strictly after `value`.
+    pub fn next_business_day<T: RangeBound + Into<Date>>(&self, value: T) -> Result<T, String> {
+        self.add_business_days(value, 1)
+    }
+
+    /// Returns the last business day strictly before `value`.
+    pub fn previous_business_day<T: RangeBound + Into<Date>>(&self, value: T) -> Result<T, String> {
+        self.add_business_days(value, -1)
+    }
+
+    /// Moves `days` business days forwards, or backwards when `days` is negative.
+    ///
+    /// Starting from a non-business day, the first step lands on the nearest business day in
+    /// that direction. Moving zero days returns `value` unchanged.
+    pub fn add_business_days<T: RangeBound + Into<Date>>(
+        &self,
+        value: T,
+        days: i64,
+    ) -> Result<T, String> {
+        if days != 0 && self.weekend.len() >= 7 {
+            return Err("Calendar has no business days".to_string());
+        }
+        let start: Date = value.into();
+        let direction = days.signum();
+        let mut date = start;
+        for _ in 0..days.unsigned_abs() {
+            date = date.add_days(direction)?;
+            while !self.is_business_day(date) {
+                date = date.add_days(direction)?;
+            }
+        }
+        let offset = date.days_since_epoch() - start.days_since_epoch();
+        value.step_by(&Step::Period(Period::days(offset)), 1)
+    }
+
+    /// Counts the business days from `start` up to, but not including, `end`. The count is
+    /// negative when `end` is before `start`.
+    pub fn business_days_between<T: Into<Date>>(&self, start: T, end: T) -> i64 {
+        let (start, end) = (start.into(), end.into());
+        if end < start {
+            return -self.business_days_between(end, start);
+        }
+        let days = end.days_since_epoch() - start.days_since_epoch();
+        let workdays_per_week = 7 - self.weekend.len() as i64;
+        let mut count = days / 7 * workdays_per_week;
+        // The remaining days after whole weeks.
+        let mut date = start.add_days(days / 7 * 7).unwrap_or(end);
+        while date < end {
+            if !self.is_weekend(date) {
+                count += 1;
+            }
+            date = match date.add_days(1) {
+                Ok(next) => next,
+                Err(_) => break,
+            };
+        }
+        let holidays = self
+            .holidays
+            .range(start..end)
+            .filter(|holiday| !self.is_weekend(**holiday))
+            .count();
+        count - holidays as i64
+    }
+
+    /// Combines two calendars so that a day is a non-business day if it is one in either
+    /// calendar, as needed for dealings that involve both markets.
+    pub fn union(&self, other: &BusinessCalendar) -> BusinessCalendar {
+        let mut weekend = self.weekend.clone();
+        weekend.extend(&other.weekend);
+        Self::new(&weekend).with_holidays(self.holidays.union(&other.holidays).copied())
+    }
+
+    /// Combines two calendars so that a day is a non-business day only if it is one in both
+    /// calendars.
+    pub fn intersection(&self, other: &BusinessCalendar) -> BusinessCalendar {
+        let weekend: Vec<Weekday> = self
+            .weekend
+            .iter()
+            .filter(|weekday| other.weekend.contains(weekday))
+            .copied()
+            .collect();
+        let holidays = self
+            .holidays
+            .iter()
+            .filter(|date| !other.is_business_day(**date))
+            .chain(
+                other
+                    .holidays
+                    .iter()
+                    .filter(|date| !self.is_business_day(**date)),
+            )
+            .copied();
+        Self::new(&weekend).with_holidays(holidays)
+    }
+}
+
+impl Default for BusinessCalendar {
+    /// A calendar with a Saturday and Sunday weekend and no holidays.
+    fn default() -> Self {
+        Self::saturday_sunday()
+    }
+}
@@ -29,3 +29,11 @@ pub const SECONDS_IN_WEEK: i64 = 604_800; //7 days to seconds
 pub const SECONDS_IN_DAY: i64 = 86_400;
 pub const SECONDS_IN_HOUR: i64 = 3_600;
 pub const SECONDS_IN_MINUTE: i64 = 60;
+pub const NANOSECONDS_IN_SECOND: i64 = 1_000_000_000;
+
+// Astronomical time scales
+pub const TT_MINUS_TAI: f64 = 32.184; // Terrestrial Time is TAI + 32.184 s
+pub const TAI_MINUS_GPS: i64 = 19; // GPS time runs 19 s behind TAI
+pub const GPS_EPOCH_UNIX: i64 = 315_964_800; // 1980-01-06 00:00:00 UTC
+pub const UNIX_EPOCH_JULIAN_DATE: f64 = 2_440_587.5; // Julian Date of 1970-01-01 00:00:00
+pub const MJD_OFFSET: f64 = 2_400_000.5; // MJD = JD - 2400000.5
@@ -0,0 +1,478 @@
+use crate::constants::*;
+use crate::{days_in_month, Date, DateTime, TimeZone, Weekday};
+use std::fmt;
+use std::iter::FusedIterator;
+use std::str::FromStr;
+
+// Searches give up after this year (forwards) or before 1970 (backwards).
+const LAST_YEAR: u64 = 9999;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+// A day-of-month entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MonthDay {
+    // `15`
+    Day(u64),
+    // `L` or `L-3`: the last day of the month, minus an offset.
+    Last(u64),
+    // `15W`: the weekday (Monday to Friday) nearest to the given day, within the month.
+    NearestWeekday(u64),
+    // `LW`: the last weekday of the month.
+    LastWeekday,
+}
+
+// A day-of-week entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WeekDay {
+    // `FRI` or `5`
+    Every(Weekday),
+    // `5#3`: the n-th such weekday of the month.
+    Nth(Weekday, i64),
+    // `5L`: the last such weekday of the month.
+    Last(Weekday),
+}
+
+/// A cron schedule, evaluated on the wall clock of a `TimeZone`.
+///
+/// Expressions have five fields (`minute hour day-of-month month day-of-week`) or six, with
+/// seconds first. Fields accept `*`, lists (`1,15`), ranges (`MON-FRI`), steps (`*/15`,
+/// `10-50/20`) and month and weekday names; `?` is the same as `*`. Day-of-month also accepts
+/// `L` (last day), `L-2` (two days before the last day), `15W` (weekday nearest the 15th) and
+/// `LW` (last weekday), and day-of-week accepts `5L` (last Friday) and `5#3` (third Friday).
+/// Sunday is `0` or `7`. The macros `@yearly`, `@annually`, `@monthly`, `@weekly`, `@daily`,
+/// `@midnight` and `@hourly` are also recognized.
+///
+/// As in Vixie cron, when both day fields are restricted a day matches if either matches;
+/// a field that starts with `*` counts as unrestricted.
+///
+/// timekit zones have fixed offsets and no daylight saving time, so every local time exists
+/// exactly once: a job at 02:30 runs once every day, and no run is skipped or repeated.
+///
+/// # Example:
+/// ```
+/// use timekit::{CronSchedule, DateTime, TimeZone};
+/// let schedule = CronSchedule::new("30 9 * * MON-FRI", TimeZone::KST).unwrap();
+/// let friday = DateTime::new(2024, 5, 17, 10, 0, 0, TimeZone::KST).unwrap();
+///
+/// let next = schedule.next_after(&friday).unwrap();
+/// assert_eq!(next.to_string(), "2024-05-20 09:30:00"); // the following Monday
+/// let previous = schedule.prev_before(&friday).unwrap();
+/// assert_eq!(previous.to_string(), "2024-05-17 09:30:00");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    expression: String,
+    timezone: TimeZone,
+    seconds: Vec<u64>,
+    minutes: Vec<u64>,
+    hours: Vec<u64>,
+    month_days: Vec<MonthDay>,
+    months: Vec<u64>,
+    week_days: Vec<WeekDay>,
+    any_month_day: bool,
+    any_week_day: bool,
+}
+
+impl CronSchedule {
+    /// Parses a cron expression that is evaluated in `timezone`.
+    pub fn new(expression: &str, timezone: TimeZone) -> Result<Self, String> {
+        let expression = expression.split_whitespace().collect::<Vec<_>>().join(" ");
+        let expanded = match expression.to_ascii_lowercase().as_str() {
+            "@yearly" | "@annually" => "0 0 0 1 1 *",
+            "@monthly" => "0 0 0 1 * *",
+            "@weekly" => "0 0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 0 * * *",
+            "@hourly" => "0 0 * * * *",
+            macro_name if macro_name.starts_with('@') => {
+                return Err(format!("Unknown cron macro: {}", expression))
+            }
+            _ => expression.as_str(),
+        };
+        let fields: Vec<&str> = expanded.split(' ').collect();
+        let (second, rest) = match fields.len() {
+            5 => ("0", fields.as_slice()),
+            6 => (fields[0], &fields[1..]),
+            _ => return Err("Cron expression must have 5 or 6 fields".to_string()),
+        };
+        let (minute, hour, month_day, month, week_day) =
+            (rest[0], rest[1], rest[2], rest[3], rest[4]);
+
+        Ok(Self {
+            seconds: parse_field(second, 0, 59, &[])?,
+            minutes: parse_field(minute, 0, 59, &[])?,
+            hours: parse_field(hour, 0, 23, &[])?,
+            month_days: parse_month_days(month_day)?,
+            months: parse_field(month, 1, 12, &MONTH_NAMES)?,
+            week_days: parse_week_days(week_day)?,
+            any_month_day: is_unrestricted(month_day),
+            any_week_day: is_unrestricted(week_day),
+            expression,
+            timezone,
+        })
+    }
+
+    /// Returns the zone whose wall clock the schedule follows.
+    pub fn timezone(&self) -> TimeZone {
+        self.timezone
+    }
+
+    /// Returns the same schedule evaluated in another zone.
+    pub fn with_timezone(&self, timezone: TimeZone) -> Self {
+        Self {
+            timezone,
+            ..self.clone()
+        }
+    }
+
+    /// Returns the first fire time strictly after `datetime`, in the schedule's zone, or `None`
+    /// if the schedule never fires again before the year 10000.
+    pub fn next_after(&self, datetime: &DateTime) -> Option<DateTime> {
+        let local = datetime.to_unix_seconds() + self.timezone.offset_in_seconds() + 1;
+        let mut date = Date::from_days_since_epoch(local.div_euclid(SECONDS_IN_DAY)).ok()?;
+        let mut from = local.rem_euclid(SECONDS_IN_DAY);
+        while date.year <= LAST_YEAR {
+            if !self.months.contains(&date.month) {
+                date = first_of_next_month(date);
+                from = 0;
+                continue;
+            }
+            if self.matches_day(date) {
+                if let Some(time) = self.first_time_from(from) {
+                    return self.at(date, time);
+                }
+            }
+            date = date.add_days(1).ok()?;
+            from = 0;
+        }
+        None
+    }
+
+    /// Returns the last fire time strictly before `datetime`, in the schedule's zone, or `None`
+    /// if there is none since 1970.
+    pub fn prev_before(&self, datetime: &DateTime) -> Option<DateTime> {
+        let local = datetime.to_unix_seconds() + self.timezone.offset_in_seconds() - 1;
+        let mut date = Date::from_days_since_epoch(local.div_euclid(SECONDS_IN_DAY)).ok()?;
+        let mut until = local.rem_euclid(SECONDS_IN_DAY);
+        while date.year >= 1970 {
+            if !self.months.contains(&date.month) {
+                date = Date::new(date.year, date.month, 1)
+                    .ok()?
+                    .add_days(-1)
+                    .ok()?;
+                until = SECONDS_IN_DAY - 1;
+                continue;
+            }
+            if self.matches_day(date) {
+                if let Some(time) = self.last_time_until(until) {
+                    return self.at(date, time);
+                }
+            }
+            date = date.add_days(-1).ok()?;
+            until = SECONDS_IN_DAY - 1;
+        }
+        None
+    }
+
+    /// Returns the fire times strictly after `datetime`, in chronological order.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{CronSchedule, DateTime, TimeZone};
+    /// let schedule: CronSchedule = "0 0 L * *".parse().unwrap();
+    /// let start = DateTime::new(2024, 1, 15, 0, 0, 0, TimeZone::UTC).unwrap();
+    /// let month_ends: Vec<String> = schedule
+    ///     .upcoming(start)
+    ///     .take(2)
+    ///     .map(|datetime| datetime.to_string())
+    ///     .collect();
+    /// assert_eq!(month_ends, ["2024-01-31 00:00:00", "2024-02-29 00:00:00"]);
+    /// ```
+    pub fn upcoming(&self, datetime: DateTime) -> Upcoming<'_> {
+        Upcoming {
+            schedule: self,
+            current: Some(datetime),
+        }
+    }
+
+    /// Returns `true` if the schedule fires at `datetime`.
+    pub fn matches(&self, datetime: &DateTime) -> bool {
+        let local = datetime.to_unix_seconds() + self.timezone.offset_in_seconds();
+        let Ok(date) = Date::from_days_since_epoch(local.div_euclid(SECONDS_IN_DAY)) else {
+            return false;
+        };
+        let time = local.rem_euclid(SECONDS_IN_DAY);
+        self.months.contains(&date.month)
+            && self.matches_day(date)
+            && self.first_time_from(time) == Some(time)
+    }
+
+    fn matches_day(&self, date: Date) -> bool {
+        let month_day = || {
+            self.month_days
+                .iter()
+                .any(|entry| month_day_matches(*entry, date))
+        };
+        let week_day = || {
+            self.week_days
+                .iter()
+                .any(|entry| week_day_matches(*entry, date))
+        };
+        match (self.any_month_day, self.any_week_day) {
+            (true, true) => true,
+            (false, true) => month_day(),
+            (true, false) => week_day(),
+            (false, false) => month_day() || week_day(),
+        }
+    }
+
+    // Returns the first fire time of a matching day at or after `from` seconds after midnight.
+    fn first_time_from(&self, from: i64) -> Option<i64> {
+        self.times().find(|&time| time >= from)
+    }
+
+    // Returns the last fire time of a matching day at or before `until` seconds after midnight.
+    fn last_time_until(&self, until: i64) -> Option<i64> {
+        self.times().filter(|&time| time <= until).last()
+    }
+
+    // Returns the fire times of a matching day in seconds after midnight, in order.
+    fn times(&self) -> impl Iterator<Item = i64> + '_ {
+        self.hours.iter().flat_map(move |&hour| {
+            self.minutes.iter().flat_map(move |&minute| {
+                self.seconds.iter().map(move |&second| {
+                    hour as i64 * SECONDS_IN_HOUR
+                        + minute as i64 * SECONDS_IN_MINUTE
+                        + second as i64
+                })
+            })
+        })
+    }
+
+    fn at(&self, date: Date, time: i64) -> Option<DateTime> {
+        date.and_hms(
+            (time / SECONDS_IN_HOUR) as u64,
+            (time % SECONDS_IN_HOUR / SECONDS_IN_MINUTE) as u64,
+            (time % SECONDS_IN_MINUTE) as u64,
+            self.timezone,
+        )
+        .ok()
+    }
+}
+
+impl fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = String;
+
+    /// Parses a cron expression evaluated in UTC.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s, TimeZone::UTC)
+    }
+}
+
+/// The upcoming fire times of a `CronSchedule`, returned by `CronSchedule::upcoming`.
+#[derive(Debug, Clone)]
+pub struct Upcoming<'a> {
+    schedule: &'a CronSchedule,
+    current: Option<DateTime>,
+}
+
+impl Iterator for Upcoming<'_> {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<DateTime> {
+        let next = self.schedule.next_after(&self.current?);
+        self.current = next;
+        next
+    }
+}
+
+impl FusedIterator for Upcoming<'_> {}
+
+fn month_day_matches(entry: MonthDay, date: Date) -> bool {
+    let last_day = days_in_month(date.month, date.year);
+    let day = match entry {
+        MonthDay::Day(day) => day,
+        MonthDay::Last(offset) => match last_day.checked_sub(offset) {
+            Some(day) if day >= 1 => day,
+            _ => return false,
+        },
+        MonthDay::NearestWeekday(day) => {
+            if day > last_day {
+                return false;
+            }
+            let target = Date { day, ..date };
+            match target.weekday() {
+                Weekday::Saturday if day == 1 => 3,
+                Weekday::Saturday => day - 1,
+                Weekday::Sunday if day == last_day => day - 2,
+                Weekday::Sunday => day + 1,
+                _ => day,
+            }
+        }
+        MonthDay::LastWeekday => {
+            let last = Date {
+                day: last_day,
+                ..date
+            };
+            match last.weekday() {
+                Weekday::Saturday => last_day - 1,
+                Weekday::Sunday => last_day - 2,
+                _ => last_day,
+            }
+        }
+    };
+    date.day == day
+}
+
+fn week_day_matches(entry: WeekDay, date: Date) -> bool {
+    match entry {
+        WeekDay::Every(weekday) => date.weekday() == weekday,
+        WeekDay::Nth(weekday, n) => {
+            Date::nth_weekday_of_month(date.year, date.month, weekday, n).ok() == Some(date)
+        }
+        WeekDay::Last(weekday) => {
+            Date::nth_weekday_of_month(date.year, date.month, weekday, -1).ok() == Some(date)
+        }
+    }
+}
+
+fn first_of_next_month(date: Date) -> Date {
+    if date.month == 12 {
+        Date {
+            year: date.year + 1,
+            month: 1,
+            day: 1,
+        }
+    } else {
+        Date {
+            month: date.month + 1,
+            day: 1,
+            ..date
+        }
+    }
+}
+
+fn is_unrestricted(field: &str) -> bool {
+    field.starts_with('*') || field == "?"
+}
+
+// Parses a comma-separated list of values, ranges and steps into sorted values.
+fn parse_field(field: &str, min: u64, max: u64, names: &[&str]) -> Result<Vec<u64>, String> {
+    let mut values = Vec::new();
+    for item in field.split(',') {
+        values.extend(parse_item(item, min, max, names).map_err(|_| invalid_field(field))?);
+    }
+    values.sort_unstable();
+    values.dedup();
+    Ok(values)
+}
+
+fn parse_item(item: &str, min: u64, max: u64, names: &[&str]) -> Result<Vec<u64>, String> {
+    let (range, step) = match item.split_once('/') {
+        Some((range, step)) => (range, Some(step.parse::<u64>().map_err(|e| e.to_string())?)),
+        None => (item, None),
+    };
+    if step == Some(0) {
+        return Err("Step must not be zero".to_string());
+    }
+    let (first, last) = match range.split_once('-') {
+        _ if range == "*" || range == "?" => (min, max),
+        Some((first, last)) => (
+            parse_value(first, min, names)?,
+            parse_value(last, min, names)?,
+        ),
+        None => {
+            let value = parse_value(range, min, names)?;
+            (value, if step.is_some() { max } else { value })
+        }
+    };
+    if first < min || last > max || first > last {
+        return Err("Value out of range".to_string());
+    }
+    Ok((first..=last).step_by(step.unwrap_or(1) as usize).collect())
+}
+
+fn parse_value(text: &str, min: u64, names: &[&str]) -> Result<u64, String> {
+    if let Some(index) = names
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(text))
+    {
+        return Ok(index as u64 + min);
+    }
+    text.parse().map_err(|_| format!("Invalid value: {}", text))
+}
+
+fn parse_month_days(field: &str) -> Result<Vec<MonthDay>, String> {
+    let mut entries = Vec::new();
+    for item in field.split(',') {
+        let upper = item.to_ascii_uppercase();
+        let entry = if upper == "L" {
+            MonthDay::Last(0)
+        } else if upper == "LW" {
+            MonthDay::LastWeekday
+        } else if let Some(offset) = upper.strip_prefix("L-") {
+            match offset.parse() {
+                Ok(offset) if offset < 31 => MonthDay::Last(offset),
+                _ => return Err(invalid_field(field)),
+            }
+        } else if let Some(day) = upper.strip_suffix('W') {
+            match day.parse() {
+                Ok(day) if (1..=31).contains(&day) => MonthDay::NearestWeekday(day),
+                _ => return Err(invalid_field(field)),
+            }
+        } else {
+            let days = parse_item(item, 1, 31, &[]).map_err(|_| invalid_field(field))?;
+            entries.extend(days.into_iter().map(MonthDay::Day));
+            continue;
+        };
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+fn parse_week_days(field: &str) -> Result<Vec<WeekDay>, String> {
+    let weekday = |text: &str| -> Result<Weekday, String> {
+        match parse_value(text, 0, &WEEKDAY_NAMES) {
+            Ok(number @ 0..=7) => Ok(weekday_from_cron(number)),
+            _ => Err(invalid_field(field)),
+        }
+    };
+    let mut entries = Vec::new();
+    for item in field.split(',') {
+        if let Some((day, n)) = item.split_once('#') {
+            match n.parse() {
+                Ok(n @ 1..=5) => entries.push(WeekDay::Nth(weekday(day)?, n)),
+                _ => return Err(invalid_field(field)),
+            }
+        } else if let Some(day) = item.strip_suffix(['L', 'l']) {
+            entries.push(WeekDay::Last(weekday(day)?));
+        } else {
+            let numbers =
+                parse_item(item, 0, 7, &WEEKDAY_NAMES).map_err(|_| invalid_field(field))?;
+            entries.extend(
+                numbers
+                    .into_iter()
+                    .map(|number| WeekDay::Every(weekday_from_cron(number))),
+            );
+        }
+    }
+    Ok(entries)
+}
+
+// Cron numbers weekdays from Sunday (0), and also accepts 7 for Sunday.
+fn weekday_from_cron(number: u64) -> Weekday {
+    Weekday::from_number_from_monday((number + 6) % 7 + 1).expect("weekday is in range")
+}
+
+fn invalid_field(field: &str) -> String {
+    format!("Invalid cron field: {}", field)
+}
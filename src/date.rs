@@ -0,0 +1,240 @@
+use crate::{days_in_month, DateTime, TimeDelta, TimeZone, Weekday};
+use std::fmt;
+use std::str::FromStr;
+
+/// A calendar date in the proleptic Gregorian calendar, without a time of day or zone.
+///
+/// Unlike `DateTime`, a `Date` is not limited to 1970 and later, which makes it suitable for
+/// calendar computations such as holidays and lunar conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: u64,
+    pub month: u64,
+    pub day: u64,
+}
+
+impl Date {
+    /// Creates a new `Date` object.
+    pub fn new(year: u64, month: u64, day: u64) -> Result<Self, String> {
+        if !(1..=12).contains(&month) {
+            return Err("Invalid month".to_string());
+        }
+        if day < 1 || day > days_in_month(month, year) {
+            return Err("Invalid day".to_string());
+        }
+        Ok(Self { year, month, day })
+    }
+
+    /// Returns the number of days since 1970-01-01, negative for earlier dates.
+    pub fn days_since_epoch(&self) -> i64 {
+        // Howard Hinnant's `days_from_civil`, with years starting in March.
+        let year = self.year as i64 - if self.month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month_from_march = (self.month as i64 + 9) % 12;
+        let day_of_year = (153 * month_from_march + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Creates a `Date` from the number of days since 1970-01-01.
+    pub fn from_days_since_epoch(days: i64) -> Result<Self, String> {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month = if month_from_march < 10 {
+            month_from_march + 3
+        } else {
+            month_from_march - 9
+        };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        if year < 0 {
+            return Err("Date is before year 0".to_string());
+        }
+        Ok(Self {
+            year: year as u64,
+            month: month as u64,
+            day: day as u64,
+        })
+    }
+
+    /// Returns the day of the week.
+    pub fn weekday(&self) -> Weekday {
+        Weekday::from_days_since_epoch(self.days_since_epoch())
+    }
+
+    /// Returns the day of the year, from 1 to 366.
+    pub fn ordinal(&self) -> u64 {
+        (1..self.month)
+            .map(|month| days_in_month(month, self.year))
+            .sum::<u64>()
+            + self.day
+    }
+
+    /// Returns the date `days` days later (or earlier, for negative values).
+    pub fn add_days(&self, days: i64) -> Result<Self, String> {
+        Self::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// Returns the `n`-th `weekday` of the month, counting from the end when `n` is negative.
+    ///
+    /// Returns an error when `n` is zero or the month has no such weekday (e.g. a fifth Monday).
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{Date, Weekday};
+    /// // Third Monday and last Friday of May 2024
+    /// let third_monday = Date::nth_weekday_of_month(2024, 5, Weekday::Monday, 3).unwrap();
+    /// let last_friday = Date::nth_weekday_of_month(2024, 5, Weekday::Friday, -1).unwrap();
+    /// assert_eq!(third_monday.to_string(), "2024-05-20");
+    /// assert_eq!(last_friday.to_string(), "2024-05-31");
+    /// ```
+    pub fn nth_weekday_of_month(
+        year: u64,
+        month: u64,
+        weekday: Weekday,
+        n: i64,
+    ) -> Result<Self, String> {
+        let first = Self::new(year, month, 1)?;
+        let last_day = days_in_month(month, year);
+        let no_such_day = || {
+            format!(
+                "There is no weekday #{} ({}) in {:04}-{:02}",
+                n, weekday, year, month
+            )
+        };
+
+        if n.unsigned_abs() > 5 {
+            return Err(no_such_day());
+        }
+        let day = match n {
+            0 => return Err("n must not be zero".to_string()),
+            n if n > 0 => {
+                let offset = weekday.days_since(first.weekday()) as i64;
+                1 + offset + (n - 1) * 7
+            }
+            n => {
+                let last = Self::new(year, month, last_day)?;
+                let offset = last.weekday().days_since(weekday) as i64;
+                last_day as i64 - offset - (-n - 1) * 7
+            }
+        };
+        if day < 1 || day > last_day as i64 {
+            return Err(no_such_day());
+        }
+        Self::new(year, month, day as u64)
+    }
+
+    /// Returns the first `weekday` strictly after this date.
+    pub fn next_weekday(&self, weekday: Weekday) -> Result<Self, String> {
+        let days = match weekday.days_since(self.weekday()) {
+            0 => 7,
+            days => days,
+        };
+        self.add_days(days as i64)
+    }
+
+    /// Returns the last `weekday` strictly before this date.
+    pub fn previous_weekday(&self, weekday: Weekday) -> Result<Self, String> {
+        let days = match self.weekday().days_since(weekday) {
+            0 => 7,
+            days => days,
+        };
+        self.add_days(-(days as i64))
+    }
+
+    /// Combines the date with a time of day in the given zone.
+    ///
+    /// Fails for dates before 1970, which a `DateTime` cannot represent.
+    pub fn and_hms(
+        &self,
+        hour: u64,
+        minute: u64,
+        second: u64,
+        timezone: TimeZone,
+    ) -> Result<DateTime, String> {
+        if self.year < 1970 {
+            return Err("Year must be 1970 or later".to_string());
+        }
+        DateTime::new(
+            self.year, self.month, self.day, hour, minute, second, timezone,
+        )
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    /// Parses an ISO 8601 calendar date, `YYYY-MM-DD`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid date: {}", s);
+        let bytes = s.as_bytes();
+        if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return Err(invalid());
+        }
+        let field = |range: std::ops::Range<usize>| -> Result<u64, String> {
+            let digits = &s[range];
+            if !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            digits.parse().map_err(|_| invalid())
+        };
+        Self::new(field(0..4)?, field(5..7)?, field(8..10)?)
+    }
+}
+
+impl DateTime {
+    /// Returns the local calendar date.
+    pub fn date(&self) -> Date {
+        Date {
+            year: self.year,
+            month: self.month,
+            day: self.day,
+        }
+    }
+
+    /// Returns the same local time of day on the first `weekday` strictly after this date.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{DateTime, TimeZone, Weekday};
+    /// let friday = DateTime::new(2024, 5, 17, 9, 30, 0, TimeZone::KST).unwrap();
+    /// let tuesday = friday.next_weekday(Weekday::Tuesday).unwrap();
+    /// assert_eq!(tuesday.to_string(), "2024-05-21 09:30:00");
+    /// ```
+    pub fn next_weekday(&self, weekday: Weekday) -> Result<Self, String> {
+        let date = self.date();
+        let days = date.next_weekday(weekday)?.days_since_epoch() - date.days_since_epoch();
+        self.add_timedelta(TimeDelta {
+            days,
+            ..Default::default()
+        })
+    }
+
+    /// Returns the same local time of day on the last `weekday` strictly before this date.
+    pub fn previous_weekday(&self, weekday: Weekday) -> Result<Self, String> {
+        let date = self.date();
+        let days = date.previous_weekday(weekday)?.days_since_epoch() - date.days_since_epoch();
+        self.add_timedelta(TimeDelta {
+            days,
+            ..Default::default()
+        })
+    }
+}
+
+impl From<DateTime> for Date {
+    fn from(datetime: DateTime) -> Self {
+        datetime.date()
+    }
+}
@@ -0,0 +1,653 @@
+use crate::constants::*;
+use crate::{DateTime, Period, TimeDelta};
+use std::fmt;
+use std::str::FromStr;
+
+// The designators of an ISO 8601 duration, in the order they must appear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Designator {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+}
+
+// One component of an ISO 8601 duration, such as `10D` or `-1.5H`.
+struct Component {
+    designator: Designator,
+    whole: i64,
+    // The digits after the decimal sign, and how many there are.
+    fraction: Option<(i128, u32)>,
+    negative: bool,
+}
+
+impl Component {
+    // The whole part with its sign.
+    fn value(&self) -> Option<i64> {
+        self.whole.checked_mul(if self.negative { -1 } else { 1 })
+    }
+
+    // The fraction with its sign, in nanoseconds, for a unit of `unit_seconds`. Digits
+    // below a nanosecond are truncated.
+    fn fraction_nanoseconds(&self, unit_seconds: i64) -> i128 {
+        let Some((fraction, digits)) = self.fraction else {
+            return 0;
+        };
+        let nanoseconds =
+            fraction * unit_seconds as i128 * NANOSECONDS_IN_SECOND as i128 / 10_i128.pow(digits);
+        if self.negative {
+            -nanoseconds
+        } else {
+            nanoseconds
+        }
+    }
+}
+
+// Adds `nanoseconds` to the day, clock and sub-second components of `delta`.
+fn add_nanoseconds(delta: &mut TimeDelta, nanoseconds: i128) -> Result<(), String> {
+    let extra = TimeDelta::from_nanoseconds(nanoseconds)?;
+    delta.days += extra.days;
+    delta.hours += extra.hours;
+    delta.minutes += extra.minutes;
+    delta.seconds += extra.seconds;
+    delta.nanoseconds += extra.nanoseconds;
+    Ok(())
+}
+
+// Writes `nanoseconds` as decimal seconds without trailing zeros, such as `1.5` or `-0.25`.
+pub(crate) fn decimal_seconds(nanoseconds: i128) -> String {
+    let sign = if nanoseconds < 0 { "-" } else { "" };
+    format!(
+        "{}{}",
+        sign,
+        decimal(nanoseconds.unsigned_abs(), NANOSECONDS_IN_SECOND as u128)
+    )
+}
+
+// Writes `nanoseconds` in a unit of `unit` nanoseconds, a power of ten, without trailing
+// zeros.
+fn decimal(nanoseconds: u128, unit: u128) -> String {
+    let (whole, fraction) = (nanoseconds / unit, nanoseconds % unit);
+    if fraction == 0 {
+        return whole.to_string();
+    }
+    let width = unit.ilog10() as usize;
+    let fraction = format!("{:0width$}", fraction, width = width);
+    format!("{}.{}", whole, fraction.trim_end_matches('0'))
+}
+
+// Parses numbers with units, such as `1h 30min` or `1.5s`, into a total in nanoseconds.
+// Each unit is looked up in `units`, which pairs unit names with their length in
+// nanoseconds; a unit is a run of letters and may be empty if `units` has a `""` name.
+// Numbers may have a fraction, which is truncated below a nanosecond, and whitespace may
+// separate a number from its unit and the parts from each other. Signs are left to the
+// caller.
+pub(crate) fn parse_units(s: &str, units: &[(&[&str], i128)]) -> Option<i128> {
+    let mut rest = s.trim();
+    if rest.is_empty() {
+        return None;
+    }
+    let mut total: i128 = 0;
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number = &rest[..number_len];
+        rest = rest[number_len..].trim_start();
+        let unit_len = rest
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(rest.len());
+        let unit = &rest[..unit_len];
+        rest = rest[unit_len..].trim_start();
+
+        let &(_, unit_nanoseconds) = units.iter().find(|(names, _)| names.contains(&unit))?;
+        let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if whole.is_empty() && fraction.is_empty()
+            || !fraction.bytes().all(|b| b.is_ascii_digit())
+            || fraction.len() > 18
+        {
+            return None;
+        }
+        let whole: i128 = if whole.is_empty() {
+            0
+        } else {
+            whole.parse().ok()?
+        };
+        let mut nanoseconds = whole.checked_mul(unit_nanoseconds)?;
+        if !fraction.is_empty() {
+            let scale = 10_i128.pow(fraction.len() as u32);
+            let fraction: i128 = fraction.parse().ok()?;
+            nanoseconds += fraction * unit_nanoseconds / scale;
+        }
+        total = total.checked_add(nanoseconds)?;
+    }
+    Some(total)
+}
+
+// Splits `PnYnMnWnDTnHnMnS` into its components. A leading sign applies to the whole
+// duration and a sign before a number to that component; only the last component may have
+// a fraction, written with `.` or `,`.
+fn parse_components(s: &str) -> Result<Vec<Component>, String> {
+    let invalid = || format!("Invalid ISO 8601 duration: {}", s);
+    let trimmed = s.trim();
+    let (negative, rest) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+    let mut chars = rest.chars().peekable();
+    if !matches!(chars.next(), Some('P' | 'p')) {
+        return Err(invalid());
+    }
+
+    let mut components: Vec<Component> = Vec::new();
+    let mut in_time = false;
+    while let Some(&next) = chars.peek() {
+        if next == 'T' || next == 't' {
+            chars.next();
+            // `T` appears once and must be followed by a time component.
+            if in_time || chars.peek().is_none() {
+                return Err(invalid());
+            }
+            in_time = true;
+            continue;
+        }
+
+        let mut number = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_digit() || matches!(c, '.' | ',' | '-' | '+') {
+                number.push(c);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        let designator = match (chars.next().map(|c| c.to_ascii_uppercase()), in_time) {
+            (Some('Y'), false) => Designator::Years,
+            (Some('M'), false) => Designator::Months,
+            (Some('W'), false) => Designator::Weeks,
+            (Some('D'), false) => Designator::Days,
+            (Some('H'), true) => Designator::Hours,
+            (Some('M'), true) => Designator::Minutes,
+            (Some('S'), true) => Designator::Seconds,
+            _ => return Err(invalid()),
+        };
+        if let Some(last) = components.last() {
+            if last.designator >= designator || last.fraction.is_some() {
+                return Err(invalid());
+            }
+        }
+
+        let (component_negative, number) = match number.strip_prefix('-') {
+            Some(number) => (!negative, number),
+            None => (negative, number.strip_prefix('+').unwrap_or(&number)),
+        };
+        let (whole, fraction) = match number.split_once(['.', ',']) {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (number, None),
+        };
+        let digits =
+            |digits: &str| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit());
+        if !digits(whole)
+            || fraction.is_some_and(|fraction| !digits(fraction) || fraction.len() > 18)
+        {
+            return Err(invalid());
+        }
+        components.push(Component {
+            designator,
+            whole: whole.parse().map_err(|_| invalid())?,
+            fraction: match fraction {
+                Some(fraction) => Some((
+                    fraction.parse().map_err(|_| invalid())?,
+                    fraction.len() as u32,
+                )),
+                None => None,
+            },
+            negative: component_negative,
+        });
+    }
+    if components.is_empty() {
+        return Err(invalid());
+    }
+    Ok(components)
+}
+
+// Formats components as `[-]PnYnMnWnDTnHnMnS`, leaving out zeros, with the seconds given
+// in nanoseconds so that a fraction is kept. The sign goes in front when no component is
+// positive, and on each negative component otherwise.
+fn format_components(date: &[(i64, char)], time: &[(i64, char)], nanoseconds: i128) -> String {
+    let all = || {
+        date.iter()
+            .chain(time)
+            .map(|&(value, _)| value as i128)
+            .chain([nanoseconds])
+    };
+    let negative = all().any(|value| value < 0) && all().all(|value| value <= 0);
+    let sign = if negative { -1 } else { 1 };
+    let mut result = if negative { "-P" } else { "P" }.to_string();
+    for &(value, designator) in date {
+        if value != 0 {
+            result.push_str(&format!("{}{}", value * sign, designator));
+        }
+    }
+    if time.iter().any(|&(value, _)| value != 0) || nanoseconds != 0 {
+        result.push('T');
+        for &(value, designator) in time {
+            if value != 0 {
+                result.push_str(&format!("{}{}", value * sign, designator));
+            }
+        }
+        if nanoseconds != 0 {
+            result.push_str(&format!("{}S", decimal_seconds(nanoseconds * sign as i128)));
+        }
+    }
+    result
+}
+
+// The seconds of `delta` with their fraction, in nanoseconds.
+fn seconds_in_nanoseconds(delta: &TimeDelta) -> i128 {
+    delta.seconds as i128 * NANOSECONDS_IN_SECOND as i128 + delta.nanoseconds as i128
+}
+
+impl TimeDelta {
+    /// Parses an ISO 8601 duration such as `PT1H30M`, `P3DT4H` or `P2W`.
+    ///
+    /// A leading `-` negates the whole duration, and a `-` before a number negates that
+    /// component. The last component may have a fraction (`PT1.5H`, `PT0.25S`), which is kept
+    /// to the nanosecond. Years and months are rejected as ambiguous, since their length
+    /// depends on the date they are added to; parse those with `Period::parse_iso8601`. Days
+    /// are 24 hours.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::TimeDelta;
+    /// let delta = TimeDelta::parse_iso8601("PT1H30M").unwrap();
+    /// assert_eq!(delta.total_seconds(), 5400);
+    /// assert_eq!(TimeDelta::parse_iso8601("-P2W").unwrap().weeks, -2);
+    /// assert_eq!(TimeDelta::parse_iso8601("PT0.5M").unwrap().seconds, 30);
+    /// assert_eq!(TimeDelta::parse_iso8601("PT1.25S").unwrap().nanoseconds, 250_000_000);
+    /// assert!(TimeDelta::parse_iso8601("P1M").is_err());
+    /// ```
+    pub fn parse_iso8601(s: &str) -> Result<TimeDelta, String> {
+        let invalid = || format!("Invalid ISO 8601 duration: {}", s);
+        let mut delta = TimeDelta::default();
+        for component in parse_components(s)? {
+            let (field, unit_seconds) = match component.designator {
+                Designator::Years | Designator::Months => {
+                    return Err(format!(
+                        "Years and months have no fixed length; parse {} as a Period",
+                        s.trim()
+                    ))
+                }
+                Designator::Weeks => (&mut delta.weeks, SECONDS_IN_WEEK),
+                Designator::Days => (&mut delta.days, SECONDS_IN_DAY),
+                Designator::Hours => (&mut delta.hours, SECONDS_IN_HOUR),
+                Designator::Minutes => (&mut delta.minutes, SECONDS_IN_MINUTE),
+                Designator::Seconds => (&mut delta.seconds, 1),
+            };
+            *field = component.value().ok_or_else(invalid)?;
+            add_nanoseconds(&mut delta, component.fraction_nanoseconds(unit_seconds))?;
+        }
+        delta.total_seconds_checked().ok_or_else(invalid)?;
+        Ok(delta)
+    }
+
+    /// Formats the delta as an ISO 8601 duration, keeping its components as they are so that
+    /// parsed durations are written back the same way. A zero delta is `PT0S`.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::TimeDelta;
+    /// let delta = TimeDelta { days: 3, hours: 4, ..Default::default() };
+    /// assert_eq!(delta.to_iso8601(), "P3DT4H");
+    /// assert_eq!(TimeDelta::from_seconds(-5400).to_iso8601(), "-PT1H30M");
+    /// assert_eq!(TimeDelta::parse_iso8601("PT0.5S").unwrap().to_iso8601(), "PT0.5S");
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        if *self == TimeDelta::default() {
+            return "PT0S".to_string();
+        }
+        format_components(
+            &[(self.weeks, 'W'), (self.days, 'D')],
+            &[(self.hours, 'H'), (self.minutes, 'M')],
+            seconds_in_nanoseconds(self),
+        )
+    }
+
+    fn total_seconds_checked(&self) -> Option<i64> {
+        i64::try_from(self.total_nanoseconds() / NANOSECONDS_IN_SECOND as i128).ok()
+    }
+}
+
+impl Period {
+    /// Parses the calendar components of an ISO 8601 duration, such as `P1Y2M10D` or `P2W`.
+    ///
+    /// Weeks are added to the days. Signs work as in `TimeDelta::parse_iso8601`. Time
+    /// components and fractions are rejected, as a `Period` has no time of day and a
+    /// fraction of a month is ambiguous.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::Period;
+    /// let period = Period::parse_iso8601("P1Y2M10D").unwrap();
+    /// assert_eq!((period.years, period.months, period.days), (1, 2, 10));
+    /// assert_eq!(period.to_iso8601(), "P1Y2M10D");
+    /// assert!(Period::parse_iso8601("P1.5M").is_err());
+    /// ```
+    pub fn parse_iso8601(s: &str) -> Result<Period, String> {
+        let invalid = || format!("Invalid ISO 8601 duration: {}", s);
+        let mut period = Period::default();
+        for component in parse_components(s)? {
+            if component.fraction.is_some() {
+                return Err(format!(
+                    "Fractional calendar components are ambiguous: {}",
+                    s
+                ));
+            }
+            let value = component.value().ok_or_else(invalid)?;
+            match component.designator {
+                Designator::Years => period.years = value,
+                Designator::Months => period.months = value,
+                Designator::Weeks => period.days = value.checked_mul(7).ok_or_else(invalid)?,
+                Designator::Days => {
+                    period.days = period.days.checked_add(value).ok_or_else(invalid)?
+                }
+                _ => return Err(format!("A Period has no time components: {}", s)),
+            }
+        }
+        Ok(period)
+    }
+
+    /// Formats the period as an ISO 8601 duration. A zero period is `P0D`, and components
+    /// with different signs are each written with their own sign, e.g. `P1Y-3D`.
+    pub fn to_iso8601(&self) -> String {
+        if self.is_zero() {
+            return "P0D".to_string();
+        }
+        format_components(
+            &[(self.years, 'Y'), (self.months, 'M'), (self.days, 'D')],
+            &[],
+            0,
+        )
+    }
+}
+
+impl fmt::Display for Period {
+    /// Formats the period as an ISO 8601 duration.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_iso8601())
+    }
+}
+
+impl FromStr for Period {
+    type Err = String;
+
+    /// Parses an ISO 8601 duration with `Period::parse_iso8601`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Period::parse_iso8601(s)
+    }
+}
+
+/// An ISO 8601 duration that may mix calendar and clock components, such as
+/// `P1Y2M10DT2H30M`, as used in ISO 8601 intervals.
+///
+/// Years, months, weeks and days go into `period` and hours, minutes and seconds into
+/// `delta`. A fraction of a week or day is added to `delta`; fractions of years and months
+/// are rejected as ambiguous.
+///
+/// # Example:
+/// ```
+/// use timekit::{DateTime, IsoDuration, TimeZone};
+/// let duration: IsoDuration = "P1M2DT12H".parse().unwrap();
+/// let start = DateTime::new(2024, 1, 31, 6, 0, 0, TimeZone::UTC).unwrap();
+/// assert_eq!(duration.add_to(&start).unwrap().to_string(), "2024-03-02 18:00:00");
+/// assert_eq!(duration.to_string(), "P1M2DT12H");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IsoDuration {
+    pub period: Period,
+    pub delta: TimeDelta,
+}
+
+impl IsoDuration {
+    /// Parses an ISO 8601 duration with any of its components.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let invalid = || format!("Invalid ISO 8601 duration: {}", s);
+        let mut duration = IsoDuration::default();
+        for component in parse_components(s)? {
+            let value = component.value().ok_or_else(invalid)?;
+            let period = &mut duration.period;
+            let delta = &mut duration.delta;
+            let unit_seconds = match component.designator {
+                Designator::Years | Designator::Months if component.fraction.is_some() => {
+                    return Err(format!(
+                        "Fractional calendar components are ambiguous: {}",
+                        s
+                    ))
+                }
+                Designator::Years => {
+                    period.years = value;
+                    continue;
+                }
+                Designator::Months => {
+                    period.months = value;
+                    continue;
+                }
+                Designator::Weeks => {
+                    period.days = value.checked_mul(7).ok_or_else(invalid)?;
+                    SECONDS_IN_WEEK
+                }
+                Designator::Days => {
+                    period.days = period.days.checked_add(value).ok_or_else(invalid)?;
+                    SECONDS_IN_DAY
+                }
+                Designator::Hours => {
+                    delta.hours = value;
+                    SECONDS_IN_HOUR
+                }
+                Designator::Minutes => {
+                    delta.minutes = value;
+                    SECONDS_IN_MINUTE
+                }
+                Designator::Seconds => {
+                    delta.seconds = value;
+                    1
+                }
+            };
+            add_nanoseconds(delta, component.fraction_nanoseconds(unit_seconds))?;
+        }
+        duration.delta.total_seconds_checked().ok_or_else(invalid)?;
+        Ok(duration)
+    }
+
+    /// Returns `true` if every component is zero.
+    pub fn is_zero(&self) -> bool {
+        self.period.is_zero() && self.delta.total_nanoseconds() == 0
+    }
+
+    /// Returns `true` if any component is negative.
+    pub fn has_negative_component(&self) -> bool {
+        let delta = &self.delta;
+        [
+            self.period.years,
+            self.period.months,
+            self.period.days,
+            delta.weeks,
+            delta.days,
+            delta.hours,
+            delta.minutes,
+            delta.seconds,
+            delta.nanoseconds,
+        ]
+        .iter()
+        .any(|&value| value < 0)
+    }
+
+    /// Returns the duration with the period and the delta multiplied by `factor`.
+    pub fn checked_mul(&self, factor: i64) -> Result<Self, String> {
+        let nanoseconds = self
+            .delta
+            .total_nanoseconds()
+            .checked_mul(factor as i128)
+            .ok_or_else(|| "Duration is too large".to_string())?;
+        Ok(Self {
+            period: self.period.checked_mul(factor)?,
+            delta: TimeDelta::from_nanoseconds(nanoseconds)?,
+        })
+    }
+
+    /// Adds the duration to `datetime`: the period on the local calendar, then the delta.
+    pub fn add_to(&self, datetime: &DateTime) -> Result<DateTime, String> {
+        datetime.add_period(self.period)?.add_timedelta(self.delta)
+    }
+
+    /// Subtracts the duration from `datetime`: the delta, then the period on the local
+    /// calendar. Because days are clamped to the end of short months, this does not always
+    /// undo `add_to`: January 31 plus `P1M` is February 29 in 2024, but February 29 minus
+    /// `P1M` is January 29.
+    pub fn sub_from(&self, datetime: &DateTime) -> Result<DateTime, String> {
+        datetime.sub_timedelta(self.delta)?.sub_period(self.period)
+    }
+}
+
+impl From<Period> for IsoDuration {
+    fn from(period: Period) -> Self {
+        Self {
+            period,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<TimeDelta> for IsoDuration {
+    fn from(delta: TimeDelta) -> Self {
+        Self {
+            delta,
+            ..Default::default()
+        }
+    }
+}
+
+impl fmt::Display for IsoDuration {
+    /// Formats the duration in ISO 8601, with the days of the period and the delta combined.
+    /// A zero duration is `PT0S`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "PT0S");
+        }
+        let (period, delta) = (&self.period, &self.delta);
+        let days = period.days + delta.weeks * 7 + delta.days;
+        let formatted = format_components(
+            &[(period.years, 'Y'), (period.months, 'M'), (days, 'D')],
+            &[(delta.hours, 'H'), (delta.minutes, 'M')],
+            seconds_in_nanoseconds(delta),
+        );
+        write!(f, "{}", formatted)
+    }
+}
+
+impl FromStr for IsoDuration {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TimeDelta {
+    /// Formats the delta as a compact duration string such as `1h30m`, `-2d4h` or `1.5s`, the
+    /// form read by `TimeDelta::from_str`.
+    ///
+    /// The total is split into weeks, days, hours, minutes and seconds, leaving out zero
+    /// units; a zero delta is `0s`. Seconds keep their fraction, and a delta shorter than a
+    /// second is written in `ms`, `us` or `ns`. Unlike `Display`, which spells out every
+    /// component, the output can be parsed back into an equal total.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::TimeDelta;
+    /// let delta = TimeDelta { minutes: 90, ..Default::default() };
+    /// assert_eq!(delta.to_string(), "90 minutes");
+    /// assert_eq!(delta.to_compact(), "1h30m");
+    /// assert_eq!(delta.to_compact().parse::<TimeDelta>().unwrap().total_seconds(), 5400);
+    /// let delta = TimeDelta { nanoseconds: 500_000_000, ..Default::default() };
+    /// assert_eq!(delta.to_compact(), "500ms");
+    /// ```
+    pub fn to_compact(&self) -> String {
+        let total = self.total_nanoseconds();
+        if total == 0 {
+            return "0s".to_string();
+        }
+        let mut remaining = total.unsigned_abs();
+        let mut result = if total < 0 { "-" } else { "" }.to_string();
+        for (unit, seconds) in [
+            ('w', SECONDS_IN_WEEK),
+            ('d', SECONDS_IN_DAY),
+            ('h', SECONDS_IN_HOUR),
+            ('m', SECONDS_IN_MINUTE),
+        ] {
+            let nanoseconds = seconds as u128 * NANOSECONDS_IN_SECOND as u128;
+            let count = remaining / nanoseconds;
+            remaining %= nanoseconds;
+            if count > 0 {
+                result.push_str(&format!("{}{}", count, unit));
+            }
+        }
+        if remaining == 0 {
+            return result;
+        }
+        // Below a second with nothing before it, use the largest unit that is at least one.
+        let (unit, nanoseconds) = match remaining {
+            _ if result.len() > 1 || remaining >= NANOSECONDS_IN_SECOND as u128 => {
+                ("s", NANOSECONDS_IN_SECOND as u128)
+            }
+            1_000_000.. => ("ms", 1_000_000),
+            1_000.. => ("us", 1_000),
+            _ => ("ns", 1),
+        };
+        result.push_str(&format!("{}{}", decimal(remaining, nanoseconds), unit));
+        result
+    }
+}
+
+impl FromStr for TimeDelta {
+    type Err = String;
+
+    /// Parses a Go or Prometheus style duration such as `90s`, `1h30m`, `2d4h`, `1.5h` or
+    /// `500ms`.
+    ///
+    /// A duration is an optional sign followed by numbers with units, optionally separated by
+    /// whitespace, as in `2d 4h`. The units are `ns`, `us` (or `µs`), `ms`, `s`, `m`, `h`,
+    /// `d` (24 hours) and `w`, and numbers may have a fraction. `0` alone is also accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid duration: {}", s);
+        let trimmed = s.trim();
+        let (negative, rest) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+        };
+        if rest == "0" {
+            return Ok(TimeDelta::default());
+        }
+        if rest.starts_with(char::is_whitespace) {
+            return Err(invalid());
+        }
+        let nanoseconds = parse_units(rest, COMPACT_UNITS).ok_or_else(invalid)?;
+        TimeDelta::from_nanoseconds(if negative { -nanoseconds } else { nanoseconds })
+            .map_err(|_| invalid())
+    }
+}
+
+// The units of a Go or Prometheus style duration, in nanoseconds.
+const COMPACT_UNITS: &[(&[&str], i128)] = &[
+    (&["ns"], 1),
+    (&["us", "µs", "μs"], 1_000),
+    (&["ms"], 1_000_000),
+    (&["s"], 1_000_000_000),
+    (&["m"], 60_000_000_000),
+    (&["h"], 3_600_000_000_000),
+    (&["d"], 86_400_000_000_000),
+    (&["w"], 604_800_000_000_000),
+];
@@ -0,0 +1,170 @@
+use crate::Date;
+use std::fmt;
+
+/// The church whose Easter reckoning to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Church {
+    /// Western churches, reckoning Easter in the Gregorian calendar.
+    Western,
+    /// Eastern Orthodox churches, reckoning Easter in the Julian calendar. Dates are still
+    /// returned in the Gregorian calendar.
+    Orthodox,
+}
+
+/// A feast whose date is a fixed number of days from Easter Sunday.
+///
+/// # Example:
+/// ```
+/// use timekit::{Church, Date, MovableFeast};
+/// let pentecost = MovableFeast::Pentecost.date(2024, Church::Western).unwrap();
+/// assert_eq!(pentecost, Date::new(2024, 5, 19).unwrap());
+/// let good_friday = MovableFeast::GoodFriday.date(2024, Church::Orthodox).unwrap();
+/// assert_eq!(good_friday, Date::new(2024, 5, 3).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MovableFeast {
+    AshWednesday,
+    PalmSunday,
+    MaundyThursday,
+    GoodFriday,
+    HolySaturday,
+    EasterSunday,
+    EasterMonday,
+    Ascension,
+    Pentecost,
+    WhitMonday,
+    TrinitySunday,
+    CorpusChristi,
+}
+
+const ALL_FEASTS: [MovableFeast; 12] = [
+    MovableFeast::AshWednesday,
+    MovableFeast::PalmSunday,
+    MovableFeast::MaundyThursday,
+    MovableFeast::GoodFriday,
+    MovableFeast::HolySaturday,
+    MovableFeast::EasterSunday,
+    MovableFeast::EasterMonday,
+    MovableFeast::Ascension,
+    MovableFeast::Pentecost,
+    MovableFeast::WhitMonday,
+    MovableFeast::TrinitySunday,
+    MovableFeast::CorpusChristi,
+];
+
+impl MovableFeast {
+    /// Returns an iterator over all feasts, in the order they fall in the year.
+    pub fn all() -> impl Iterator<Item = MovableFeast> {
+        ALL_FEASTS.iter().copied()
+    }
+
+    /// Returns the number of days from Easter Sunday to the feast.
+    pub const fn offset(&self) -> i64 {
+        match self {
+            MovableFeast::AshWednesday => -46,
+            MovableFeast::PalmSunday => -7,
+            MovableFeast::MaundyThursday => -3,
+            MovableFeast::GoodFriday => -2,
+            MovableFeast::HolySaturday => -1,
+            MovableFeast::EasterSunday => 0,
+            MovableFeast::EasterMonday => 1,
+            MovableFeast::Ascension => 39,
+            MovableFeast::Pentecost => 49,
+            MovableFeast::WhitMonday => 50,
+            MovableFeast::TrinitySunday => 56,
+            MovableFeast::CorpusChristi => 60,
+        }
+    }
+
+    /// Returns the English name of the feast.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MovableFeast::AshWednesday => "Ash Wednesday",
+            MovableFeast::PalmSunday => "Palm Sunday",
+            MovableFeast::MaundyThursday => "Maundy Thursday",
+            MovableFeast::GoodFriday => "Good Friday",
+            MovableFeast::HolySaturday => "Holy Saturday",
+            MovableFeast::EasterSunday => "Easter Sunday",
+            MovableFeast::EasterMonday => "Easter Monday",
+            MovableFeast::Ascension => "Ascension Day",
+            MovableFeast::Pentecost => "Pentecost",
+            MovableFeast::WhitMonday => "Whit Monday",
+            MovableFeast::TrinitySunday => "Trinity Sunday",
+            MovableFeast::CorpusChristi => "Corpus Christi",
+        }
+    }
+
+    /// Returns the date of the feast in `year`.
+    pub fn date(&self, year: u64, church: Church) -> Result<Date, String> {
+        let easter = match church {
+            Church::Western => Date::easter(year)?,
+            Church::Orthodox => Date::orthodox_easter(year)?,
+        };
+        easter.add_days(self.offset())
+    }
+}
+
+impl fmt::Display for MovableFeast {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Date {
+    /// Returns Western Easter Sunday in `year`, from 1583 onwards.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::Date;
+    /// assert_eq!(Date::easter(2024).unwrap(), Date::new(2024, 3, 31).unwrap());
+    /// assert_eq!(Date::easter(2025).unwrap(), Date::new(2025, 4, 20).unwrap());
+    /// ```
+    pub fn easter(year: u64) -> Result<Self, String> {
+        if year < 1583 {
+            return Err("Gregorian Easter is only defined from 1583".to_string());
+        }
+        // The anonymous Gregorian algorithm (Meeus/Jones/Butcher).
+        let a = year % 19;
+        let b = year / 100;
+        let c = year % 100;
+        let d = b / 4;
+        let e = b % 4;
+        let f = (b + 8) / 25;
+        let g = (b - f + 1) / 3;
+        let h = (19 * a + b - d - g + 15) % 30;
+        let i = c / 4;
+        let k = c % 4;
+        let l = (32 + 2 * e + 2 * i - h - k) % 7;
+        let m = (a + 11 * h + 22 * l) / 451;
+        let month = (h + l - 7 * m + 114) / 31;
+        let day = (h + l - 7 * m + 114) % 31 + 1;
+        Self::new(year, month, day)
+    }
+
+    /// Returns Orthodox Easter Sunday in `year`, from 1583 onwards, as a Gregorian date.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::Date;
+    /// assert_eq!(Date::orthodox_easter(2024).unwrap(), Date::new(2024, 5, 5).unwrap());
+    /// // Both churches celebrated on the same day in 2025
+    /// assert_eq!(Date::orthodox_easter(2025).unwrap(), Date::easter(2025).unwrap());
+    /// ```
+    pub fn orthodox_easter(year: u64) -> Result<Self, String> {
+        if year < 1583 {
+            return Err("Orthodox Easter is only converted to Gregorian from 1583".to_string());
+        }
+        // Meeus' Julian algorithm, giving a date in the Julian calendar.
+        let a = year % 4;
+        let b = year % 7;
+        let c = year % 19;
+        let d = (19 * c + 15) % 30;
+        let e = (2 * a + 4 * b + 34 - d) % 7;
+        let month = (d + e + 114) / 31;
+        let day = (d + e + 114) % 31 + 1;
+        // The Julian calendar runs behind by one more day for every century year that is
+        // not a Gregorian leap year.
+        let difference = year / 100 - year / 400 - 2;
+        Self::new(year, month, 1)?.add_days((day - 1 + difference) as i64)
+    }
+}
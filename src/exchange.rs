@@ -0,0 +1,278 @@
+use crate::constants::*;
+use crate::holiday::{Holiday, HolidayProvider};
+use crate::us::{observed, observed_name};
+use crate::{
+    BusinessCalendar, Church, Date, DateTime, Interval, KoreanHolidays, MovableFeast, TimeZone,
+    Weekday,
+};
+use std::ops::RangeInclusive;
+
+/// A stock exchange with its trading sessions, holiday closures and shortened days.
+///
+/// Sessions are defined in the exchange's local time and returned as UTC intervals; NYSE
+/// hours follow US Eastern daylight saving time. `next_open` and `next_close` answer in the
+/// time zone of their argument.
+///
+/// - `Nyse`: 09:30 to 16:00 New York time, closing at 13:00 on the day before Independence
+///   Day, the day after Thanksgiving and Christmas Eve. Closed on the NYSE holidays, including
+///   Good Friday, and on special closures such as those after September 11th, 2001.
+/// - `Krx`: 09:00 to 15:30 Korea Standard Time (15:00 before August 2016), opening an hour late
+///   on the first trading day of the year. Closed on Korean public holidays, Labour Day (May
+///   1st) and the last day of the year. Election days are not included.
+///
+/// # Example:
+/// ```
+/// use timekit::{DateTime, Exchange, TimeZone};
+/// // Friday, November 29th 2024, the day after Thanksgiving
+/// let noon = DateTime::new(2024, 11, 29, 17, 0, 0, TimeZone::UTC).unwrap();
+/// assert!(Exchange::Nyse.is_open(&noon).unwrap());
+/// let close = Exchange::Nyse.next_close(&noon).unwrap();
+/// assert_eq!(close.to_string(), "2024-11-29 18:00:00");
+///
+/// // Seoul closes for Chuseok until Thursday, September 19th 2024
+/// let chuseok = DateTime::new(2024, 9, 16, 12, 0, 0, TimeZone::KST).unwrap();
+/// let open = Exchange::Krx.next_open(&chuseok).unwrap();
+/// assert_eq!(open.to_string(), "2024-09-19 09:00:00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Exchange {
+    /// The New York Stock Exchange.
+    Nyse,
+    /// The Korea Exchange.
+    Krx,
+}
+
+// Days on which the NYSE closed for events rather than regular holidays, including the
+// presidential Election Days on which it closed until 1980.
+const NYSE_SPECIAL_CLOSURES: [(u64, u64, u64, &str); 18] = [
+    (1972, 11, 7, "Presidential Election Day"),
+    (1972, 12, 28, "Funeral of Harry S. Truman"),
+    (1973, 1, 25, "Funeral of Lyndon B. Johnson"),
+    (1976, 11, 2, "Presidential Election Day"),
+    (1977, 7, 14, "New York City Blackout"),
+    (1980, 11, 4, "Presidential Election Day"),
+    (1985, 9, 27, "Hurricane Gloria"),
+    (1994, 4, 27, "Funeral of Richard Nixon"),
+    (2001, 9, 11, "September 11 Attacks"),
+    (2001, 9, 12, "September 11 Attacks"),
+    (2001, 9, 13, "September 11 Attacks"),
+    (2001, 9, 14, "September 11 Attacks"),
+    (2004, 6, 11, "Funeral of Ronald Reagan"),
+    (2007, 1, 2, "Funeral of Gerald Ford"),
+    (2012, 10, 29, "Hurricane Sandy"),
+    (2012, 10, 30, "Hurricane Sandy"),
+    (2018, 12, 5, "Funeral of George H. W. Bush"),
+    (2025, 1, 9, "Funeral of Jimmy Carter"),
+];
+
+impl Exchange {
+    /// Returns the exchange's short name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Exchange::Nyse => "NYSE",
+            Exchange::Krx => "KRX",
+        }
+    }
+
+    /// Returns a business calendar of the exchange's trading days in `years`.
+    pub fn business_calendar(
+        &self,
+        years: RangeInclusive<u64>,
+    ) -> Result<BusinessCalendar, String> {
+        BusinessCalendar::saturday_sunday().with_holidays_from(self, years)
+    }
+
+    /// Returns the trading session on a local date as a UTC interval, or `None` when the
+    /// exchange is closed that day.
+    pub fn session(&self, date: Date) -> Result<Option<Interval>, String> {
+        let calendar = self.business_calendar(date.year..=date.year)?;
+        self.session_in(&calendar, date)
+    }
+
+    /// Returns `true` if the exchange is in a trading session at the given instant.
+    pub fn is_open(&self, at: &DateTime) -> Result<bool, String> {
+        let at = DateTime::from_unix_seconds(at.to_unix_seconds(), TimeZone::UTC)?;
+        // The local date of the session is within a day of the UTC date.
+        for offset in -1..=1 {
+            if let Some(session) = self.session(at.date().add_days(offset)?)? {
+                if session.contains(&at) {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    /// Returns the first session open strictly after `after`, in the zone of `after`.
+    pub fn next_open(&self, after: &DateTime) -> Result<DateTime, String> {
+        self.next_session_edge(after, |session| session.start())
+    }
+
+    /// Returns the first session close strictly after `after`, in the zone of `after`. While
+    /// the exchange is open, this is the close of the current session.
+    pub fn next_close(&self, after: &DateTime) -> Result<DateTime, String> {
+        self.next_session_edge(after, |session| session.end())
+    }
+
+    fn next_session_edge(
+        &self,
+        after: &DateTime,
+        edge: fn(&Interval) -> DateTime,
+    ) -> Result<DateTime, String> {
+        let unix = after.to_unix_seconds();
+        let mut date = DateTime::from_unix_seconds(unix, TimeZone::UTC)?
+            .date()
+            .add_days(-1)?;
+        let mut calendar = self.business_calendar(date.year..=date.year)?;
+        // Closures never last more than a couple of weeks.
+        for _ in 0..366 {
+            if let Some(session) = self.session_in(&calendar, date)? {
+                let instant = edge(&session);
+                if instant.to_unix_seconds() > unix {
+                    return DateTime::from_unix_seconds(instant.to_unix_seconds(), after.timezone);
+                }
+            }
+            let next = date.add_days(1)?;
+            if next.year != date.year {
+                calendar = self.business_calendar(next.year..=next.year)?;
+            }
+            date = next;
+        }
+        Err("No trading session found within a year".to_string())
+    }
+
+    // The session on `date`, given a calendar of the trading days in its year.
+    fn session_in(
+        &self,
+        calendar: &BusinessCalendar,
+        date: Date,
+    ) -> Result<Option<Interval>, String> {
+        if !calendar.is_business_day(date) {
+            return Ok(None);
+        }
+        let (open, close, offset) = match self {
+            Exchange::Nyse => {
+                let close = if Self::is_early_close(date)? {
+                    (13, 0)
+                } else {
+                    (16, 0)
+                };
+                ((9, 30), close, us_eastern_offset(date))
+            }
+            Exchange::Krx => {
+                let first_day = calendar.next_business_day(Date::new(date.year - 1, 12, 31)?)?;
+                let open = if date == first_day { (10, 0) } else { (9, 0) };
+                let close = if date < Date::new(2016, 8, 1)? {
+                    (15, 0)
+                } else {
+                    (15, 30)
+                };
+                (open, close, TimeZone::KST.offset_in_seconds())
+            }
+        };
+        let local = |(hour, minute): (i64, i64)| {
+            let seconds = date.days_since_epoch() * SECONDS_IN_DAY
+                + hour * SECONDS_IN_HOUR
+                + minute * SECONDS_IN_MINUTE;
+            DateTime::from_unix_seconds(seconds - offset, TimeZone::UTC)
+        };
+        Interval::new(local(open)?, local(close)?).map(Some)
+    }
+
+    // Whether the NYSE closes early on a trading day.
+    fn is_early_close(date: Date) -> Result<bool, String> {
+        let year = date.year;
+        let thanksgiving = Date::nth_weekday_of_month(year, 11, Weekday::Thursday, 4)?;
+        Ok(date == Date::new(year, 7, 3)?
+            || date == thanksgiving.add_days(1)?
+            || date == Date::new(year, 12, 24)?)
+    }
+
+    fn nyse_holidays(year: u64) -> Result<Vec<Holiday>, String> {
+        let date = |month, day| Date::new(year, month, day);
+        let monday = |month, n| Date::nth_weekday_of_month(year, month, Weekday::Monday, n);
+        let mut holidays = Vec::new();
+        let mut add = |date: Date, name: &str, observe_saturday: bool| -> Result<(), String> {
+            if !observe_saturday && date.weekday() == Weekday::Saturday {
+                return Ok(());
+            }
+            let day = observed(date)?;
+            holidays.push(Holiday {
+                date: day,
+                name: observed_name(name, date, day),
+            });
+            Ok(())
+        };
+
+        // A Saturday New Year's Day is not made up on the last trading day of the year.
+        add(date(1, 1)?, "New Year's Day", false)?;
+        if year >= 1998 {
+            add(monday(1, 3)?, "Martin Luther King Jr. Day", true)?;
+        }
+        add(monday(2, 3)?, "Washington's Birthday", true)?;
+        let good_friday = MovableFeast::GoodFriday.date(year, Church::Western)?;
+        add(good_friday, "Good Friday", true)?;
+        add(monday(5, -1)?, "Memorial Day", true)?;
+        if year >= 2022 {
+            add(date(6, 19)?, "Juneteenth National Independence Day", true)?;
+        }
+        add(date(7, 4)?, "Independence Day", true)?;
+        add(monday(9, 1)?, "Labor Day", true)?;
+        let thanksgiving = Date::nth_weekday_of_month(year, 11, Weekday::Thursday, 4)?;
+        add(thanksgiving, "Thanksgiving Day", true)?;
+        add(date(12, 25)?, "Christmas Day", true)?;
+        for (closure_year, month, day, name) in NYSE_SPECIAL_CLOSURES {
+            if closure_year == year {
+                add(date(month, day)?, name, true)?;
+            }
+        }
+        holidays.sort_by_key(|holiday| holiday.date);
+        Ok(holidays)
+    }
+
+    fn krx_holidays(year: u64) -> Result<Vec<Holiday>, String> {
+        let mut holidays = KoreanHolidays.holidays(year)?;
+        holidays.push(Holiday::new(Date::new(year, 5, 1)?, "Labour Day"));
+        // The year-end closing day moves back to the last weekday that is not a holiday.
+        let mut last = Date::new(year, 12, 31)?;
+        while matches!(last.weekday(), Weekday::Saturday | Weekday::Sunday)
+            || holidays.iter().any(|holiday| holiday.date == last)
+        {
+            last = last.add_days(-1)?;
+        }
+        holidays.push(Holiday::new(last, "Year-End Closing Day"));
+        holidays.sort_by_key(|holiday| holiday.date);
+        Ok(holidays)
+    }
+}
+
+impl HolidayProvider for Exchange {
+    /// Returns the days on which the exchange is closed for the whole day, other than
+    /// weekends.
+    fn holidays(&self, year: u64) -> Result<Vec<Holiday>, String> {
+        match self {
+            Exchange::Nyse if year < 1971 => {
+                Err("NYSE holidays are only available from 1971".to_string())
+            }
+            Exchange::Nyse => Self::nyse_holidays(year),
+            Exchange::Krx => Self::krx_holidays(year),
+        }
+    }
+}
+
+// UTC offset of US Eastern time on a date, in seconds. The clock change happens at 2:00,
+// well outside trading hours, so the date alone decides it.
+fn us_eastern_offset(date: Date) -> i64 {
+    let year = date.year;
+    let sunday = |month, n| Date::nth_weekday_of_month(year, month, Weekday::Sunday, n);
+    let (start, end) = match year {
+        ..=1986 => (sunday(4, -1), sunday(10, -1)),
+        1987..=2006 => (sunday(4, 1), sunday(10, -1)),
+        _ => (sunday(3, 2), sunday(11, 1)),
+    };
+    let standard = TimeZone::EST.offset_in_seconds();
+    match (start, end) {
+        (Ok(start), Ok(end)) if start <= date && date < end => standard + SECONDS_IN_HOUR,
+        _ => standard,
+    }
+}
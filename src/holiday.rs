@@ -0,0 +1,51 @@
+use crate::{BusinessCalendar, Date};
+use std::ops::RangeInclusive;
+
+/// A named holiday on a given date.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Holiday {
+    pub date: Date,
+    pub name: String,
+}
+
+impl Holiday {
+    /// Creates a new `Holiday`.
+    pub fn new(date: Date, name: &str) -> Self {
+        Self {
+            date,
+            name: name.to_string(),
+        }
+    }
+}
+
+/// A source of holidays, such as the public holiday rules of a country.
+pub trait HolidayProvider {
+    /// Returns the holidays in `year` in chronological order, or an error if the provider
+    /// does not cover that year.
+    fn holidays(&self, year: u64) -> Result<Vec<Holiday>, String>;
+}
+
+impl BusinessCalendar {
+    /// Returns the calendar with every holiday the provider lists for `years` added.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{BusinessCalendar, Date, KoreanHolidays};
+    /// let calendar = BusinessCalendar::default()
+    ///     .with_holidays_from(&KoreanHolidays, 2024..=2024)
+    ///     .unwrap();
+    /// assert!(!calendar.is_business_day(Date::new(2024, 9, 17).unwrap())); // Chuseok
+    /// ```
+    pub fn with_holidays_from<P: HolidayProvider + ?Sized>(
+        mut self,
+        provider: &P,
+        years: RangeInclusive<u64>,
+    ) -> Result<Self, String> {
+        for year in years {
+            for holiday in provider.holidays(year)? {
+                self.add_holiday(holiday.date);
+            }
+        }
+        Ok(self)
+    }
+}
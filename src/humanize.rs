@@ -0,0 +1,340 @@
+use crate::constants::*;
+use crate::{DateTime, TimeDelta, TimeUnit};
+
+// Approximate lengths of the calendar units, as used for humanized output.
+const SECONDS_IN_MONTH: i64 = 30 * SECONDS_IN_DAY;
+
+const UNITS: [TimeUnit; 7] = [
+    TimeUnit::Second,
+    TimeUnit::Minute,
+    TimeUnit::Hour,
+    TimeUnit::Day,
+    TimeUnit::Week,
+    TimeUnit::Month,
+    TimeUnit::Year,
+];
+
+/// The language of humanized durations.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Language {
+    #[default]
+    English,
+    Korean,
+    Japanese,
+}
+
+impl Language {
+    // The name of `count` units, e.g. "5 minutes", "5분" or "5分".
+    fn unit(&self, unit: TimeUnit, count: i64) -> String {
+        match self {
+            Language::English => {
+                let name = match unit {
+                    TimeUnit::Second => "second",
+                    TimeUnit::Minute => "minute",
+                    TimeUnit::Hour => "hour",
+                    TimeUnit::Day => "day",
+                    TimeUnit::Week => "week",
+                    TimeUnit::Month => "month",
+                    TimeUnit::Year => "year",
+                };
+                format!("{} {}{}", count, name, if count == 1 { "" } else { "s" })
+            }
+            Language::Korean => {
+                let name = match unit {
+                    TimeUnit::Second => "초",
+                    TimeUnit::Minute => "분",
+                    TimeUnit::Hour => "시간",
+                    TimeUnit::Day => "일",
+                    TimeUnit::Week => "주",
+                    TimeUnit::Month => "개월",
+                    TimeUnit::Year => "년",
+                };
+                format!("{}{}", count, name)
+            }
+            Language::Japanese => {
+                let name = match unit {
+                    TimeUnit::Second => "秒",
+                    TimeUnit::Minute => "分",
+                    TimeUnit::Hour => "時間",
+                    TimeUnit::Day => "日",
+                    TimeUnit::Week => "週間",
+                    TimeUnit::Month => "か月",
+                    TimeUnit::Year => "年",
+                };
+                format!("{}{}", count, name)
+            }
+        }
+    }
+
+    fn separator(&self) -> &'static str {
+        match self {
+            Language::English => ", ",
+            Language::Korean => " ",
+            Language::Japanese => "",
+        }
+    }
+
+    // The default past, future and present phrasing.
+    fn phrasing(&self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            Language::English => ("{} ago", "in {}", "just now"),
+            Language::Korean => ("{} 전", "{} 후", "방금"),
+            Language::Japanese => ("{}前", "{}後", "たった今"),
+        }
+    }
+}
+
+/// The counts below which a humanized duration stays in a unit before moving to the next
+/// larger one, in the style of moment.js.
+///
+/// With the defaults, 44 seconds reads as "44 seconds" and 45 seconds as "1 minute", 21
+/// hours as "21 hours" and 22 hours as "1 day". Years have no threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Thresholds {
+    pub seconds: i64,
+    pub minutes: i64,
+    pub hours: i64,
+    pub days: i64,
+    pub weeks: i64,
+    pub months: i64,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            seconds: 45,
+            minutes: 45,
+            hours: 22,
+            days: 7,
+            weeks: 4,
+            months: 11,
+        }
+    }
+}
+
+impl Thresholds {
+    fn limit(&self, unit: TimeUnit) -> Option<i64> {
+        match unit {
+            TimeUnit::Second => Some(self.seconds),
+            TimeUnit::Minute => Some(self.minutes),
+            TimeUnit::Hour => Some(self.hours),
+            TimeUnit::Day => Some(self.days),
+            TimeUnit::Week => Some(self.weeks),
+            TimeUnit::Month => Some(self.months),
+            TimeUnit::Year => None,
+        }
+    }
+}
+
+/// Turns durations into short phrases such as "5 minutes ago" or "in 2 days".
+///
+/// By default a duration is shown in a single unit, rounded half up and chosen by the
+/// `Thresholds`. With `max_units` above 1, the duration is instead split into up to that
+/// many units ("1 hour, 30 minutes"), rounded to the `granularity` unit and truncated after
+/// the last unit shown. Months count as 30 days and years as 365 days.
+///
+/// # Example:
+/// ```
+/// use timekit::{Humanizer, Language, TimeDelta, TimeUnit};
+/// let delta = TimeDelta { hours: 1, minutes: 30, seconds: 20, ..Default::default() };
+/// assert_eq!(Humanizer::default().duration(delta), "2 hours");
+///
+/// let precise = Humanizer::new(Language::Korean).max_units(2).granularity(TimeUnit::Minute);
+/// assert_eq!(precise.duration(delta), "1시간 30분");
+/// assert_eq!(precise.relative(-90 * 60), "1시간 30분 전");
+///
+/// let japanese = Humanizer::new(Language::Japanese);
+/// assert_eq!(japanese.relative(2 * 86_400), "2日後");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Humanizer {
+    language: Language,
+    granularity: TimeUnit,
+    max_units: usize,
+    thresholds: Thresholds,
+    phrasing: Option<(String, String, String)>,
+}
+
+impl Default for Humanizer {
+    fn default() -> Self {
+        Self::new(Language::English)
+    }
+}
+
+impl Humanizer {
+    /// Creates a humanizer for `language` with the default settings.
+    pub fn new(language: Language) -> Self {
+        Self {
+            language,
+            granularity: TimeUnit::Second,
+            max_units: 1,
+            thresholds: Thresholds::default(),
+            phrasing: None,
+        }
+    }
+
+    /// Returns the humanizer with `unit` as the smallest unit shown.
+    pub fn granularity(mut self, unit: TimeUnit) -> Self {
+        self.granularity = unit;
+        self
+    }
+
+    /// Returns the humanizer showing up to `max_units` units (at least 1).
+    pub fn max_units(mut self, max_units: usize) -> Self {
+        self.max_units = max_units.max(1);
+        self
+    }
+
+    /// Returns the humanizer with custom unit thresholds.
+    pub fn thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Returns the humanizer with custom relative phrasing. `{}` in `past` and `future` is
+    /// replaced by the duration, and `now` is used when the duration rounds to zero.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::Humanizer;
+    /// let humanizer = Humanizer::default().phrasing("{} earlier", "{} from now", "now");
+    /// assert_eq!(humanizer.relative(-300), "5 minutes earlier");
+    /// assert_eq!(humanizer.relative(10), "10 seconds from now");
+    /// ```
+    pub fn phrasing(mut self, past: &str, future: &str, now: &str) -> Self {
+        self.phrasing = Some((past.to_string(), future.to_string(), now.to_string()));
+        self
+    }
+
+    /// Describes the length of `delta`, ignoring its sign.
+    pub fn duration(&self, delta: TimeDelta) -> String {
+        let parts = self.parts(delta.total_seconds().unsigned_abs() as i64);
+        if parts.is_empty() {
+            return self.language.unit(self.granularity, 0);
+        }
+        self.join(&parts)
+    }
+
+    /// Describes an offset of `seconds` from now, in the past when negative.
+    pub fn relative(&self, seconds: i64) -> String {
+        let parts = self.parts(seconds.unsigned_abs() as i64);
+        let (past, future, now) = match &self.phrasing {
+            Some((past, future, now)) => (past.as_str(), future.as_str(), now.as_str()),
+            None => self.language.phrasing(),
+        };
+        if parts.is_empty() {
+            return now.to_string();
+        }
+        let template = if seconds < 0 { past } else { future };
+        template.replace("{}", &self.join(&parts))
+    }
+
+    fn join(&self, parts: &[(TimeUnit, i64)]) -> String {
+        parts
+            .iter()
+            .map(|&(unit, count)| self.language.unit(unit, count))
+            .collect::<Vec<_>>()
+            .join(self.language.separator())
+    }
+
+    // The units and counts to show for a non-negative number of seconds; empty when it
+    // rounds to zero.
+    fn parts(&self, seconds: i64) -> Vec<(TimeUnit, i64)> {
+        let units = UNITS
+            .iter()
+            .copied()
+            .filter(|&unit| unit >= self.granularity);
+        if self.max_units == 1 {
+            for unit in units {
+                let count = round(seconds, unit_seconds(unit));
+                if self
+                    .thresholds
+                    .limit(unit)
+                    .is_none_or(|limit| count < limit)
+                {
+                    return if count == 0 {
+                        Vec::new()
+                    } else {
+                        vec![(unit, count)]
+                    };
+                }
+            }
+            return Vec::new();
+        }
+
+        let mut remaining =
+            round(seconds, unit_seconds(self.granularity)) * unit_seconds(self.granularity);
+        let mut parts = Vec::new();
+        for unit in units.rev() {
+            let count = remaining / unit_seconds(unit);
+            remaining %= unit_seconds(unit);
+            if count > 0 || !parts.is_empty() {
+                parts.push((unit, count));
+            }
+        }
+        parts.truncate(self.max_units);
+        parts.retain(|&(_, count)| count > 0);
+        parts
+    }
+}
+
+fn unit_seconds(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Minute => SECONDS_IN_MINUTE,
+        TimeUnit::Hour => SECONDS_IN_HOUR,
+        TimeUnit::Day => SECONDS_IN_DAY,
+        TimeUnit::Week => SECONDS_IN_WEEK,
+        TimeUnit::Month => SECONDS_IN_MONTH,
+        TimeUnit::Year => SECONDS_IN_YEAR,
+    }
+}
+
+// `seconds / unit`, rounded half up.
+fn round(seconds: i64, unit: i64) -> i64 {
+    (seconds + unit / 2) / unit
+}
+
+impl TimeDelta {
+    /// Describes the length of the delta in a single rounded unit, in English.
+    ///
+    /// Unlike `Display`, which spells out every component, this gives a short approximation.
+    /// Use `humanize_with` for other languages and settings.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::TimeDelta;
+    /// let delta = TimeDelta { days: 2, hours: 3, ..Default::default() };
+    /// assert_eq!(delta.to_string(), "2 days, 3 hours");
+    /// assert_eq!(delta.humanize(), "2 days");
+    /// ```
+    pub fn humanize(&self) -> String {
+        Humanizer::default().duration(*self)
+    }
+
+    /// Describes the length of the delta with the given `Humanizer`.
+    pub fn humanize_with(&self, humanizer: &Humanizer) -> String {
+        humanizer.duration(*self)
+    }
+}
+
+impl DateTime {
+    /// Describes this instant relative to `other` in English, e.g. "5 minutes ago" when it
+    /// is five minutes before `other`, or "in 2 days" when it is two days after.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{DateTime, TimeZone};
+    /// let now = DateTime::new(2024, 5, 17, 12, 0, 0, TimeZone::UTC).unwrap();
+    /// let posted = DateTime::new(2024, 5, 17, 20, 55, 0, TimeZone::KST).unwrap();
+    /// assert_eq!(posted.humanize_relative_to(&now), "5 minutes ago");
+    /// ```
+    pub fn humanize_relative_to(&self, other: &DateTime) -> String {
+        self.humanize_relative_to_with(other, &Humanizer::default())
+    }
+
+    /// Describes this instant relative to `other` with the given `Humanizer`.
+    pub fn humanize_relative_to_with(&self, other: &DateTime, humanizer: &Humanizer) -> String {
+        humanizer.relative(self.to_unix_seconds() - other.to_unix_seconds())
+    }
+}
@@ -0,0 +1,298 @@
+use crate::{DateTime, TimeDelta};
+
+/// A half-open span of time, `[start, end)`, between two `DateTime`s.
+///
+/// Intervals are compared by the instants they cover, so the time zones of `start` and `end`
+/// do not affect equality or set operations. An interval whose start equals its end is empty.
+#[derive(Debug, Clone, Copy)]
+pub struct Interval {
+    start: DateTime,
+    end: DateTime,
+}
+
+impl Interval {
+    /// Creates the interval `[start, end)`. `end` must not be before `start`.
+    pub fn new(start: DateTime, end: DateTime) -> Result<Self, String> {
+        if end.to_unix_seconds() < start.to_unix_seconds() {
+            return Err("Interval end must not be before its start".to_string());
+        }
+        Ok(Self { start, end })
+    }
+
+    /// Creates the interval that starts at `start` and lasts for `duration`.
+    pub fn from_duration(start: DateTime, duration: TimeDelta) -> Result<Self, String> {
+        Self::new(start, start.add_timedelta(duration)?)
+    }
+
+    /// Returns the inclusive start of the interval.
+    pub fn start(&self) -> DateTime {
+        self.start
+    }
+
+    /// Returns the exclusive end of the interval.
+    pub fn end(&self) -> DateTime {
+        self.end
+    }
+
+    /// Returns `true` if the interval covers no time at all.
+    pub fn is_empty(&self) -> bool {
+        self.start_seconds() == self.end_seconds()
+    }
+
+    /// Returns the length of the interval.
+    pub fn duration(&self) -> TimeDelta {
+        TimeDelta::from_seconds(self.end_seconds() - self.start_seconds())
+    }
+
+    /// Returns `true` if `datetime` lies in `[start, end)`.
+    pub fn contains(&self, datetime: &DateTime) -> bool {
+        let seconds = datetime.to_unix_seconds();
+        self.start_seconds() <= seconds && seconds < self.end_seconds()
+    }
+
+    /// Returns `true` if the two intervals share at least one instant. Intervals that only
+    /// touch, such as `[9:00, 10:00)` and `[10:00, 11:00)`, do not overlap.
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start_seconds() < other.end_seconds() && other.start_seconds() < self.end_seconds()
+    }
+
+    /// Returns the span covered by both intervals, or `None` if they do not overlap.
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        Some(Interval {
+            start: latest(self.start, other.start),
+            end: earliest(self.end, other.end),
+        })
+    }
+
+    /// Returns the span covered by either interval, or `None` if they neither overlap nor touch,
+    /// since the result would not be a single interval.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{DateTime, Interval, TimeZone};
+    /// let at = |hour| DateTime::new(2024, 5, 1, hour, 0, 0, TimeZone::KST).unwrap();
+    /// let morning = Interval::new(at(9), at(10)).unwrap();
+    /// let late_morning = Interval::new(at(10), at(12)).unwrap();
+    /// let afternoon = Interval::new(at(13), at(15)).unwrap();
+    /// assert_eq!(morning.union(&late_morning), Some(Interval::new(at(9), at(12)).unwrap()));
+    /// assert_eq!(morning.union(&afternoon), None);
+    /// ```
+    pub fn union(&self, other: &Interval) -> Option<Interval> {
+        if self.start_seconds() > other.end_seconds() || other.start_seconds() > self.end_seconds()
+        {
+            return None;
+        }
+        Some(Interval {
+            start: earliest(self.start, other.start),
+            end: latest(self.end, other.end),
+        })
+    }
+
+    /// Returns the span between two intervals, or `None` if they overlap or touch.
+    pub fn gap(&self, other: &Interval) -> Option<Interval> {
+        if self.end_seconds() < other.start_seconds() {
+            Some(Interval {
+                start: self.end,
+                end: other.start,
+            })
+        } else if other.end_seconds() < self.start_seconds() {
+            Some(Interval {
+                start: other.end,
+                end: self.start,
+            })
+        } else {
+            None
+        }
+    }
+
+    fn start_seconds(&self) -> i64 {
+        self.start.to_unix_seconds()
+    }
+
+    fn end_seconds(&self) -> i64 {
+        self.end.to_unix_seconds()
+    }
+}
+
+impl PartialEq for Interval {
+    fn eq(&self, other: &Self) -> bool {
+        self.start_seconds() == other.start_seconds() && self.end_seconds() == other.end_seconds()
+    }
+}
+
+impl Eq for Interval {}
+
+/// A set of instants stored as sorted, non-overlapping intervals.
+///
+/// Overlapping and touching intervals are merged as they are inserted, and empty intervals are
+/// dropped, so every set has a single normalized form.
+///
+/// # Example:
+/// ```
+/// use timekit::{DateTime, Interval, IntervalSet, TimeZone};
+/// let at = |hour| DateTime::new(2024, 5, 1, hour, 0, 0, TimeZone::UTC).unwrap();
+/// let open: IntervalSet = [Interval::new(at(9), at(18)).unwrap()].into_iter().collect();
+/// let booked: IntervalSet = [
+///     Interval::new(at(10), at(11)).unwrap(),
+///     Interval::new(at(13), at(15)).unwrap(),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let free = open.subtract(&booked);
+/// assert_eq!(free.len(), 3);
+/// assert_eq!(free.total_duration().hours, 6);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an interval, merging it with any interval it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let mut merged = interval;
+        let mut kept = Vec::with_capacity(self.intervals.len() + 1);
+        for existing in self.intervals.drain(..) {
+            match merged.union(&existing) {
+                Some(union) => merged = union,
+                None => kept.push(existing),
+            }
+        }
+        let position = kept
+            .iter()
+            .position(|existing| existing.start_seconds() > merged.start_seconds())
+            .unwrap_or(kept.len());
+        kept.insert(position, merged);
+        self.intervals = kept;
+    }
+
+    /// Returns the intervals of the set in chronological order.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.intervals.iter()
+    }
+
+    /// Returns the number of disjoint intervals in the set.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Returns `true` if the set covers no time.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns `true` if any interval of the set contains `datetime`.
+    pub fn contains(&self, datetime: &DateTime) -> bool {
+        self.intervals
+            .iter()
+            .any(|interval| interval.contains(datetime))
+    }
+
+    /// Returns the total time covered by the set.
+    pub fn total_duration(&self) -> TimeDelta {
+        TimeDelta::from_seconds(
+            self.intervals
+                .iter()
+                .map(|interval| interval.end_seconds() - interval.start_seconds())
+                .sum(),
+        )
+    }
+
+    /// Returns every instant covered by either set.
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = self.clone();
+        for interval in &other.intervals {
+            result.insert(*interval);
+        }
+        result
+    }
+
+    /// Returns every instant covered by both sets.
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        self.intervals
+            .iter()
+            .flat_map(|a| other.intervals.iter().filter_map(|b| a.intersection(b)))
+            .collect()
+    }
+
+    /// Returns every instant covered by this set but not by `other`.
+    pub fn subtract(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = Vec::new();
+        for interval in &self.intervals {
+            let mut remaining = Some(*interval);
+            for cut in &other.intervals {
+                let Some(current) = remaining else { break };
+                if !current.overlaps(cut) {
+                    continue;
+                }
+                if cut.start_seconds() > current.start_seconds() {
+                    result.push(Interval {
+                        start: current.start,
+                        end: cut.start,
+                    });
+                }
+                remaining = (cut.end_seconds() < current.end_seconds()).then_some(Interval {
+                    start: cut.end,
+                    end: current.end,
+                });
+            }
+            result.extend(remaining);
+        }
+        result.into_iter().collect()
+    }
+
+    /// Returns the spans between consecutive intervals of the set.
+    pub fn gaps(&self) -> IntervalSet {
+        self.intervals
+            .windows(2)
+            .filter_map(|pair| pair[0].gap(&pair[1]))
+            .collect()
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl<'a> IntoIterator for &'a IntervalSet {
+    type Item = &'a Interval;
+    type IntoIter = std::slice::Iter<'a, Interval>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+fn earliest(a: DateTime, b: DateTime) -> DateTime {
+    if b.to_unix_seconds() < a.to_unix_seconds() {
+        b
+    } else {
+        a
+    }
+}
+
+fn latest(a: DateTime, b: DateTime) -> DateTime {
+    if b.to_unix_seconds() > a.to_unix_seconds() {
+        b
+    } else {
+        a
+    }
+}
@@ -0,0 +1,341 @@
+use crate::{
+    Date, DateTime, Interval, IsoDuration, OffsetDateTime, OffsetFormat, TimeDelta, TimeZone,
+};
+use std::fmt;
+use std::iter::FusedIterator;
+use std::str::FromStr;
+
+/// An ISO 8601 time interval in one of its four forms.
+///
+/// Endpoints keep the UTC offset they were written with, and calendar components of a
+/// duration are applied on the wall clock of the endpoint they are measured from. An
+/// endpoint written as a date alone, such as `2024-03-01`, is midnight UTC, and is written
+/// back as a full timestamp.
+///
+/// # Example:
+/// ```
+/// use timekit::{IsoInterval, TimeZone};
+/// let interval: IsoInterval = "2024-03-01T13:00:00Z/P1Y2M10DT2H30M".parse().unwrap();
+/// let resolved = interval.to_interval(TimeZone::UTC).unwrap();
+/// assert_eq!(resolved.end().to_string(), "2025-05-11 15:30:00");
+/// assert_eq!(interval.to_string(), "2024-03-01T13:00:00Z/P1Y2M10DT2H30M");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsoInterval {
+    /// `start/end`, such as `2024-03-01T13:00:00Z/2024-05-11T15:30:00Z`
+    StartEnd(OffsetDateTime, OffsetDateTime),
+    /// `start/duration`, such as `2024-03-01T13:00:00Z/P1Y2M10DT2H30M`
+    StartDuration(OffsetDateTime, IsoDuration),
+    /// `duration/end`, such as `P1Y2M10DT2H30M/2025-05-11T15:30:00Z`
+    DurationEnd(IsoDuration, OffsetDateTime),
+    /// A duration with no context, such as `P1Y2M10DT2H30M`
+    Duration(IsoDuration),
+}
+
+impl IsoInterval {
+    /// Parses an interval in any of the four ISO 8601 forms.
+    ///
+    /// Durations must not be negative, and an explicit end must not be before its start. The
+    /// end of a `start/end` interval may leave out the leading fields it shares with the
+    /// start, as in `2024-03-01T13:00:00Z/15:30` or `2024-03-01/05`, and then also takes the
+    /// start's offset unless it has its own.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{IsoInterval, TimeZone};
+    /// let meeting: IsoInterval = "2024-03-01T13:00:00+09:00/15:30".parse().unwrap();
+    /// assert_eq!(
+    ///     meeting.to_string(),
+    ///     "2024-03-01T13:00:00+09:00/2024-03-01T15:30:00+09:00"
+    /// );
+    /// let day: IsoInterval = "2024-03-01/P1D".parse().unwrap();
+    /// let resolved = day.to_interval(TimeZone::UTC).unwrap();
+    /// assert_eq!(resolved.start().to_string(), "2024-03-01 00:00:00");
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        let interval = match s.split_once('/') {
+            None => Self::Duration(parse_duration(s)?),
+            Some((first, second)) => match (is_duration(first), is_duration(second)) {
+                (false, false) => {
+                    let start = parse_endpoint(first)?;
+                    let end = parse_end(second, &start)?;
+                    if end < start {
+                        return Err(format!("Interval end is before its start: {}", s));
+                    }
+                    Self::StartEnd(start, end)
+                }
+                (false, true) => {
+                    Self::StartDuration(parse_endpoint(first)?, parse_duration(second)?)
+                }
+                (true, false) => Self::DurationEnd(parse_duration(first)?, parse_endpoint(second)?),
+                (true, true) => {
+                    return Err(format!("Interval cannot have two durations: {}", s));
+                }
+            },
+        };
+        Ok(interval)
+    }
+
+    /// Returns the duration of the interval, if it was written with one.
+    pub fn duration(&self) -> Option<IsoDuration> {
+        match self {
+            Self::StartEnd(..) => None,
+            Self::StartDuration(_, duration)
+            | Self::DurationEnd(duration, _)
+            | Self::Duration(duration) => Some(*duration),
+        }
+    }
+
+    /// Resolves the interval into an `Interval` with endpoints in `timezone`.
+    ///
+    /// Fails for the duration-only form, which has no position in time.
+    pub fn to_interval(&self, timezone: TimeZone) -> Result<Interval, String> {
+        let (start, end) = match self {
+            Self::StartEnd(start, end) => (*start, *end),
+            Self::StartDuration(start, duration) => (*start, shift(start, duration, 1)?),
+            Self::DurationEnd(duration, end) => (shift(end, duration, -1)?, *end),
+            Self::Duration(_) => {
+                return Err("A duration alone cannot be resolved to an interval".to_string())
+            }
+        };
+        Interval::new(start.to_datetime(timezone)?, end.to_datetime(timezone)?)
+    }
+}
+
+impl fmt::Display for IsoInterval {
+    /// Formats the interval in ISO 8601, writing a zero offset as `Z`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let timestamp = |at: &OffsetDateTime| at.to_rfc3339(OffsetFormat::Zulu);
+        match self {
+            Self::StartEnd(start, end) => write!(f, "{}/{}", timestamp(start), timestamp(end)),
+            Self::StartDuration(start, duration) => {
+                write!(f, "{}/{}", timestamp(start), duration)
+            }
+            Self::DurationEnd(duration, end) => write!(f, "{}/{}", duration, timestamp(end)),
+            Self::Duration(duration) => write!(f, "{}", duration),
+        }
+    }
+}
+
+impl FromStr for IsoInterval {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// An ISO 8601 repeating interval, such as `R5/2024-01-01T00:00:00Z/P1D`.
+///
+/// `repetitions` is the number of occurrences, or `None` for `R/...`, which repeats without
+/// end.
+///
+/// # Example:
+/// ```
+/// use timekit::{RepeatingInterval, TimeZone};
+/// let monthly: RepeatingInterval = "R3/2024-01-30T09:00:00+09:00/P1M".parse().unwrap();
+/// let starts: Vec<String> = monthly
+///     .occurrences(TimeZone::KST)
+///     .unwrap()
+///     .map(|datetime| datetime.to_string())
+///     .collect();
+/// assert_eq!(
+///     starts,
+///     ["2024-01-30 09:00:00", "2024-02-29 09:00:00", "2024-03-30 09:00:00"]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RepeatingInterval {
+    pub repetitions: Option<u64>,
+    pub interval: IsoInterval,
+}
+
+impl RepeatingInterval {
+    /// Parses a repeating interval written as `Rn/interval` or `R/interval`.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        let invalid = || format!("Invalid repeating interval: {}", s);
+        let (count, interval) = s.split_once('/').ok_or_else(invalid)?;
+        let count = count.strip_prefix(['R', 'r']).ok_or_else(invalid)?;
+        let repetitions = if count.is_empty() {
+            None
+        } else if count.bytes().all(|b| b.is_ascii_digit()) {
+            Some(count.parse().map_err(|_| invalid())?)
+        } else {
+            return Err(invalid());
+        };
+        Ok(Self {
+            repetitions,
+            interval: IsoInterval::parse(interval)?,
+        })
+    }
+
+    /// Returns a lazy iterator over the start of each occurrence, in `timezone`.
+    ///
+    /// Each occurrence is measured from the interval's anchor rather than from the previous
+    /// occurrence, so `P1M` from January 31 gives the last day of each following month.
+    /// Occurrences of a `start/...` interval run forward from the start. For `duration/end`,
+    /// the last occurrence ends at `end`: a bounded repetition is yielded in chronological
+    /// order, while an unbounded one runs backwards from `end`.
+    ///
+    /// Fails for a duration with no start or end, or an interval of zero length.
+    pub fn occurrences(&self, timezone: TimeZone) -> Result<IsoOccurrences, String> {
+        let (anchor, step, first, direction) = match self.interval {
+            IsoInterval::StartEnd(start, end) => {
+                let seconds = end.unix_seconds() - start.unix_seconds();
+                (start, TimeDelta::from_seconds(seconds).into(), 0, 1)
+            }
+            IsoInterval::StartDuration(start, duration) => (start, duration, 0, 1),
+            IsoInterval::DurationEnd(duration, end) => match self.repetitions {
+                Some(repetitions) => {
+                    let first = i64::try_from(repetitions)
+                        .map_err(|_| "Too many repetitions".to_string())?;
+                    (end, duration, -first, 1)
+                }
+                None => (end, duration, -1, -1),
+            },
+            IsoInterval::Duration(_) => {
+                return Err("A duration alone has no occurrences".to_string());
+            }
+        };
+        if step.is_zero() {
+            return Err("A repeating interval must not have zero length".to_string());
+        }
+        Ok(IsoOccurrences {
+            anchor,
+            step,
+            factor: first,
+            direction,
+            remaining: self.repetitions,
+            timezone,
+        })
+    }
+}
+
+impl fmt::Display for RepeatingInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.repetitions {
+            Some(repetitions) => write!(f, "R{}/{}", repetitions, self.interval),
+            None => write!(f, "R/{}", self.interval),
+        }
+    }
+}
+
+impl FromStr for RepeatingInterval {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// The occurrence starts of a `RepeatingInterval`, returned by
+/// `RepeatingInterval::occurrences`.
+///
+/// The iterator ends early if an occurrence cannot be represented, such as one before 1970.
+#[derive(Debug, Clone)]
+pub struct IsoOccurrences {
+    anchor: OffsetDateTime,
+    step: IsoDuration,
+    factor: i64,
+    direction: i64,
+    remaining: Option<u64>,
+    timezone: TimeZone,
+}
+
+impl Iterator for IsoOccurrences {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<DateTime> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let occurrence = shift(&self.anchor, &self.step, self.factor)
+            .and_then(|at| at.to_datetime(self.timezone));
+        let Ok(occurrence) = occurrence else {
+            self.remaining = Some(0);
+            return None;
+        };
+        self.remaining = self.remaining.map(|remaining| remaining - 1);
+        self.factor += self.direction;
+        Some(occurrence)
+    }
+}
+
+impl FusedIterator for IsoOccurrences {}
+
+// A part of an interval is a duration unless it starts like a timestamp.
+fn is_duration(part: &str) -> bool {
+    !part.starts_with(|c: char| c.is_ascii_digit())
+}
+
+// A timestamp with an offset, or a date alone at midnight UTC.
+fn parse_endpoint(s: &str) -> Result<OffsetDateTime, String> {
+    if s.contains(['T', 't', ' ']) {
+        return OffsetDateTime::parse(s);
+    }
+    let date: Date = s.parse()?;
+    OffsetDateTime::from_local(date.year, date.month, date.day, 0, 0, 0, 0)
+}
+
+// The end of a `start/end` interval. An end that does not start with a year takes the
+// fields it leaves out from the local date of `start`, and its offset too when it has a time
+// without one. Minutes may end the time, as in `15:30`.
+fn parse_end(s: &str, start: &OffsetDateTime) -> Result<OffsetDateTime, String> {
+    if s.find('-') == Some(4) {
+        return parse_endpoint(s);
+    }
+    let invalid = || format!("Invalid interval end: {}", s);
+    let (date, time) = match s.split_once(['T', 't']) {
+        Some((date, time)) => (date, Some(time)),
+        None if s.contains(':') => ("", Some(s)),
+        None => (s, None),
+    };
+    if ![0, 2, 5].contains(&date.len()) {
+        return Err(invalid());
+    }
+    let local = start.local();
+    let start_date = format!("{:04}-{:02}-{:02}", local.year, local.month, local.day);
+    let date = format!("{}{}", &start_date[..start_date.len() - date.len()], date);
+    let Some(time) = time else {
+        return parse_endpoint(&date);
+    };
+    let (clock, offset) = match time.find(['Z', 'z', '+', '-']) {
+        Some(index) => time.split_at(index),
+        None => (time, ""),
+    };
+    let seconds = if clock.len() == 5 { ":00" } else { "" };
+    let offset = if offset.is_empty() {
+        start.format_offset(OffsetFormat::Extended)
+    } else {
+        offset.to_string()
+    };
+    OffsetDateTime::parse(&format!("{}T{}{}{}", date, clock, seconds, offset))
+        .map_err(|_| invalid())
+}
+
+fn parse_duration(s: &str) -> Result<IsoDuration, String> {
+    let duration = IsoDuration::parse(s)?;
+    if duration.has_negative_component() {
+        return Err(format!("Interval duration must not be negative: {}", s));
+    }
+    Ok(duration)
+}
+
+// `at` moved by `duration` times `factor` on its local wall clock, keeping its offset.
+fn shift(
+    at: &OffsetDateTime,
+    duration: &IsoDuration,
+    factor: i64,
+) -> Result<OffsetDateTime, String> {
+    let scaled = duration.checked_mul(factor.abs())?;
+    let local = at.local();
+    let moved = if factor < 0 {
+        scaled.sub_from(&local)?
+    } else {
+        scaled.add_to(&local)?
+    };
+    let offset = at.offset_in_seconds();
+    OffsetDateTime::new(moved.to_unix_seconds() - offset, offset)
+}
@@ -0,0 +1,331 @@
+use crate::holiday::{Holiday, HolidayProvider};
+use crate::{Date, Weekday};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// A Japanese era (gengō), from Meiji through Reiwa.
+///
+/// Era years count from 1 in the year the era began, so the last year of one era is also
+/// the first year of the next. Dates before 1873, when Japan adopted the Gregorian
+/// calendar, are converted as proleptic Gregorian dates.
+///
+/// # Example:
+/// ```
+/// use timekit::{Date, JapaneseEra};
+/// let date = Date::new(2024, 5, 1).unwrap();
+/// assert_eq!(date.japanese_era().unwrap(), (JapaneseEra::Reiwa, 6));
+/// assert_eq!(JapaneseEra::Reiwa.format_year(6), "令和6年");
+///
+/// // The Heisei era began on January 8th, 1989
+/// let showa = Date::new(1989, 1, 7).unwrap();
+/// assert_eq!(showa.japanese_era().unwrap(), (JapaneseEra::Showa, 64));
+/// assert_eq!(Date::from_japanese_era(JapaneseEra::Heisei, 1, 1, 8).unwrap().year, 1989);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum JapaneseEra {
+    Meiji,
+    Taisho,
+    Showa,
+    Heisei,
+    Reiwa,
+}
+
+const ALL_ERAS: [JapaneseEra; 5] = [
+    JapaneseEra::Meiji,
+    JapaneseEra::Taisho,
+    JapaneseEra::Showa,
+    JapaneseEra::Heisei,
+    JapaneseEra::Reiwa,
+];
+
+impl JapaneseEra {
+    /// Returns an iterator over all eras, from Meiji to Reiwa.
+    pub fn all() -> impl Iterator<Item = JapaneseEra> {
+        ALL_ERAS.iter().copied()
+    }
+
+    /// Returns the romanized name of the era, e.g. `"Reiwa"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            JapaneseEra::Meiji => "Meiji",
+            JapaneseEra::Taisho => "Taisho",
+            JapaneseEra::Showa => "Showa",
+            JapaneseEra::Heisei => "Heisei",
+            JapaneseEra::Reiwa => "Reiwa",
+        }
+    }
+
+    /// Returns the name of the era in kanji, e.g. `"令和"`.
+    pub fn kanji(&self) -> &'static str {
+        match self {
+            JapaneseEra::Meiji => "明治",
+            JapaneseEra::Taisho => "大正",
+            JapaneseEra::Showa => "昭和",
+            JapaneseEra::Heisei => "平成",
+            JapaneseEra::Reiwa => "令和",
+        }
+    }
+
+    /// Returns the one-letter abbreviation used in forms, e.g. `'R'`.
+    pub fn abbreviation(&self) -> char {
+        match self {
+            JapaneseEra::Meiji => 'M',
+            JapaneseEra::Taisho => 'T',
+            JapaneseEra::Showa => 'S',
+            JapaneseEra::Heisei => 'H',
+            JapaneseEra::Reiwa => 'R',
+        }
+    }
+
+    /// Returns the first day of the era.
+    pub fn start(&self) -> Date {
+        let (year, month, day) = match self {
+            JapaneseEra::Meiji => (1868, 10, 23),
+            JapaneseEra::Taisho => (1912, 7, 30),
+            JapaneseEra::Showa => (1926, 12, 25),
+            JapaneseEra::Heisei => (1989, 1, 8),
+            JapaneseEra::Reiwa => (2019, 5, 1),
+        };
+        Date { year, month, day }
+    }
+
+    /// Returns the Gregorian year of an era year.
+    pub fn gregorian_year(&self, era_year: u64) -> u64 {
+        self.start().year + era_year - 1
+    }
+
+    /// Formats an era year in kanji, e.g. `令和6年`, writing the first year as `元年`.
+    pub fn format_year(&self, era_year: u64) -> String {
+        if era_year == 1 {
+            format!("{}元年", self.kanji())
+        } else {
+            format!("{}{}年", self.kanji(), era_year)
+        }
+    }
+}
+
+impl fmt::Display for JapaneseEra {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for JapaneseEra {
+    type Err = String;
+
+    /// Parses a romanized name (case-insensitive), a kanji name or a one-letter abbreviation.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        JapaneseEra::all()
+            .find(|era| {
+                era.name().eq_ignore_ascii_case(s)
+                    || era.kanji() == s
+                    || s.len() == 1 && s.eq_ignore_ascii_case(&era.abbreviation().to_string())
+            })
+            .ok_or_else(|| format!("Invalid Japanese era: {}", s))
+    }
+}
+
+impl Date {
+    /// Returns the Japanese era of the date and the year within it.
+    pub fn japanese_era(&self) -> Result<(JapaneseEra, u64), String> {
+        let era = ALL_ERAS
+            .iter()
+            .copied()
+            .rev()
+            .find(|era| era.start() <= *self)
+            .ok_or_else(|| "Date is before the Meiji era".to_string())?;
+        Ok((era, self.year - era.start().year + 1))
+    }
+
+    /// Creates a `Date` from a Japanese era year, checking that the date falls in that era.
+    pub fn from_japanese_era(
+        era: JapaneseEra,
+        era_year: u64,
+        month: u64,
+        day: u64,
+    ) -> Result<Self, String> {
+        if era_year == 0 {
+            return Err("Era years start at 1".to_string());
+        }
+        let date = Self::new(era.gregorian_year(era_year), month, day)?;
+        if date.japanese_era()?.0 != era {
+            return Err(format!("{} is not in the {} era", date, era));
+        }
+        Ok(date)
+    }
+}
+
+/// Japanese national holidays, as set by the Act on National Holidays, from 1970 through
+/// 2100.
+///
+/// Moving holidays follow the rules of each year, including the Happy Monday system from
+/// 2000, which put Coming of Age Day, Marine Day, Respect for the Aged Day and Sports Day on
+/// Mondays. The equinox days use the dates the National Astronomical Observatory predicts
+/// from the equinoxes in Japan Standard Time. From 1973, a holiday falling on a Sunday adds a
+/// substitute holiday (the next day until 2006, the next non-holiday from 2007), and from
+/// 1986 a day between two holidays is itself a holiday. One-off holidays set by law, such
+/// as the 2019 enthronement, are included.
+///
+/// # Example:
+/// ```
+/// use timekit::{Date, HolidayProvider, JapaneseHolidays};
+/// let holidays = JapaneseHolidays.holidays(2024).unwrap();
+/// let find = |name: &str| holidays.iter().find(|holiday| holiday.name == name).unwrap().date;
+/// assert_eq!(find("Coming of Age Day"), Date::new(2024, 1, 8).unwrap());
+/// assert_eq!(find("Vernal Equinox Day"), Date::new(2024, 3, 20).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JapaneseHolidays;
+
+impl JapaneseHolidays {
+    // National holidays in `year`, without substitute and in-between holidays.
+    fn national_holidays(year: u64) -> Result<BTreeMap<Date, &'static str>, String> {
+        let mut holidays = BTreeMap::new();
+        let date = |month, day| Date::new(year, month, day);
+        let monday = |month, n| Date::nth_weekday_of_month(year, month, Weekday::Monday, n);
+
+        holidays.insert(date(1, 1)?, "New Year's Day");
+        let coming_of_age = if year >= 2000 {
+            monday(1, 2)?
+        } else {
+            date(1, 15)?
+        };
+        holidays.insert(coming_of_age, "Coming of Age Day");
+        holidays.insert(date(2, 11)?, "National Foundation Day");
+        if year >= 2020 {
+            holidays.insert(date(2, 23)?, "Emperor's Birthday");
+        }
+        holidays.insert(date(3, equinox(year, false))?, "Vernal Equinox Day");
+        let april_29 = match year {
+            ..=1988 => "Emperor's Birthday",
+            1989..=2006 => "Greenery Day",
+            _ => "Showa Day",
+        };
+        holidays.insert(date(4, 29)?, april_29);
+        holidays.insert(date(5, 3)?, "Constitution Memorial Day");
+        if year >= 2007 {
+            holidays.insert(date(5, 4)?, "Greenery Day");
+        }
+        holidays.insert(date(5, 5)?, "Children's Day");
+        // Marine Day, Mountain Day and Sports Day moved for the Tokyo Olympics.
+        let marine = match year {
+            ..=1995 => None,
+            1996..=2002 => Some(date(7, 20)?),
+            2020 => Some(date(7, 23)?),
+            2021 => Some(date(7, 22)?),
+            _ => Some(monday(7, 3)?),
+        };
+        if let Some(marine) = marine {
+            holidays.insert(marine, "Marine Day");
+        }
+        let mountain = match year {
+            ..=2015 => None,
+            2020 => Some(date(8, 10)?),
+            2021 => Some(date(8, 8)?),
+            _ => Some(date(8, 11)?),
+        };
+        if let Some(mountain) = mountain {
+            holidays.insert(mountain, "Mountain Day");
+        }
+        let aged = if year >= 2003 {
+            monday(9, 3)?
+        } else {
+            date(9, 15)?
+        };
+        holidays.insert(aged, "Respect for the Aged Day");
+        holidays.insert(date(9, equinox(year, true))?, "Autumnal Equinox Day");
+        let sports = match year {
+            ..=1999 => date(10, 10)?,
+            2020 => date(7, 24)?,
+            2021 => date(7, 23)?,
+            _ => monday(10, 2)?,
+        };
+        let sports_name = if year >= 2020 {
+            "Sports Day"
+        } else {
+            "Health and Sports Day"
+        };
+        holidays.insert(sports, sports_name);
+        holidays.insert(date(11, 3)?, "Culture Day");
+        holidays.insert(date(11, 23)?, "Labour Thanksgiving Day");
+        if (1989..=2018).contains(&year) {
+            holidays.insert(date(12, 23)?, "Emperor's Birthday");
+        }
+
+        // Holidays set by individual laws.
+        let one_off = match year {
+            1989 => Some((2, 24, "State Funeral of Emperor Showa")),
+            1990 => Some((11, 12, "Enthronement Ceremony")),
+            1993 => Some((6, 9, "Wedding of Crown Prince Naruhito")),
+            _ => None,
+        };
+        if let Some((month, day, name)) = one_off {
+            holidays.insert(date(month, day)?, name);
+        }
+        if year == 2019 {
+            holidays.insert(date(5, 1)?, "Enthronement Day");
+            holidays.insert(date(10, 22)?, "Enthronement Ceremony");
+        }
+        Ok(holidays)
+    }
+}
+
+// The day of March or September on which the equinox falls in Japan Standard Time, from
+// the approximation used by the National Astronomical Observatory of Japan.
+fn equinox(year: u64, autumn: bool) -> u64 {
+    let base = match (year, autumn) {
+        (..=1979, false) => 20.8357,
+        (..=1979, true) => 23.2588,
+        (1980..=2099, false) => 20.8431,
+        (1980..=2099, true) => 23.2488,
+        (_, false) => 21.8510,
+        (_, true) => 24.2488,
+    };
+    let years = year as f64 - 1980.0;
+    (base + 0.242194 * years - (years / 4.0).floor()).floor() as u64
+}
+
+impl HolidayProvider for JapaneseHolidays {
+    fn holidays(&self, year: u64) -> Result<Vec<Holiday>, String> {
+        if !(1970..=2100).contains(&year) {
+            return Err("Japanese holidays are only available from 1970 to 2100".to_string());
+        }
+        let national = Self::national_holidays(year)?;
+        let mut holidays: BTreeMap<Date, &str> = national.clone();
+
+        // Substitute holidays, from April 12th, 1973.
+        let substitutes_from = Date::new(1973, 4, 12)?;
+        for date in national.keys() {
+            if date.weekday() != Weekday::Sunday || *date < substitutes_from {
+                continue;
+            }
+            let mut substitute = date.add_days(1)?;
+            if year >= 2007 {
+                while holidays.contains_key(&substitute) {
+                    substitute = substitute.add_days(1)?;
+                }
+            }
+            holidays.entry(substitute).or_insert("Substitute Holiday");
+        }
+
+        // Days between two national holidays, from 1986.
+        if year >= 1986 {
+            for (date, next) in national.keys().zip(national.keys().skip(1)) {
+                let between = date.add_days(1)?;
+                if next.days_since_epoch() - date.days_since_epoch() == 2
+                    && !holidays.contains_key(&between)
+                    && (year >= 2007 || between.weekday() != Weekday::Sunday)
+                {
+                    holidays.insert(between, "Citizen's Holiday");
+                }
+            }
+        }
+
+        Ok(holidays
+            .into_iter()
+            .map(|(date, name)| Holiday::new(date, name))
+            .collect())
+    }
+}
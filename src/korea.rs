@@ -0,0 +1,259 @@
+use crate::holiday::{Holiday, HolidayProvider};
+use crate::{Date, LunarCalendar, LunarDate, Weekday};
+use std::collections::BTreeSet;
+
+/// South Korean public holidays, as set by the Regulations on Holidays of Government
+/// Offices, from 1970 through 2100.
+///
+/// Seollal, Buddha's Birthday and Chuseok are computed from the Korean lunar calendar.
+/// Holidays that were added or dropped over the years (Arbor Day, Constitution Day, Hangul
+/// Day and others) are listed only in the years they were in force, and substitute holidays
+/// follow the rules of each year:
+/// - March 1989 to November 1990: a holiday falling on a Sunday moves to the next day.
+/// - From 2014: Seollal and Chuseok when a day of the break falls on a Sunday or on another
+///   holiday, and Children's Day when it falls on a weekend or on another holiday.
+/// - From 2021: the national days (Independence Movement Day, Liberation Day, National
+///   Foundation Day and Hangul Day), on the same terms as Children's Day.
+/// - From 2023: Buddha's Birthday and Christmas Day, on the same terms.
+///
+/// A substitute holiday is the first weekday after the holiday (or after the whole Seollal
+/// or Chuseok break) that is not already a holiday. Election days and one-off temporary
+/// holidays are not included.
+///
+/// # Example:
+/// ```
+/// use timekit::{Date, HolidayProvider, KoreanHolidays};
+/// let holidays = KoreanHolidays.holidays(2024).unwrap();
+/// let names: Vec<&str> = holidays
+///     .iter()
+///     .filter(|holiday| holiday.date.month == 2)
+///     .map(|holiday| holiday.name.as_str())
+///     .collect();
+/// // Seollal fell on a Saturday, and the day after it on a Sunday
+/// assert_eq!(
+///     names,
+///     ["Seollal Holiday", "Seollal", "Seollal Holiday", "Substitute Holiday (Seollal)"]
+/// );
+/// assert_eq!(holidays[4].date, Date::new(2024, 2, 12).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KoreanHolidays;
+
+// When a holiday earns a substitute day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Substitute {
+    Never,
+    Sunday,
+    SundayOrOverlap,
+    WeekendOrOverlap,
+}
+
+// A holiday before substitutes are assigned.
+struct Entry {
+    date: Date,
+    name: &'static str,
+    substitute: Substitute,
+    // The holiday (or break) a substitute is named after, and the day it ends.
+    group: &'static str,
+    end: Date,
+}
+
+impl KoreanHolidays {
+    // Holidays in `year`, without substitute holidays.
+    fn entries(year: u64) -> Result<Vec<Entry>, String> {
+        let mut entries = Vec::new();
+        let mut fixed = |month, day, name, substitute| -> Result<(), String> {
+            let date = Date::new(year, month, day)?;
+            entries.push(Entry {
+                date,
+                name,
+                substitute,
+                group: name,
+                end: date,
+            });
+            Ok(())
+        };
+        let national = if year >= 2021 {
+            Substitute::WeekendOrOverlap
+        } else {
+            Substitute::Never
+        };
+
+        fixed(1, 1, "New Year's Day", Substitute::Never)?;
+        if year <= 1998 {
+            fixed(1, 2, "New Year Holiday", Substitute::Never)?;
+        }
+        if year <= 1990 {
+            fixed(1, 3, "New Year Holiday", Substitute::Never)?;
+        }
+        fixed(3, 1, "Independence Movement Day", national)?;
+        if year <= 2005 {
+            fixed(4, 5, "Arbor Day", Substitute::Never)?;
+        }
+        if year >= 1975 {
+            let substitute = if year >= 2014 {
+                Substitute::WeekendOrOverlap
+            } else {
+                Substitute::Never
+            };
+            fixed(5, 5, "Children's Day", substitute)?;
+        }
+        fixed(6, 6, "Memorial Day", Substitute::Never)?;
+        if year <= 2007 {
+            fixed(7, 17, "Constitution Day", Substitute::Never)?;
+        }
+        fixed(8, 15, "Liberation Day", national)?;
+        if (1976..=1990).contains(&year) {
+            fixed(10, 1, "Armed Forces Day", Substitute::Never)?;
+        }
+        fixed(10, 3, "National Foundation Day", national)?;
+        if year <= 1990 || year >= 2013 {
+            fixed(10, 9, "Hangul Day", national)?;
+        }
+        if year <= 1975 {
+            fixed(10, 24, "United Nations Day", Substitute::Never)?;
+        }
+        let late = if year >= 2023 {
+            Substitute::WeekendOrOverlap
+        } else {
+            Substitute::Never
+        };
+        fixed(12, 25, "Christmas Day", late)?;
+
+        let lunar = |month, day| {
+            LunarDate::new(LunarCalendar::Korean, year, month, false, day)
+                .map(|date| date.to_date())
+        };
+        let breaks = if year >= 2014 {
+            Substitute::SundayOrOverlap
+        } else {
+            Substitute::Never
+        };
+        // Seollal: a single "Folk Day" from 1985, a three-day break from 1989.
+        let seollal = lunar(1, 1)?;
+        if year >= 1989 {
+            entries.extend(Self::break_days(seollal, "Seollal", breaks)?);
+        } else if year >= 1985 {
+            entries.push(Entry {
+                date: seollal,
+                name: "Folk Day",
+                substitute: Substitute::Never,
+                group: "Folk Day",
+                end: seollal,
+            });
+        }
+        if year >= 1975 {
+            let date = lunar(4, 8)?;
+            entries.push(Entry {
+                date,
+                name: "Buddha's Birthday",
+                substitute: late,
+                group: "Buddha's Birthday",
+                end: date,
+            });
+        }
+        // Chuseok: one day until 1985, two days from 1986, three days from 1989.
+        let chuseok = lunar(8, 15)?;
+        if year >= 1989 {
+            entries.extend(Self::break_days(chuseok, "Chuseok", breaks)?);
+        } else {
+            let end = if year >= 1986 {
+                chuseok.add_days(1)?
+            } else {
+                chuseok
+            };
+            let entry = |date, name| Entry {
+                date,
+                name,
+                substitute: Substitute::Never,
+                group: "Chuseok",
+                end,
+            };
+            entries.push(entry(chuseok, "Chuseok"));
+            if end != chuseok {
+                entries.push(entry(end, "Chuseok Holiday"));
+            }
+        }
+
+        // The short-lived rule of 1989 and 1990.
+        let first = Date::new(1989, 3, 1)?;
+        let last = Date::new(1990, 11, 9)?;
+        for entry in &mut entries {
+            if (first..=last).contains(&entry.date) {
+                entry.substitute = Substitute::Sunday;
+            }
+        }
+        entries.sort_by_key(|entry| entry.date);
+        Ok(entries)
+    }
+
+    // The day before, the day of and the day after a lunar holiday.
+    fn break_days(
+        day: Date,
+        name: &'static str,
+        substitute: Substitute,
+    ) -> Result<[Entry; 3], String> {
+        let (before, after) = (day.add_days(-1)?, day.add_days(1)?);
+        let holiday_name = if name == "Seollal" {
+            "Seollal Holiday"
+        } else {
+            "Chuseok Holiday"
+        };
+        let entry = |date, entry_name| Entry {
+            date,
+            name: entry_name,
+            substitute,
+            group: name,
+            end: after,
+        };
+        Ok([
+            entry(before, holiday_name),
+            entry(day, name),
+            entry(after, holiday_name),
+        ])
+    }
+}
+
+impl HolidayProvider for KoreanHolidays {
+    fn holidays(&self, year: u64) -> Result<Vec<Holiday>, String> {
+        if !(1970..=2100).contains(&year) {
+            return Err("Korean holidays are only available from 1970 to 2100".to_string());
+        }
+        let entries = Self::entries(year)?;
+        let mut taken: BTreeSet<Date> = entries.iter().map(|entry| entry.date).collect();
+        let mut holidays: Vec<Holiday> = entries
+            .iter()
+            .map(|entry| Holiday::new(entry.date, entry.name))
+            .collect();
+
+        let dates: Vec<Date> = taken.iter().copied().collect();
+        for date in dates {
+            let on_day: Vec<&Entry> = entries.iter().filter(|entry| entry.date == date).collect();
+            let weekday = date.weekday();
+            let overlap = on_day.len() > 1;
+            let Some(entry) = on_day.iter().find(|entry| match entry.substitute {
+                Substitute::Never => false,
+                Substitute::Sunday => weekday == Weekday::Sunday,
+                Substitute::SundayOrOverlap => weekday == Weekday::Sunday || overlap,
+                Substitute::WeekendOrOverlap => {
+                    matches!(weekday, Weekday::Saturday | Weekday::Sunday) || overlap
+                }
+            }) else {
+                continue;
+            };
+            let mut substitute = entry.end.add_days(1)?;
+            while matches!(substitute.weekday(), Weekday::Saturday | Weekday::Sunday)
+                || taken.contains(&substitute)
+            {
+                substitute = substitute.add_days(1)?;
+            }
+            taken.insert(substitute);
+            holidays.push(Holiday {
+                date: substitute,
+                name: format!("Substitute Holiday ({})", entry.group),
+            });
+        }
+        holidays.sort_by_key(|holiday| holiday.date);
+        Ok(holidays)
+    }
+}
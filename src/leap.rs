@@ -0,0 +1,309 @@
+use crate::constants::*;
+use crate::{DateTime, TimeZone};
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Seconds between the NTP epoch (1900-01-01) and the Unix epoch (1970-01-01).
+const NTP_UNIX_OFFSET: i64 = 2_208_988_800;
+
+/// TAI − UTC in effect on 1972-01-01, before the first inserted leap second.
+const INITIAL_TAI_MINUS_UTC: i64 = 10;
+
+// (Unix second at which the new offset takes effect, TAI − UTC from then on)
+const BUILTIN_LEAP_SECONDS: [(i64, i64); 28] = [
+    (63_072_000, 10),    // 1972-01-01
+    (78_796_800, 11),    // 1972-07-01
+    (94_694_400, 12),    // 1973-01-01
+    (126_230_400, 13),   // 1974-01-01
+    (157_766_400, 14),   // 1975-01-01
+    (189_302_400, 15),   // 1976-01-01
+    (220_924_800, 16),   // 1977-01-01
+    (252_460_800, 17),   // 1978-01-01
+    (283_996_800, 18),   // 1979-01-01
+    (315_532_800, 19),   // 1980-01-01
+    (362_793_600, 20),   // 1981-07-01
+    (394_329_600, 21),   // 1982-07-01
+    (425_865_600, 22),   // 1983-07-01
+    (489_024_000, 23),   // 1985-07-01
+    (567_993_600, 24),   // 1988-01-01
+    (631_152_000, 25),   // 1990-01-01
+    (662_688_000, 26),   // 1991-01-01
+    (709_948_800, 27),   // 1992-07-01
+    (741_484_800, 28),   // 1993-07-01
+    (773_020_800, 29),   // 1994-07-01
+    (820_454_400, 30),   // 1996-01-01
+    (867_715_200, 31),   // 1997-07-01
+    (915_148_800, 32),   // 1999-01-01
+    (1_136_073_600, 33), // 2006-01-01
+    (1_230_768_000, 34), // 2009-01-01
+    (1_341_100_800, 35), // 2012-07-01
+    (1_435_708_800, 36), // 2015-07-01
+    (1_483_228_800, 37), // 2017-01-01
+];
+
+/// A single change of TAI − UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeapSecond {
+    /// The Unix second at which the new offset takes effect (00:00:00 UTC of the day after
+    /// the leap second).
+    pub unix_seconds: i64,
+    /// TAI − UTC in seconds from `unix_seconds` onwards.
+    pub tai_minus_utc: i64,
+}
+
+/// A table of leap seconds, used to convert between UTC and TAI.
+///
+/// TAI instants are expressed as "TAI seconds": the POSIX timestamp plus TAI − UTC, which
+/// is the value reported by Linux's `CLOCK_TAI`. Unlike Unix seconds, this count advances
+/// during a leap second, so `23:59:60` gets a TAI second of its own.
+///
+/// The table compiled into timekit covers every leap second announced up to the one at the
+/// end of 2016. Newer data can be loaded from the IANA `leap-seconds.list` file or the tzdata
+/// `leapseconds` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeapSecondTable {
+    entries: Vec<LeapSecond>,
+    expires: Option<i64>,
+}
+
+impl LeapSecondTable {
+    /// Returns the table compiled into timekit.
+    pub fn builtin() -> Self {
+        Self {
+            entries: BUILTIN_LEAP_SECONDS
+                .iter()
+                .map(|&(unix_seconds, tai_minus_utc)| LeapSecond {
+                    unix_seconds,
+                    tai_minus_utc,
+                })
+                .collect(),
+            expires: None,
+        }
+    }
+
+    /// Loads a leap second file from disk, detecting whether it uses the IANA
+    /// `leap-seconds.list` format or the tzdata `leapseconds` format.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let contents = fs::read_to_string(path.as_ref())
+            .map_err(|e| format!("Failed to read {}: {}", path.as_ref().display(), e))?;
+        let is_tzdata = contents
+            .lines()
+            .any(|line| line.starts_with("Leap") || line.starts_with("Expires"));
+        if is_tzdata {
+            Self::parse_tzdata(&contents)
+        } else {
+            Self::parse_leap_seconds_list(&contents)
+        }
+    }
+
+    /// Parses the IANA / IERS `leap-seconds.list` format.
+    ///
+    /// Each data line holds an NTP timestamp and the TAI − UTC offset that starts at it;
+    /// lines beginning with `#` are comments, except `#@`, which carries the expiry date.
+    pub fn parse_leap_seconds_list(contents: &str) -> Result<Self, String> {
+        let mut entries = Vec::new();
+        let mut expires = None;
+
+        for line in contents.lines().map(str::trim) {
+            if let Some(expiry) = line.strip_prefix("#@") {
+                let ntp: i64 = expiry
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("Invalid expiry line: {}", line))?;
+                expires = Some(ntp - NTP_UNIX_OFFSET);
+                continue;
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.split_whitespace();
+            let (Some(ntp), Some(offset)) = (fields.next(), fields.next()) else {
+                return Err(format!("Invalid leap second line: {}", line));
+            };
+            let ntp: i64 = ntp
+                .parse()
+                .map_err(|_| format!("Invalid leap second line: {}", line))?;
+            let tai_minus_utc: i64 = offset
+                .parse()
+                .map_err(|_| format!("Invalid leap second line: {}", line))?;
+            entries.push(LeapSecond {
+                unix_seconds: ntp - NTP_UNIX_OFFSET,
+                tai_minus_utc,
+            });
+        }
+
+        Self::from_entries(entries, expires)
+    }
+
+    /// Parses the tzdata `leapseconds` format, e.g. `Leap 2016 Dec 31 23:59:60 + S`.
+    ///
+    /// Offsets accumulate from TAI − UTC = 10 s, which applied from 1972-01-01.
+    pub fn parse_tzdata(contents: &str) -> Result<Self, String> {
+        let mut entries = vec![LeapSecond {
+            unix_seconds: BUILTIN_LEAP_SECONDS[0].0,
+            tai_minus_utc: INITIAL_TAI_MINUS_UTC,
+        }];
+        let mut expires = None;
+
+        for line in contents.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.first() {
+                Some(&"Leap") if fields.len() >= 6 => {
+                    let midnight = parse_tzdata_day(&fields[1..4])? + SECONDS_IN_DAY;
+                    let previous = entries.last().map_or(0, |entry| entry.tai_minus_utc);
+                    let tai_minus_utc = match fields[5] {
+                        "+" => previous + 1,
+                        "-" => previous - 1,
+                        _ => return Err(format!("Invalid leap second line: {}", line)),
+                    };
+                    entries.push(LeapSecond {
+                        unix_seconds: midnight,
+                        tai_minus_utc,
+                    });
+                }
+                Some(&"Expires") if fields.len() >= 4 => {
+                    expires = Some(parse_tzdata_day(&fields[1..4])?);
+                }
+                Some(_) => return Err(format!("Invalid leap second line: {}", line)),
+                None => {}
+            }
+        }
+
+        Self::from_entries(entries, expires)
+    }
+
+    fn from_entries(mut entries: Vec<LeapSecond>, expires: Option<i64>) -> Result<Self, String> {
+        if entries.is_empty() {
+            return Err("Leap second table is empty".to_string());
+        }
+        entries.sort_by_key(|entry| entry.unix_seconds);
+        Ok(Self { entries, expires })
+    }
+
+    /// Returns the entries of the table, ordered by `unix_seconds`.
+    pub fn entries(&self) -> &[LeapSecond] {
+        &self.entries
+    }
+
+    /// Returns the Unix second after which the table is no longer guaranteed to be
+    /// complete, when the source file declares one.
+    pub fn expires(&self) -> Option<i64> {
+        self.expires
+    }
+
+    /// Returns TAI − UTC in seconds at the given Unix second.
+    ///
+    /// Instants before the first entry use the first entry's offset.
+    pub fn tai_minus_utc(&self, unix_seconds: i64) -> i64 {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.unix_seconds <= unix_seconds)
+            .unwrap_or(&self.entries[0])
+            .tai_minus_utc
+    }
+
+    /// Returns `true` if a positive leap second is inserted immediately before the given
+    /// Unix second, i.e. the UTC minute ending there has a 61st second.
+    pub fn has_leap_second_before(&self, unix_seconds: i64) -> bool {
+        self.entries.windows(2).any(|pair| {
+            pair[1].unix_seconds == unix_seconds && pair[1].tai_minus_utc > pair[0].tai_minus_utc
+        })
+    }
+
+    /// Returns `true` if a positive leap second was inserted at the end of the given UTC date.
+    pub fn is_leap_second_day(&self, year: u64, month: u64, day: u64) -> bool {
+        DateTime::calculate_total_seconds(year, month, day, 0, 0, 0)
+            .map(|start| self.has_leap_second_before(start + SECONDS_IN_DAY))
+            .unwrap_or(false)
+    }
+
+    /// Converts a UTC `DateTime` (in any time zone) to TAI seconds.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{DateTime, LeapSecondTable, TimeZone};
+    /// let table = LeapSecondTable::builtin();
+    /// let before = DateTime::new(2016, 12, 31, 23, 59, 59, TimeZone::UTC).unwrap();
+    /// let leap = DateTime::new(2016, 12, 31, 23, 59, 60, TimeZone::UTC).unwrap();
+    /// assert_eq!(table.utc_to_tai(&leap), table.utc_to_tai(&before) + 1);
+    /// ```
+    pub fn utc_to_tai(&self, datetime: &DateTime) -> i64 {
+        if datetime.second == 60 {
+            let previous = datetime.to_unix_seconds() - 1;
+            return previous + self.tai_minus_utc(previous) + 1;
+        }
+        let unix_seconds = datetime.to_unix_seconds();
+        unix_seconds + self.tai_minus_utc(unix_seconds)
+    }
+
+    /// Converts TAI seconds back to a `DateTime` in the given time zone.
+    ///
+    /// A TAI second that falls inside a leap second is returned with `second == 60`.
+    pub fn tai_to_utc(&self, tai_seconds: i64, timezone: TimeZone) -> Result<DateTime, String> {
+        let index = self
+            .entries
+            .iter()
+            .rposition(|entry| entry.unix_seconds + entry.tai_minus_utc <= tai_seconds)
+            .unwrap_or(0);
+        let entry = self.entries[index];
+        let unix_seconds = tai_seconds - entry.tai_minus_utc;
+
+        match self.entries.get(index + 1) {
+            Some(next) if unix_seconds >= next.unix_seconds => {
+                let datetime = DateTime::from_unix_seconds(next.unix_seconds - 1, timezone)?;
+                Ok(DateTime {
+                    second: 60,
+                    ..datetime
+                })
+            }
+            _ => DateTime::from_unix_seconds(unix_seconds, timezone),
+        }
+    }
+}
+
+impl Default for LeapSecondTable {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+/// Returns the compiled-in table, built on first use and shared afterwards.
+pub(crate) fn builtin_table() -> &'static LeapSecondTable {
+    static TABLE: OnceLock<LeapSecondTable> = OnceLock::new();
+    TABLE.get_or_init(LeapSecondTable::builtin)
+}
+
+/// Returns `true` if the compiled-in table inserts a leap second right before `unix_seconds`.
+pub(crate) fn is_builtin_leap_second(unix_seconds: i64) -> bool {
+    BUILTIN_LEAP_SECONDS
+        .windows(2)
+        .any(|pair| pair[1].0 == unix_seconds && pair[1].1 > pair[0].1)
+}
+
+// Parses `YYYY Mon DD` into the Unix second of that day's midnight.
+fn parse_tzdata_day(fields: &[&str]) -> Result<i64, String> {
+    let invalid = || format!("Invalid date: {}", fields.join(" "));
+    let year: u64 = fields[0].parse().map_err(|_| invalid())?;
+    let month = match fields[1] {
+        "Jan" => 1,
+        "Feb" => 2,
+        "Mar" => 3,
+        "Apr" => 4,
+        "May" => 5,
+        "Jun" => 6,
+        "Jul" => 7,
+        "Aug" => 8,
+        "Sep" => 9,
+        "Oct" => 10,
+        "Nov" => 11,
+        "Dec" => 12,
+        _ => return Err(invalid()),
+    };
+    let day: u64 = fields[2].parse().map_err(|_| invalid())?;
+    DateTime::new(year, month, day, 0, 0, 0, TimeZone::UTC)
+        .map(|datetime| datetime.to_unix_seconds())
+}
@@ -1,7 +1,64 @@
 // Bring in the constants from const.rs
+pub mod boundaries;
+pub mod business;
 pub mod constants;
+pub mod cron;
+pub mod date;
+pub mod duration;
+pub mod easter;
+pub mod exchange;
+pub mod holiday;
+pub mod humanize;
+pub mod interval;
+pub mod iso_interval;
+pub mod japan;
+pub mod korea;
+pub mod leap;
+pub mod locale;
+pub mod lunar;
+pub mod natural;
+pub mod offset;
+pub mod period;
+pub mod range;
+pub mod recurrence;
+pub mod rounding;
+pub mod rules;
+pub mod scales;
+pub mod systemd;
+pub mod us;
+pub mod weekday;
+
+pub use business::BusinessCalendar;
+pub use cron::{CronSchedule, Upcoming};
+pub use date::Date;
+pub use duration::IsoDuration;
+pub use easter::{Church, MovableFeast};
+pub use exchange::Exchange;
+pub use holiday::{Holiday, HolidayProvider};
+pub use humanize::{Humanizer, Language, Thresholds};
+pub use interval::{Interval, IntervalSet};
+pub use iso_interval::{IsoInterval, IsoOccurrences, RepeatingInterval};
+pub use japan::{JapaneseEra, JapaneseHolidays};
+pub use korea::KoreanHolidays;
+pub use leap::{LeapSecond, LeapSecondTable};
+pub use locale::{FormatStyle, Locale};
+pub use lunar::{LunarCalendar, LunarDate};
+pub use natural::{EnglishParser, RelativeDay, RelativeExpression, RelativeParser, Resolved};
+pub use offset::{OffsetDateTime, OffsetFormat};
+pub use period::Period;
+pub use range::{DateRange, DateTimeRange, RangeBound, Step, TimeRange};
+pub use recurrence::{
+    Frequency, NthWeekday, Occurrences, RecurrenceRule, RecurrenceSet, SetOccurrences, Until,
+};
+pub use rounding::TimeUnit;
+pub use rules::{HolidayDate, HolidayRule, HolidayRules, Observance};
+pub use scales::GpsTime;
+pub use systemd::CalendarEvent;
+pub use us::UsFederalHolidays;
+pub use weekday::Weekday;
 
 use constants::*;
+use std::str::FromStr;
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::{fmt, ops::Add, ops::Sub};
 
@@ -19,6 +76,11 @@ pub struct DateTime {
 
 impl DateTime {
     /// Creates a new `DateTime` object.
+    ///
+    /// `second` may be 60 when the time is a leap second, such as 2016-12-31 23:59:60 UTC
+    /// (or 2017-01-01 08:59:60 KST), according to the leap second table compiled into timekit.
+    /// Unix seconds cannot represent a leap second, so `to_unix_seconds` treats it as the first
+    /// second of the following minute; use `LeapSecondTable` to convert it to TAI exactly.
     pub fn new(
         year: u64,
         month: u64,
@@ -28,7 +90,7 @@ impl DateTime {
         second: u64,
         timezone: TimeZone,
     ) -> Result<Self, String> {
-        if month < 1 || month > 12 {
+        if !(1..=12).contains(&month) {
             return Err("Invalid month".to_string());
         }
         if day < 1 || day > days_in_month(month, year) {
@@ -40,6 +102,16 @@ impl DateTime {
         if minute > 59 {
             return Err("Invalid minute".to_string());
         }
+        if second == 60 {
+            let datetime = Self::new(year, month, day, hour, minute, 59, timezone)?;
+            if !leap::is_builtin_leap_second(datetime.to_unix_seconds() + 1) {
+                return Err("Invalid second".to_string());
+            }
+            return Ok(Self {
+                second: 60,
+                ..datetime
+            });
+        }
         if second > 59 {
             return Err("Invalid second".to_string());
         }
@@ -113,12 +185,49 @@ impl DateTime {
         Ok(total_seconds)
     }
 
+    /// Formats the date and time with `strftime`-style directives:
+    /// - `%Y` (year), `%y` (two-digit year), `%m` (month), `%d` (day), `%H` (hour), `%I`
+    ///   (12-hour clock hour), `%M` (minute) and `%S` (second), zero-padded; `%-m`, `%-d`,
+    ///   `%-H` and `%-I` leave out the padding
+    /// - `%B` and `%b` (month name), `%A` and `%a` (weekday name) and `%p` (AM/PM), in
+    ///   English; see `strftime_localized` for other locales
+    /// - the Japanese era directives `%EC` (era name, `令和`), `%Ey` (era year, `6`) and
+    ///   `%EY` (full era year, `令和6年`)
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{DateTime, TimeZone};
+    /// let datetime = DateTime::new(2024, 5, 7, 15, 4, 0, TimeZone::UTC).unwrap();
+    /// assert_eq!(datetime.strftime("%a, %-d %b %Y %-I:%M %p"), "Tue, 7 May 2024 3:04 PM");
+    /// ```
     pub fn strftime(&self, format: &str) -> String {
+        self.strftime_localized(format, &Locale::EN_US)
+    }
+
+    // Applies the directives that do not depend on the locale.
+    pub(crate) fn format_numeric(&self, format: &str) -> String {
         let mut result = format.to_string();
+        if result.contains("%E") {
+            if let Ok((era, year)) = self.date().japanese_era() {
+                result = result.replace("%EC", era.kanji());
+                result = result.replace("%Ey", &year.to_string());
+                result = result.replace("%EY", &era.format_year(year));
+            }
+        }
+        let hour12 = match self.hour % 12 {
+            0 => 12,
+            hour => hour,
+        };
         result = result.replace("%Y", &format!("{:04}", self.year));
+        result = result.replace("%y", &format!("{:02}", self.year % 100));
         result = result.replace("%m", &format!("{:02}", self.month));
+        result = result.replace("%-m", &self.month.to_string());
         result = result.replace("%d", &format!("{:02}", self.day));
+        result = result.replace("%-d", &self.day.to_string());
         result = result.replace("%H", &format!("{:02}", self.hour));
+        result = result.replace("%-H", &self.hour.to_string());
+        result = result.replace("%I", &format!("{:02}", hour12));
+        result = result.replace("%-I", &hour12.to_string());
         result = result.replace("%M", &format!("{:02}", self.minute));
         result = result.replace("%S", &format!("{:02}", self.second));
         result
@@ -189,16 +298,12 @@ impl DateTime {
         })
     }
 
+    /// Adds `delta`. A `DateTime` counts whole seconds, so the part of `delta` below a
+    /// second is truncated.
     pub fn add_timedelta(&self, delta: TimeDelta) -> Result<Self, String> {
         let current_unix = self.to_unix_seconds(); // 현재 시간을 Unix 시간으로 변환
-        let delta_seconds = compute_total_seconds(
-            delta.weeks,
-            delta.days,
-            delta.hours,
-            delta.minutes,
-            delta.seconds,
-        );
-        let timezone = self.timezone.clone();
+        let delta_seconds = delta.total_seconds();
+        let timezone = self.timezone;
         let new_unix = current_unix + delta_seconds; // 초 단위로 더하기
         if new_unix < 0 {
             return Err(
@@ -208,17 +313,12 @@ impl DateTime {
         DateTime::from_unix_seconds(new_unix, timezone) // 다시 DateTime으로 변환
     }
 
+    /// Subtracts `delta`, truncating its part below a second like `add_timedelta`.
     pub fn sub_timedelta(&self, delta: TimeDelta) -> Result<Self, String> {
         let current_unix = self.to_unix_seconds(); // 현재 시간을 Unix 시간으로 변환
-        let delta_seconds = compute_total_seconds(
-            delta.weeks,
-            delta.days,
-            delta.hours,
-            delta.minutes,
-            delta.seconds,
-        );
+        let delta_seconds = delta.total_seconds();
         let new_unix = current_unix - delta_seconds; // 초 단위로 빼기
-        let timezone = self.timezone.clone();
+        let timezone = self.timezone;
         if new_unix < 0 {
             return Err(
                 "Resulting DateTime is before Unix epoch (1970-01-01 00:00:00 UTC)".to_string(),
@@ -268,13 +368,74 @@ impl PartialEq for DateTime {
 }
 
 /// TimeDelta struct to represent a time difference similar to Python's timedelta.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// `nanoseconds` holds the part of the delta below a second, such as the `.5` of `PT1.5S`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct TimeDelta {
     pub weeks: i64,
     pub days: i64,
     pub hours: i64,
     pub minutes: i64,
     pub seconds: i64,
+    pub nanoseconds: i64,
+}
+
+impl TimeDelta {
+    /// Returns the total length of the delta in whole seconds, truncated toward zero.
+    pub const fn total_seconds(&self) -> i64 {
+        (self.total_nanoseconds() / NANOSECONDS_IN_SECOND as i128) as i64
+    }
+
+    /// Returns the total length of the delta in nanoseconds.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::TimeDelta;
+    /// let delta = TimeDelta { seconds: 1, nanoseconds: 500_000_000, ..Default::default() };
+    /// assert_eq!(delta.total_nanoseconds(), 1_500_000_000);
+    /// assert_eq!(delta.total_seconds(), 1);
+    /// ```
+    pub const fn total_nanoseconds(&self) -> i128 {
+        let seconds = self.weeks as i128 * SECONDS_IN_WEEK as i128
+            + self.days as i128 * SECONDS_IN_DAY as i128
+            + self.hours as i128 * SECONDS_IN_HOUR as i128
+            + self.minutes as i128 * SECONDS_IN_MINUTE as i128
+            + self.seconds as i128;
+        seconds * NANOSECONDS_IN_SECOND as i128 + self.nanoseconds as i128
+    }
+
+    /// Creates a `TimeDelta` from a number of nanoseconds, split like `from_seconds` with the
+    /// part below a second in `nanoseconds`. Fails when the whole seconds do not fit in an
+    /// `i64`.
+    pub fn from_nanoseconds(nanoseconds: i128) -> Result<Self, String> {
+        let seconds = i64::try_from(nanoseconds / NANOSECONDS_IN_SECOND as i128)
+            .map_err(|_| "TimeDelta is too large".to_string())?;
+        Ok(Self {
+            nanoseconds: (nanoseconds % NANOSECONDS_IN_SECOND as i128) as i64,
+            ..Self::from_seconds(seconds)
+        })
+    }
+
+    /// Creates a `TimeDelta` from a number of seconds, split into days, hours, minutes and
+    /// seconds that all share the sign of `seconds`.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::TimeDelta;
+    /// let delta = TimeDelta::from_seconds(90_061);
+    /// assert_eq!(delta.to_string(), "1 day, 1 hour, 1 minute, 1 second");
+    /// assert_eq!(delta.total_seconds(), 90_061);
+    /// ```
+    pub const fn from_seconds(seconds: i64) -> Self {
+        Self {
+            weeks: 0,
+            days: seconds / SECONDS_IN_DAY,
+            hours: seconds % SECONDS_IN_DAY / SECONDS_IN_HOUR,
+            minutes: seconds % SECONDS_IN_HOUR / SECONDS_IN_MINUTE,
+            seconds: seconds % SECONDS_IN_MINUTE,
+            nanoseconds: 0,
+        }
+    }
 }
 
 impl std::fmt::Display for TimeDelta {
@@ -309,7 +470,15 @@ impl std::fmt::Display for TimeDelta {
                 if self.minutes.abs() == 1 { "" } else { "s" }
             ));
         }
-        if self.seconds != 0 || components.is_empty() {
+        if self.nanoseconds != 0 {
+            // Seconds with a fraction, e.g. "1.5 seconds".
+            let nanoseconds =
+                self.seconds as i128 * NANOSECONDS_IN_SECOND as i128 + self.nanoseconds as i128;
+            components.push(format!(
+                "{} seconds",
+                duration::decimal_seconds(nanoseconds)
+            ));
+        } else if self.seconds != 0 || components.is_empty() {
             // 항상 seconds는 출력
             components.push(format!(
                 "{} second{}",
@@ -322,20 +491,8 @@ impl std::fmt::Display for TimeDelta {
     }
 }
 
-impl Default for TimeDelta {
-    fn default() -> Self {
-        Self {
-            weeks: 0,
-            days: 0,
-            hours: 0,
-            minutes: 0,
-            seconds: 0,
-        }
-    }
-}
-
 /// Enum for representing time zones with precomputed UTC offsets in seconds.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TimeZone {
     UTC,
     KST,     // Korea Standard Time (UTC+9)
@@ -391,6 +548,211 @@ impl TimeZone {
             TimeZone::HKT => OFFSET_HKT,
         }
     }
+
+    /// Returns an iterator over every supported time zone, in declaration order.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::TimeZone;
+    /// assert_eq!(TimeZone::all().count(), 23);
+    /// assert_eq!(TimeZone::all().next(), Some(TimeZone::UTC));
+    /// ```
+    pub fn all() -> impl Iterator<Item = TimeZone> {
+        ALL_TIMEZONES.iter().copied()
+    }
+
+    /// Returns the common abbreviation of the time zone.
+    ///
+    /// Both `TimeZone::CST` and `TimeZone::CSTAsia` are abbreviated as `"CST"`; use
+    /// `TimeZone::from_abbreviation` with a `Region` hint to tell them apart when parsing.
+    pub const fn abbreviation(&self) -> &'static str {
+        match self {
+            TimeZone::UTC => "UTC",
+            TimeZone::KST => "KST",
+            TimeZone::EST => "EST",
+            TimeZone::PST => "PST",
+            TimeZone::JST => "JST",
+            TimeZone::IST => "IST",
+            TimeZone::CET => "CET",
+            TimeZone::AST => "AST",
+            TimeZone::CST => "CST",
+            TimeZone::MST => "MST",
+            TimeZone::AKST => "AKST",
+            TimeZone::HST => "HST",
+            TimeZone::BST => "BST",
+            TimeZone::WET => "WET",
+            TimeZone::EET => "EET",
+            TimeZone::SAST => "SAST",
+            TimeZone::EAT => "EAT",
+            TimeZone::AEST => "AEST",
+            TimeZone::ACST => "ACST",
+            TimeZone::AWST => "AWST",
+            TimeZone::CSTAsia => "CST",
+            TimeZone::SGT => "SGT",
+            TimeZone::HKT => "HKT",
+        }
+    }
+
+    /// Returns the geographic region the time zone belongs to, or `None` for UTC.
+    pub const fn region(&self) -> Option<Region> {
+        match self {
+            TimeZone::UTC => None,
+            TimeZone::EST
+            | TimeZone::PST
+            | TimeZone::AST
+            | TimeZone::CST
+            | TimeZone::MST
+            | TimeZone::AKST
+            | TimeZone::HST => Some(Region::Americas),
+            TimeZone::CET | TimeZone::BST | TimeZone::WET | TimeZone::EET => Some(Region::Europe),
+            TimeZone::SAST | TimeZone::EAT => Some(Region::Africa),
+            TimeZone::AEST | TimeZone::ACST | TimeZone::AWST => Some(Region::Oceania),
+            TimeZone::KST
+            | TimeZone::JST
+            | TimeZone::IST
+            | TimeZone::CSTAsia
+            | TimeZone::SGT
+            | TimeZone::HKT => Some(Region::Asia),
+        }
+    }
+
+    /// Resolves a time zone abbreviation such as `"KST"` or `"CST"`.
+    ///
+    /// Matching is case-insensitive, and `"GMT"` and `"Z"` are accepted as aliases of UTC.
+    /// When an abbreviation is shared by several zones, the `hint` region selects between
+    /// them; without a hint (or when no candidate lies in the hinted region) the first zone
+    /// in declaration order wins, so `"CST"` resolves to US Central Standard Time.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{Region, TimeZone};
+    /// assert_eq!(TimeZone::from_abbreviation("CST", None), Ok(TimeZone::CST));
+    /// assert_eq!(
+    ///     TimeZone::from_abbreviation("cst", Some(Region::Asia)),
+    ///     Ok(TimeZone::CSTAsia)
+    /// );
+    /// ```
+    pub fn from_abbreviation(abbr: &str, hint: Option<Region>) -> Result<TimeZone, String> {
+        let abbr = abbr.trim();
+        if abbr.eq_ignore_ascii_case("GMT") || abbr.eq_ignore_ascii_case("Z") {
+            return Ok(TimeZone::UTC);
+        }
+
+        let mut candidates =
+            TimeZone::all().filter(|tz| tz.abbreviation().eq_ignore_ascii_case(abbr));
+        let first = candidates
+            .next()
+            .ok_or_else(|| format!("Unknown time zone abbreviation: {}", abbr))?;
+
+        match hint {
+            Some(region) if first.region() != Some(region) => Ok(candidates
+                .find(|tz| tz.region() == Some(region))
+                .unwrap_or(first)),
+            _ => Ok(first),
+        }
+    }
+}
+
+/// Broad geographic regions, used as a hint when resolving ambiguous abbreviations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Region {
+    Americas,
+    Europe,
+    Africa,
+    Asia,
+    Oceania,
+}
+
+const ALL_TIMEZONES: [TimeZone; 23] = [
+    TimeZone::UTC,
+    TimeZone::KST,
+    TimeZone::EST,
+    TimeZone::PST,
+    TimeZone::JST,
+    TimeZone::IST,
+    TimeZone::CET,
+    TimeZone::AST,
+    TimeZone::CST,
+    TimeZone::MST,
+    TimeZone::AKST,
+    TimeZone::HST,
+    TimeZone::BST,
+    TimeZone::WET,
+    TimeZone::EET,
+    TimeZone::SAST,
+    TimeZone::EAT,
+    TimeZone::AEST,
+    TimeZone::ACST,
+    TimeZone::AWST,
+    TimeZone::CSTAsia,
+    TimeZone::SGT,
+    TimeZone::HKT,
+];
+
+// IANA zone names mapped onto the fixed-offset zone that matches their standard time.
+const IANA_NAMES: [(&str, TimeZone); 30] = [
+    ("Etc/UTC", TimeZone::UTC),
+    ("Etc/GMT", TimeZone::UTC),
+    ("Asia/Seoul", TimeZone::KST),
+    ("America/New_York", TimeZone::EST),
+    ("America/Los_Angeles", TimeZone::PST),
+    ("Asia/Tokyo", TimeZone::JST),
+    ("Asia/Kolkata", TimeZone::IST),
+    ("Asia/Calcutta", TimeZone::IST),
+    ("Europe/Paris", TimeZone::CET),
+    ("Europe/Berlin", TimeZone::CET),
+    ("America/Halifax", TimeZone::AST),
+    ("America/Chicago", TimeZone::CST),
+    ("America/Denver", TimeZone::MST),
+    ("America/Anchorage", TimeZone::AKST),
+    ("Pacific/Honolulu", TimeZone::HST),
+    ("Europe/London", TimeZone::WET),
+    ("Europe/Lisbon", TimeZone::WET),
+    ("Europe/Athens", TimeZone::EET),
+    ("Europe/Helsinki", TimeZone::EET),
+    ("Africa/Johannesburg", TimeZone::SAST),
+    ("Africa/Nairobi", TimeZone::EAT),
+    ("Australia/Sydney", TimeZone::AEST),
+    ("Australia/Brisbane", TimeZone::AEST),
+    ("Australia/Adelaide", TimeZone::ACST),
+    ("Australia/Darwin", TimeZone::ACST),
+    ("Australia/Perth", TimeZone::AWST),
+    ("Asia/Shanghai", TimeZone::CSTAsia),
+    ("Asia/Singapore", TimeZone::SGT),
+    ("Asia/Hong_Kong", TimeZone::HKT),
+    ("PRC", TimeZone::CSTAsia),
+];
+
+impl fmt::Display for TimeZone {
+    /// Writes the variant name, which is the abbreviation for every zone except
+    /// `CSTAsia`. The output always parses back to the same zone with `FromStr`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeZone::CSTAsia => write!(f, "CSTAsia"),
+            _ => write!(f, "{}", self.abbreviation()),
+        }
+    }
+}
+
+impl FromStr for TimeZone {
+    type Err = String;
+
+    /// Parses a variant name (`"KST"`, `"CSTAsia"`), an abbreviation or an IANA zone name
+    /// (`"Asia/Seoul"`). Matching is case-insensitive; ambiguous abbreviations resolve as in
+    /// `TimeZone::from_abbreviation` without a region hint.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("CSTAsia") {
+            return Ok(TimeZone::CSTAsia);
+        }
+        if let Some((_, tz)) = IANA_NAMES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(s))
+        {
+            return Ok(*tz);
+        }
+        TimeZone::from_abbreviation(s, None).map_err(|_| format!("Unknown time zone: {}", s))
+    }
 }
 
 /// Returns the current date and time adjusted for the specified time zone.
@@ -511,17 +873,17 @@ pub const fn compute_total_seconds(
     minutes: i64,
     seconds: i64,
 ) -> i64 {
-    weeks * SECONDS_IN_WEEK as i64
-        + days * SECONDS_IN_DAY as i64
-        + hours * SECONDS_IN_HOUR as i64
-        + minutes * SECONDS_IN_MINUTE as i64
+    weeks * SECONDS_IN_WEEK
+        + days * SECONDS_IN_DAY
+        + hours * SECONDS_IN_HOUR
+        + minutes * SECONDS_IN_MINUTE
         + seconds
 }
 
 pub const fn adjust_second_with_timezone(total_seconds: u64, timezone: TimeZone) -> u64 {
     let timezone_offset = timezone.offset_in_seconds();
-    let adjusted_seconds = (total_seconds as i64 + timezone_offset) as u64;
-    adjusted_seconds
+
+    (total_seconds as i64 + timezone_offset) as u64
 }
 
 pub fn calculate_date_since_epoch(
@@ -549,7 +911,7 @@ pub fn calculate_date_since_epoch(
         days -= days_in_month(month, year);
         month += 1;
     }
-    let day = days as u64 + 1; // Days start from 1.
+    let day = days + 1; // Days start from 1.
 
     // Return the DateTime object.
     DateTime::new(year, month, day, hour, minute, second, timezone)
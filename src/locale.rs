@@ -0,0 +1,401 @@
+use crate::{DateTime, Weekday};
+use std::fmt;
+use std::str::FromStr;
+
+/// The length of a localized date pattern.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum FormatStyle {
+    /// Numeric, e.g. `05/17/2024` or `24. 5. 17.`.
+    Short,
+    /// Abbreviated month names where the locale uses them, e.g. `May 17, 2024`.
+    #[default]
+    Medium,
+    /// Full month names, e.g. `May 17, 2024` or `17. Mai 2024`.
+    Long,
+}
+
+// How a locale writes ordinal numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Ordinals {
+    English,
+    Period,
+    French,
+    Korean,
+    Japanese,
+    Chinese,
+}
+
+/// Month and weekday names, AM/PM markers, ordinals and date patterns for one locale.
+///
+/// The bundled locales are `EN_US`, `EN_GB`, `KO_KR`, `JA_JP`, `ZH_CN`, `DE_DE` and
+/// `FR_FR`, also found by their BCP 47 tag with `from_str`. Date patterns use the
+/// directives of `DateTime::strftime_localized`.
+///
+/// # Example:
+/// ```
+/// use timekit::{DateTime, FormatStyle, Locale, TimeZone};
+/// let datetime = DateTime::new(2024, 5, 17, 15, 0, 0, TimeZone::KST).unwrap();
+/// assert_eq!(datetime.format_localized(&Locale::EN_US, FormatStyle::Long), "May 17, 2024");
+/// assert_eq!(datetime.format_localized(&Locale::KO_KR, FormatStyle::Long), "2024년 5월 17일");
+/// assert_eq!(datetime.format_localized(&Locale::DE_DE, FormatStyle::Short), "17.05.24");
+///
+/// let locale: Locale = "ja-JP".parse().unwrap();
+/// assert_eq!(datetime.strftime_localized("%A %p%-I時", &locale), "金曜日 午後3時");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Locale {
+    tag: &'static str,
+    months: [&'static str; 12],
+    month_abbreviations: [&'static str; 12],
+    weekdays: [&'static str; 7],
+    weekday_abbreviations: [&'static str; 7],
+    am_pm: [&'static str; 2],
+    ordinals: Ordinals,
+    patterns: [&'static str; 3],
+}
+
+const ENGLISH_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const ENGLISH_MONTH_ABBREVIATIONS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+const ENGLISH_WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+const ENGLISH_WEEKDAY_ABBREVIATIONS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+const KOREAN_MONTHS: [&str; 12] = [
+    "1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월",
+];
+
+// Months written with Arabic numerals, as in Japanese and abbreviated Chinese.
+const NUMBERED_MONTHS: [&str; 12] = [
+    "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+];
+
+const ALL_LOCALES: [Locale; 7] = [
+    Locale::EN_US,
+    Locale::EN_GB,
+    Locale::KO_KR,
+    Locale::JA_JP,
+    Locale::ZH_CN,
+    Locale::DE_DE,
+    Locale::FR_FR,
+];
+
+impl Locale {
+    /// English (United States).
+    pub const EN_US: Locale = Locale {
+        tag: "en-US",
+        months: ENGLISH_MONTHS,
+        month_abbreviations: ENGLISH_MONTH_ABBREVIATIONS,
+        weekdays: ENGLISH_WEEKDAYS,
+        weekday_abbreviations: ENGLISH_WEEKDAY_ABBREVIATIONS,
+        am_pm: ["AM", "PM"],
+        ordinals: Ordinals::English,
+        patterns: ["%m/%d/%Y", "%b %-d, %Y", "%B %-d, %Y"],
+    };
+
+    /// English (United Kingdom).
+    pub const EN_GB: Locale = Locale {
+        tag: "en-GB",
+        months: ENGLISH_MONTHS,
+        month_abbreviations: ENGLISH_MONTH_ABBREVIATIONS,
+        weekdays: ENGLISH_WEEKDAYS,
+        weekday_abbreviations: ENGLISH_WEEKDAY_ABBREVIATIONS,
+        am_pm: ["am", "pm"],
+        ordinals: Ordinals::English,
+        patterns: ["%d/%m/%Y", "%-d %b %Y", "%-d %B %Y"],
+    };
+
+    /// Korean (South Korea).
+    pub const KO_KR: Locale = Locale {
+        tag: "ko-KR",
+        months: KOREAN_MONTHS,
+        month_abbreviations: KOREAN_MONTHS,
+        weekdays: [
+            "월요일",
+            "화요일",
+            "수요일",
+            "목요일",
+            "금요일",
+            "토요일",
+            "일요일",
+        ],
+        weekday_abbreviations: ["월", "화", "수", "목", "금", "토", "일"],
+        am_pm: ["오전", "오후"],
+        ordinals: Ordinals::Korean,
+        patterns: ["%y. %-m. %-d.", "%Y. %-m. %-d.", "%Y년 %-m월 %-d일"],
+    };
+
+    /// Japanese (Japan).
+    pub const JA_JP: Locale = Locale {
+        tag: "ja-JP",
+        months: NUMBERED_MONTHS,
+        month_abbreviations: NUMBERED_MONTHS,
+        weekdays: [
+            "月曜日",
+            "火曜日",
+            "水曜日",
+            "木曜日",
+            "金曜日",
+            "土曜日",
+            "日曜日",
+        ],
+        weekday_abbreviations: ["月", "火", "水", "木", "金", "土", "日"],
+        am_pm: ["午前", "午後"],
+        ordinals: Ordinals::Japanese,
+        patterns: ["%Y/%m/%d", "%Y/%m/%d", "%Y年%-m月%-d日"],
+    };
+
+    /// Chinese (China, Simplified).
+    pub const ZH_CN: Locale = Locale {
+        tag: "zh-CN",
+        months: [
+            "一月",
+            "二月",
+            "三月",
+            "四月",
+            "五月",
+            "六月",
+            "七月",
+            "八月",
+            "九月",
+            "十月",
+            "十一月",
+            "十二月",
+        ],
+        month_abbreviations: NUMBERED_MONTHS,
+        weekdays: [
+            "星期一",
+            "星期二",
+            "星期三",
+            "星期四",
+            "星期五",
+            "星期六",
+            "星期日",
+        ],
+        weekday_abbreviations: ["周一", "周二", "周三", "周四", "周五", "周六", "周日"],
+        am_pm: ["上午", "下午"],
+        ordinals: Ordinals::Chinese,
+        patterns: ["%Y/%-m/%-d", "%Y年%-m月%-d日", "%Y年%-m月%-d日"],
+    };
+
+    /// German (Germany).
+    pub const DE_DE: Locale = Locale {
+        tag: "de-DE",
+        months: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        month_abbreviations: [
+            "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+            "Dez.",
+        ],
+        weekdays: [
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+            "Sonntag",
+        ],
+        weekday_abbreviations: ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+        am_pm: ["AM", "PM"],
+        ordinals: Ordinals::Period,
+        patterns: ["%d.%m.%y", "%d.%m.%Y", "%-d. %B %Y"],
+    };
+
+    /// French (France).
+    pub const FR_FR: Locale = Locale {
+        tag: "fr-FR",
+        months: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        month_abbreviations: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        weekdays: [
+            "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+        ],
+        weekday_abbreviations: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+        am_pm: ["AM", "PM"],
+        ordinals: Ordinals::French,
+        patterns: ["%d/%m/%Y", "%-d %b %Y", "%-d %B %Y"],
+    };
+
+    /// Returns an iterator over the bundled locales.
+    pub fn all() -> impl Iterator<Item = Locale> {
+        ALL_LOCALES.iter().copied()
+    }
+
+    /// Returns the BCP 47 language tag, e.g. `"ko-KR"`.
+    pub fn tag(&self) -> &'static str {
+        self.tag
+    }
+
+    /// Returns the full name of `month` (1 to 12).
+    pub fn month_name(&self, month: u64) -> Result<&'static str, String> {
+        month_index(month).map(|index| self.months[index])
+    }
+
+    /// Returns the abbreviated name of `month` (1 to 12).
+    pub fn month_abbreviation(&self, month: u64) -> Result<&'static str, String> {
+        month_index(month).map(|index| self.month_abbreviations[index])
+    }
+
+    /// Returns the full name of `weekday`.
+    pub fn weekday_name(&self, weekday: Weekday) -> &'static str {
+        self.weekdays[weekday.number_from_monday() as usize - 1]
+    }
+
+    /// Returns the abbreviated name of `weekday`.
+    pub fn weekday_abbreviation(&self, weekday: Weekday) -> &'static str {
+        self.weekday_abbreviations[weekday.number_from_monday() as usize - 1]
+    }
+
+    /// Returns the AM or PM marker for an hour from 0 to 23.
+    pub fn am_pm(&self, hour: u64) -> &'static str {
+        self.am_pm[if hour < 12 { 0 } else { 1 }]
+    }
+
+    /// Returns `n` as an ordinal number, e.g. `"2nd"`, `"2."`, `"2e"` or `"2번째"`.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::Locale;
+    /// let ordinals: Vec<String> = [1, 2, 3, 11, 22]
+    ///     .iter()
+    ///     .map(|&n| Locale::EN_US.ordinal(n))
+    ///     .collect();
+    /// assert_eq!(ordinals, ["1st", "2nd", "3rd", "11th", "22nd"]);
+    /// assert_eq!(Locale::FR_FR.ordinal(1), "1er");
+    /// assert_eq!(Locale::ZH_CN.ordinal(3), "第3");
+    /// ```
+    pub fn ordinal(&self, n: u64) -> String {
+        match self.ordinals {
+            Ordinals::English => {
+                let suffix = match (n % 10, n % 100) {
+                    (_, 11..=13) => "th",
+                    (1, _) => "st",
+                    (2, _) => "nd",
+                    (3, _) => "rd",
+                    _ => "th",
+                };
+                format!("{}{}", n, suffix)
+            }
+            Ordinals::Period => format!("{}.", n),
+            Ordinals::French if n == 1 => "1er".to_string(),
+            Ordinals::French => format!("{}e", n),
+            Ordinals::Korean => format!("{}번째", n),
+            Ordinals::Japanese => format!("{}番目", n),
+            Ordinals::Chinese => format!("第{}", n),
+        }
+    }
+
+    /// Returns the locale's default date pattern for `style`.
+    pub fn date_pattern(&self, style: FormatStyle) -> &'static str {
+        match style {
+            FormatStyle::Short => self.patterns[0],
+            FormatStyle::Medium => self.patterns[1],
+            FormatStyle::Long => self.patterns[2],
+        }
+    }
+}
+
+fn month_index(month: u64) -> Result<usize, String> {
+    if !(1..=12).contains(&month) {
+        return Err("Invalid month".to_string());
+    }
+    Ok(month as usize - 1)
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::EN_US
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.tag)
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    /// Finds a bundled locale by its tag, ignoring case and accepting `_` for `-`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tag = s.trim().replace('_', "-");
+        Locale::all()
+            .find(|locale| locale.tag.eq_ignore_ascii_case(&tag))
+            .ok_or_else(|| format!("Unknown locale: {}", s.trim()))
+    }
+}
+
+impl DateTime {
+    /// Formats the date with `strftime` directives, using `locale` for the names: `%B` and
+    /// `%b` (month), `%A` and `%a` (weekday) and `%p` (AM/PM marker).
+    pub fn strftime_localized(&self, format: &str, locale: &Locale) -> String {
+        let month = locale.month_name(self.month).unwrap_or_default();
+        let month_abbreviation = locale.month_abbreviation(self.month).unwrap_or_default();
+        let weekday = self.weekday();
+        let result = format
+            .replace("%B", month)
+            .replace("%b", month_abbreviation)
+            .replace("%A", locale.weekday_name(weekday))
+            .replace("%a", locale.weekday_abbreviation(weekday))
+            .replace("%p", locale.am_pm(self.hour));
+        self.format_numeric(&result)
+    }
+
+    /// Formats the date with the locale's default pattern for `style`.
+    pub fn format_localized(&self, locale: &Locale, style: FormatStyle) -> String {
+        self.strftime_localized(locale.date_pattern(style), locale)
+    }
+}
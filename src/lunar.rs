@@ -0,0 +1,309 @@
+use crate::Date;
+use std::fmt;
+
+// Lunar years covered by the tables below.
+const FIRST_YEAR: u64 = 1899;
+const LAST_YEAR: u64 = 2100;
+
+// Each entry describes one lunar year, starting with 1899:
+// - bits 0-12: the month lengths in order, leap month included; a set bit is a 30-day
+//   month and a clear bit a 29-day month.
+// - bits 13-16: the number of the month followed by a leap month, or 0 for none.
+// - bits 17-23: the day of the Gregorian year (0 for January 1st) on which the lunar year
+//   starts.
+//
+// The tables were generated from Meeus' new moon series ("Astronomical Algorithms",
+// chapter 49) and solar longitudes from a truncated VSOP87 series, with ΔT from the
+// Espenak-Meeus polynomials. A month starts on the local date of its new moon, the month
+// holding the winter solstice is the 11th, and when 13 months pass between two winter
+// solstices the first month without a principal solar term is the leap month.
+
+// Months reckoned at the 135°E meridian (UTC+9, KST).
+const KOREAN: [u32; 202] = [
+    0x0500ad5, 0x03d16d2, 0x0620752, 0x04c0ea5, 0x038ad4a, 0x05c054b, 0x0440a97, 0x0309556,
+    0x056055a, 0x0400b55, 0x02a56d2, 0x0500752, 0x03ad725, 0x0600b25, 0x0480a4b, 0x032b29b,
+    0x0580aad, 0x044056a, 0x02c4b69, 0x0520ba9, 0x03efb52, 0x0640d92, 0x04c0d25, 0x036ba4d,
+    0x05c0956, 0x04602b5, 0x02e95ad, 0x05606d4, 0x0400da9, 0x02c5d92, 0x0500e92, 0x03acd26,
+    0x05e0527, 0x0480a57, 0x032b2b6, 0x0580ada, 0x04406d4, 0x02e6ea9, 0x0520749, 0x03cf693,
+    0x0620a93, 0x04c052b, 0x034ca5b, 0x05a096d, 0x0460b6a, 0x0329b54, 0x0560ba4, 0x0400b49,
+    0x02a5a93, 0x0500a95, 0x038f52b, 0x05e052d, 0x0480aad, 0x034b56a, 0x0580db2, 0x0440da4,
+    0x02e7d49, 0x0540d4a, 0x03d1a95, 0x0620a96, 0x04c0556, 0x036cab5, 0x05a0ad5, 0x04606d2,
+    0x0308ea5, 0x0560ea5, 0x0400e4a, 0x02a6c96, 0x04e0a9b, 0x03af556, 0x05e056a, 0x0480b59,
+    0x034b752, 0x05a0752, 0x0420725, 0x02c964b, 0x0520a4b, 0x03d12ab, 0x06002ad, 0x04a056b,
+    0x036cb69, 0x05c0da9, 0x0460d92, 0x0309b25, 0x0560d25, 0x0415a4d, 0x0640a56, 0x04e02b6,
+    0x038d5ad, 0x06006d4, 0x0480da9, 0x034bd92, 0x05a0e92, 0x0440d26, 0x02c6a56, 0x0500a57,
+    0x03d12b6, 0x0620b5a, 0x04c06d4, 0x036aec9, 0x05c0749, 0x0460693, 0x02e9527, 0x054052b,
+    0x03e0a5b, 0x02a555a, 0x04e036a, 0x038fb55, 0x0600ba4, 0x04a0b49, 0x032ba93, 0x0580a95,
+    0x042052d, 0x02c6a5d, 0x0500aad, 0x03d35aa, 0x06205d2, 0x04c0da5, 0x036bd4a, 0x05c0d4a,
+    0x0460a95, 0x030952d, 0x0540556, 0x03e0ab5, 0x02a55aa, 0x05006d2, 0x038cea5, 0x05e0ea5,
+    0x04a0e4a, 0x034ac96, 0x0560c9b, 0x042055a, 0x02c6ad5, 0x0520b69, 0x03d7752, 0x0620752,
+    0x04c0b25, 0x036d64b, 0x05a0a4b, 0x04404ab, 0x02ea55b, 0x054056d, 0x03e0b69, 0x02a5b52,
+    0x0500d92, 0x03afd25, 0x05e0d25, 0x0480a4d, 0x032b4ad, 0x05802b6, 0x04005b5, 0x02c6da9,
+    0x0520ea9, 0x03f1d92, 0x0620e92, 0x04c0d26, 0x036ca56, 0x05a0a57, 0x04404d6, 0x02e86b5,
+    0x05406d5, 0x0400ec9, 0x02a6e92, 0x04e0693, 0x038f52b, 0x05e052b, 0x0460a5b, 0x032b55a,
+    0x058056a, 0x0420b55, 0x02c9749, 0x0520b49, 0x03d1a93, 0x0620a95, 0x04a052d, 0x034caad,
+    0x05a0ab5, 0x04605aa, 0x02e8ba5, 0x0540da5, 0x0400d4a, 0x02a7a95, 0x04e0c95, 0x038f52e,
+    0x05e0556, 0x0480ab5, 0x032b5b2, 0x05806d2, 0x0420ea5, 0x02e9e4a, 0x052064a, 0x03b0c97,
+    0x0600cab, 0x04c055a, 0x034cad5, 0x05a0b69, 0x0460752, 0x03096a5, 0x0540b25, 0x03e064b,
+    0x0287497, 0x04e04ab,
+];
+
+// Months reckoned at the 120°E meridian (UTC+8, CST).
+const CHINESE: [u32; 202] = [
+    0x0500ad5, 0x03d16d2, 0x0620752, 0x04c0ea5, 0x038b64a, 0x05c064b, 0x0440a9b, 0x0309556,
+    0x056056a, 0x0400b59, 0x02a5752, 0x0500752, 0x03adb25, 0x0600b25, 0x0480a4b, 0x032b2ab,
+    0x0580aad, 0x044056a, 0x02c4b69, 0x0520da9, 0x03efd92, 0x0640d92, 0x04c0d25, 0x036ba4d,
+    0x05c0a56, 0x04602b6, 0x02e95b5, 0x05606d4, 0x0400ea9, 0x02c5e92, 0x0500e92, 0x03acd26,
+    0x05e052b, 0x0480a57, 0x032b2b6, 0x0580b5a, 0x04406d4, 0x02e6ec9, 0x0520749, 0x03cf693,
+    0x0620a93, 0x04c052b, 0x034ca5b, 0x05a0aad, 0x046056a, 0x0309b55, 0x0560ba4, 0x0400b49,
+    0x02a5a93, 0x0500a95, 0x038f52d, 0x05e0536, 0x0480aad, 0x034b5aa, 0x05805b2, 0x0420da5,
+    0x02e7d4a, 0x0540d4a, 0x03d0a95, 0x0600a97, 0x04c0556, 0x036cab5, 0x05a0ad5, 0x04606d2,
+    0x0308ea5, 0x0560ea5, 0x040064a, 0x0286c97, 0x04e0a9b, 0x03af55a, 0x05e056a, 0x0480b69,
+    0x034b752, 0x05a0b52, 0x0420b25, 0x02c964b, 0x0520a4b, 0x03d14ab, 0x06002ad, 0x04a056d,
+    0x036cb69, 0x05c0da9, 0x0460d92, 0x0309d25, 0x0560d25, 0x0415a4d, 0x0640a56, 0x04e02b6,
+    0x038c5b5, 0x05e06d5, 0x0480ea9, 0x034be92, 0x05a0e92, 0x0440d26, 0x02c6a56, 0x0500a57,
+    0x03d14d6, 0x062035a, 0x04a06d5, 0x036b6c9, 0x05c0749, 0x0460693, 0x02e952b, 0x054052b,
+    0x03e0a5b, 0x02a555a, 0x04e056a, 0x038fb55, 0x0600ba4, 0x04a0b49, 0x032ba93, 0x0580a95,
+    0x042052d, 0x02c8aad, 0x0500ab5, 0x03d35aa, 0x06205d2, 0x04c0da5, 0x036dd4a, 0x05c0d4a,
+    0x0460c95, 0x030952e, 0x0540556, 0x03e0ab5, 0x02a55b2, 0x05006d2, 0x038cea5, 0x05e0725,
+    0x048064b, 0x032ac97, 0x0560cab, 0x042055a, 0x02c6ad6, 0x0520b69, 0x03d7752, 0x0620b52,
+    0x04c0b25, 0x036da4b, 0x05a0a4b, 0x04404ab, 0x02ea55b, 0x05405ad, 0x03e0b6a, 0x02a5b52,
+    0x0500d92, 0x03afd25, 0x05e0d25, 0x0480a55, 0x032b4ad, 0x05804b6, 0x04005b5, 0x02c6daa,
+    0x0520ec9, 0x03f1e92, 0x0620e92, 0x04c0d26, 0x036ca56, 0x05a0a57, 0x0440556, 0x02e86d5,
+    0x0540755, 0x0400749, 0x0286e93, 0x04e0693, 0x038f52b, 0x05e052b, 0x0460a5b, 0x032b55a,
+    0x058056a, 0x0420b65, 0x02c974a, 0x0520b4a, 0x03d1a95, 0x0620a95, 0x04a052d, 0x034caad,
+    0x05a0ab5, 0x04605aa, 0x02e8ba5, 0x0540da5, 0x0400d4a, 0x02a7c95, 0x04e0c96, 0x038f94e,
+    0x05e0556, 0x0480ab5, 0x032b5b2, 0x05806d2, 0x0420ea5, 0x02e8e4a, 0x050068b, 0x03b0c97,
+    0x06004ab, 0x04a055b, 0x034cad6, 0x05a0b6a, 0x0460752, 0x0309725, 0x0540b45, 0x03e0a8b,
+    0x028549b, 0x04e04ab,
+];
+
+/// A lunisolar calendar. The two calendars follow the same rules but reckon new moons and
+/// solar terms at different meridians, so they occasionally start a month, or insert a leap
+/// month, a day or a month apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LunarCalendar {
+    /// The Korean (Dangi) calendar, reckoned in Korea Standard Time (UTC+9).
+    Korean,
+    /// The Chinese calendar, reckoned in China Standard Time (UTC+8).
+    Chinese,
+}
+
+impl LunarCalendar {
+    /// Returns the month followed by a leap month in the lunar `year`, if any.
+    pub fn leap_month(&self, year: u64) -> Result<Option<u64>, String> {
+        let month = LunarYear::new(*self, year)?.leap_month();
+        Ok((month != 0).then_some(month))
+    }
+
+    /// Returns the number of days (29 or 30) in a month of the lunar `year`.
+    pub fn days_in_month(&self, year: u64, month: u64, leap_month: bool) -> Result<u64, String> {
+        let year = LunarYear::new(*self, year)?;
+        Ok(year.length(year.index(month, leap_month)?))
+    }
+
+    /// Returns the Gregorian date of the lunar new year in `year`.
+    pub fn new_year(&self, year: u64) -> Result<Date, String> {
+        LunarYear::new(*self, year)?.start()
+    }
+}
+
+// A table entry for one lunar year.
+#[derive(Clone, Copy)]
+struct LunarYear {
+    year: u64,
+    entry: u32,
+}
+
+impl LunarYear {
+    fn new(calendar: LunarCalendar, year: u64) -> Result<Self, String> {
+        if !(FIRST_YEAR..=LAST_YEAR).contains(&year) {
+            return Err("Lunar year out of range".to_string());
+        }
+        let table = match calendar {
+            LunarCalendar::Korean => &KOREAN,
+            LunarCalendar::Chinese => &CHINESE,
+        };
+        Ok(Self {
+            year,
+            entry: table[(year - FIRST_YEAR) as usize],
+        })
+    }
+
+    fn leap_month(&self) -> u64 {
+        ((self.entry >> 13) & 0xf) as u64
+    }
+
+    fn months(&self) -> u64 {
+        if self.leap_month() == 0 {
+            12
+        } else {
+            13
+        }
+    }
+
+    fn length(&self, index: u64) -> u64 {
+        if self.entry & (1 << index) != 0 {
+            30
+        } else {
+            29
+        }
+    }
+
+    // Position of a month within the year, counting the leap month.
+    fn index(&self, month: u64, leap_month: bool) -> Result<u64, String> {
+        let leap = self.leap_month();
+        if !(1..=12).contains(&month) || (leap_month && leap != month) {
+            return Err("Invalid lunar month".to_string());
+        }
+        Ok(if leap_month || (leap != 0 && month > leap) {
+            month
+        } else {
+            month - 1
+        })
+    }
+
+    // The month and leap flag at a position within the year.
+    fn month_at(&self, index: u64) -> (u64, bool) {
+        let leap = self.leap_month();
+        if leap != 0 && index >= leap {
+            (index, index == leap)
+        } else {
+            (index + 1, false)
+        }
+    }
+
+    fn start(&self) -> Result<Date, String> {
+        Date::new(self.year, 1, 1)?.add_days((self.entry >> 17) as i64)
+    }
+}
+
+/// A date in the Korean or Chinese lunisolar calendar.
+///
+/// Conversions use tables compiled into timekit that cover the lunar years 1899 to 2100,
+/// which include every Gregorian date from 1900 through 2100. The tables were computed from
+/// astronomical new moons and solar terms at the meridian of each calendar.
+///
+/// # Example:
+/// ```
+/// use timekit::{Date, LunarCalendar, LunarDate};
+/// // Chuseok, the 15th day of the 8th month
+/// let chuseok = LunarDate::new(LunarCalendar::Korean, 2024, 8, false, 15).unwrap();
+/// assert_eq!(chuseok.to_date(), Date::new(2024, 9, 17).unwrap());
+///
+/// // 2023 had a leap month after the 2nd month
+/// let date = Date::new(2023, 4, 1).unwrap();
+/// let lunar = date.to_lunar(LunarCalendar::Chinese).unwrap();
+/// assert_eq!((lunar.month, lunar.leap_month, lunar.day), (2, true, 11));
+/// assert_eq!(lunar.to_string(), "2023-02L-11");
+/// assert_eq!(lunar.to_date(), date);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LunarDate {
+    pub calendar: LunarCalendar,
+    pub year: u64,
+    pub month: u64,
+    /// Whether this is the leap month that follows `month`.
+    pub leap_month: bool,
+    pub day: u64,
+}
+
+impl LunarDate {
+    /// Creates a new `LunarDate`, checking that the month exists in that year and that the
+    /// day fits the month.
+    pub fn new(
+        calendar: LunarCalendar,
+        year: u64,
+        month: u64,
+        leap_month: bool,
+        day: u64,
+    ) -> Result<Self, String> {
+        let days = calendar.days_in_month(year, month, leap_month)?;
+        if day < 1 || day > days {
+            return Err("Invalid lunar day".to_string());
+        }
+        Ok(Self {
+            calendar,
+            year,
+            month,
+            leap_month,
+            day,
+        })
+    }
+
+    /// Converts a Gregorian date to the lunar calendar.
+    pub fn from_date(calendar: LunarCalendar, date: Date) -> Result<Self, String> {
+        if date.year < FIRST_YEAR {
+            return Err("Date out of range for the lunar calendar".to_string());
+        }
+        let mut year = LunarYear::new(calendar, date.year.min(LAST_YEAR))?;
+        if year.start()? > date {
+            year = LunarYear::new(calendar, year.year - 1)
+                .map_err(|_| "Date out of range for the lunar calendar".to_string())?;
+        }
+        let mut days = (date.days_since_epoch() - year.start()?.days_since_epoch()) as u64;
+        for index in 0..year.months() {
+            let length = year.length(index);
+            if days < length {
+                let (month, leap_month) = year.month_at(index);
+                return Ok(Self {
+                    calendar,
+                    year: year.year,
+                    month,
+                    leap_month,
+                    day: days + 1,
+                });
+            }
+            days -= length;
+        }
+        Err("Date out of range for the lunar calendar".to_string())
+    }
+
+    /// Converts the lunar date to a Gregorian date.
+    pub fn to_date(&self) -> Date {
+        let year = LunarYear::new(self.calendar, self.year).expect("validated lunar year");
+        let index = year
+            .index(self.month, self.leap_month)
+            .expect("validated lunar month");
+        let preceding: u64 = (0..index).map(|i| year.length(i)).sum();
+        year.start()
+            .and_then(|start| start.add_days((preceding + self.day - 1) as i64))
+            .expect("lunar table dates are valid")
+    }
+
+    /// Returns the Gregorian date on which this lunar date recurs in the lunar `year`, such
+    /// as a lunar birthday. A leap month falls back to the regular month of the same number
+    /// when `year` has no such leap month, and the 30th day falls back to the 29th in a short
+    /// month.
+    pub fn anniversary(&self, year: u64) -> Result<Date, String> {
+        let leap_month = self.leap_month && self.calendar.leap_month(year)? == Some(self.month);
+        let days = self.calendar.days_in_month(year, self.month, leap_month)?;
+        Ok(Self::new(
+            self.calendar,
+            year,
+            self.month,
+            leap_month,
+            self.day.min(days),
+        )?
+        .to_date())
+    }
+}
+
+impl fmt::Display for LunarDate {
+    /// Formats the date as `YYYY-MM-DD`, with an `L` after the month for a leap month.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let leap = if self.leap_month { "L" } else { "" };
+        write!(
+            f,
+            "{:04}-{:02}{}-{:02}",
+            self.year, self.month, leap, self.day
+        )
+    }
+}
+
+impl Date {
+    /// Converts the date to the Korean or Chinese lunar calendar.
+    pub fn to_lunar(&self, calendar: LunarCalendar) -> Result<LunarDate, String> {
+        LunarDate::from_date(calendar, *self)
+    }
+}
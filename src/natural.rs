@@ -0,0 +1,335 @@
+use crate::constants::*;
+use crate::{Date, DateTime, Interval, Period, TimeDelta, TimeUnit, TimeZone, Weekday};
+
+/// A day relative to the reference date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelativeDay {
+    /// A number of days from today: 0 for today, 1 for tomorrow, -1 for yesterday.
+    Days(i64),
+    /// The next `Weekday` on or after today ("friday", "this friday").
+    This(Weekday),
+    /// The first `Weekday` strictly after today ("next friday").
+    Next(Weekday),
+    /// The last `Weekday` strictly before today ("last friday").
+    Last(Weekday),
+}
+
+/// A relative date or time, independent of the language it was written in.
+///
+/// A `RelativeParser` turns text into an expression, and `resolve` turns the expression into
+/// an instant or an interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelativeExpression {
+    /// The reference instant itself.
+    Now,
+    /// A day, optionally at an hour and minute. Without a time, it resolves to the whole day.
+    Day {
+        day: RelativeDay,
+        time: Option<(u64, u64)>,
+    },
+    /// An amount of units from the reference instant, in the past when negative. Months and
+    /// years are calendar units, as with `Period`.
+    Offset { amount: i64, unit: TimeUnit },
+    /// The unit containing the reference instant, or one `offset` units away: "this month"
+    /// is 0 months and "last week" is -1 weeks. Weeks start on Monday.
+    Period { unit: TimeUnit, offset: i64 },
+}
+
+/// What a relative expression resolves to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Resolved {
+    /// An exact instant, e.g. "tomorrow 9am" or "2 days ago".
+    Instant(DateTime),
+    /// A span for fuzzy input, e.g. "last week" or "next friday".
+    Interval(Interval),
+}
+
+impl Resolved {
+    /// Returns the instant, or the start of the interval.
+    pub fn start(&self) -> DateTime {
+        match self {
+            Resolved::Instant(datetime) => *datetime,
+            Resolved::Interval(interval) => interval.start(),
+        }
+    }
+}
+
+impl RelativeExpression {
+    /// Resolves the expression against `reference`, as seen on the wall clock of `timezone`.
+    /// The result is in `timezone`.
+    pub fn resolve(&self, reference: &DateTime, timezone: TimeZone) -> Result<Resolved, String> {
+        let reference = DateTime::from_unix_seconds(reference.to_unix_seconds(), timezone)?;
+        match *self {
+            RelativeExpression::Now => Ok(Resolved::Instant(reference)),
+            RelativeExpression::Day { day, time } => {
+                let today = reference.date();
+                let date = match day {
+                    RelativeDay::Days(days) => today.add_days(days)?,
+                    RelativeDay::This(weekday) if today.weekday() == weekday => today,
+                    RelativeDay::This(weekday) | RelativeDay::Next(weekday) => {
+                        today.next_weekday(weekday)?
+                    }
+                    RelativeDay::Last(weekday) => today.previous_weekday(weekday)?,
+                };
+                match time {
+                    Some((hour, minute)) => date
+                        .and_hms(hour, minute, 0, timezone)
+                        .map(Resolved::Instant),
+                    None => {
+                        let midnight = |date: Date| date.and_hms(0, 0, 0, timezone);
+                        Interval::new(midnight(date)?, midnight(date.add_days(1)?)?)
+                            .map(Resolved::Interval)
+                    }
+                }
+            }
+            RelativeExpression::Offset { amount, unit } => {
+                shift(&reference, unit, amount).map(Resolved::Instant)
+            }
+            RelativeExpression::Period { unit, offset } => {
+                let start = shift(&reference.trunc(unit)?, unit, offset)?;
+                Interval::new(start, shift(&start, unit, 1)?).map(Resolved::Interval)
+            }
+        }
+    }
+}
+
+// `datetime` moved by `amount` units, on the calendar for months and years.
+fn shift(datetime: &DateTime, unit: TimeUnit, amount: i64) -> Result<DateTime, String> {
+    let seconds = match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Minute => SECONDS_IN_MINUTE,
+        TimeUnit::Hour => SECONDS_IN_HOUR,
+        TimeUnit::Day => SECONDS_IN_DAY,
+        TimeUnit::Week => SECONDS_IN_WEEK,
+        TimeUnit::Month => return datetime.add_period(Period::months(amount)),
+        TimeUnit::Year => return datetime.add_period(Period::years(amount)),
+    };
+    let seconds = amount
+        .checked_mul(seconds)
+        .ok_or_else(|| "Offset is too large".to_string())?;
+    datetime.add_timedelta(TimeDelta::from_seconds(seconds))
+}
+
+/// Parses relative date and time expressions written in one language.
+///
+/// `EnglishParser` is built in; other languages implement `parse_expression` and get `parse`
+/// for free.
+///
+/// # Example:
+/// ```
+/// use timekit::{RelativeDay, RelativeExpression, RelativeParser};
+///
+/// // A parser for a couple of Korean expressions
+/// struct Korean;
+///
+/// impl RelativeParser for Korean {
+///     fn parse_expression(&self, input: &str) -> Result<RelativeExpression, String> {
+///         match input.trim() {
+///             "지금" => Ok(RelativeExpression::Now),
+///             "내일 오전 9시" => Ok(RelativeExpression::Day {
+///                 day: RelativeDay::Days(1),
+///                 time: Some((9, 0)),
+///             }),
+///             _ => Err(format!("Unrecognised expression: {}", input)),
+///         }
+///     }
+/// }
+///
+/// use timekit::{DateTime, TimeZone};
+/// let reference = DateTime::new(2024, 5, 17, 3, 0, 0, TimeZone::UTC).unwrap();
+/// let resolved = Korean.parse("내일 오전 9시", &reference, TimeZone::KST).unwrap();
+/// assert_eq!(resolved.start().to_string(), "2024-05-18 09:00:00");
+/// ```
+pub trait RelativeParser {
+    /// Parses `input` into an expression, without resolving it.
+    fn parse_expression(&self, input: &str) -> Result<RelativeExpression, String>;
+
+    /// Parses `input` and resolves it against `reference` in `timezone`.
+    fn parse(
+        &self,
+        input: &str,
+        reference: &DateTime,
+        timezone: TimeZone,
+    ) -> Result<Resolved, String> {
+        self.parse_expression(input)?.resolve(reference, timezone)
+    }
+}
+
+/// Parses relative expressions in English, ignoring case:
+///
+/// - `now`
+/// - days: `today`, `tomorrow`, `yesterday`, `friday`, `this friday`, `next friday`,
+///   `last friday`
+/// - times: `9am`, `9:30 pm`, `21:00`, `noon`, `midnight`, optionally after `at` and
+///   before or after a day; a time alone means today
+/// - offsets: `in 3 weeks`, `2 days ago`, `an hour from now`
+/// - periods: `this week`, `last month`, `next year`
+///
+/// Units are seconds, minutes, hours, days, weeks, months and years, singular or plural,
+/// with the abbreviations `sec`, `min` and `hr`. A day without a time and a period resolve to
+/// intervals; everything else resolves to an instant.
+///
+/// # Example:
+/// ```
+/// use timekit::{DateTime, EnglishParser, RelativeParser, Resolved, TimeZone};
+/// // Friday, May 17th 2024
+/// let reference = DateTime::new(2024, 5, 17, 15, 30, 0, TimeZone::UTC).unwrap();
+/// let tomorrow = EnglishParser.parse("tomorrow 9am", &reference, TimeZone::UTC).unwrap();
+/// assert_eq!(tomorrow.start().to_string(), "2024-05-18 09:00:00");
+///
+/// let last_week = EnglishParser.parse("last week", &reference, TimeZone::UTC).unwrap();
+/// let Resolved::Interval(week) = last_week else { panic!("expected an interval") };
+/// assert_eq!(week.start().to_string(), "2024-05-06 00:00:00");
+/// assert_eq!(week.end().to_string(), "2024-05-13 00:00:00");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EnglishParser;
+
+impl RelativeParser for EnglishParser {
+    fn parse_expression(&self, input: &str) -> Result<RelativeExpression, String> {
+        let invalid = || format!("Unrecognised expression: {}", input.trim());
+        let lowered = input.to_lowercase().replace(',', " ");
+        let words: Vec<&str> = lowered.split_whitespace().collect();
+
+        match words.as_slice() {
+            ["now"] | ["right", "now"] => return Ok(RelativeExpression::Now),
+            ["in", amount, unit] => {
+                return Ok(RelativeExpression::Offset {
+                    amount: parse_amount(amount).ok_or_else(invalid)?,
+                    unit: parse_unit(unit).ok_or_else(invalid)?,
+                })
+            }
+            [amount, unit, "ago"] => {
+                return Ok(RelativeExpression::Offset {
+                    amount: -parse_amount(amount).ok_or_else(invalid)?,
+                    unit: parse_unit(unit).ok_or_else(invalid)?,
+                })
+            }
+            [amount, unit, "from", "now"] => {
+                return Ok(RelativeExpression::Offset {
+                    amount: parse_amount(amount).ok_or_else(invalid)?,
+                    unit: parse_unit(unit).ok_or_else(invalid)?,
+                })
+            }
+            [which @ ("this" | "next" | "last"), unit] if parse_unit(unit).is_some() => {
+                let offset = match *which {
+                    "last" => -1,
+                    "this" => 0,
+                    _ => 1,
+                };
+                let unit = parse_unit(unit).ok_or_else(invalid)?;
+                return Ok(RelativeExpression::Period { unit, offset });
+            }
+            _ => {}
+        }
+
+        let mut day = None;
+        let mut time = None;
+        let mut index = 0;
+        while index < words.len() {
+            let word = words[index];
+            index += 1;
+            let parsed_day = match word {
+                "at" => continue,
+                "today" => Some(RelativeDay::Days(0)),
+                "tomorrow" => Some(RelativeDay::Days(1)),
+                "yesterday" => Some(RelativeDay::Days(-1)),
+                "this" | "next" | "last" => {
+                    let weekday: Weekday = words
+                        .get(index)
+                        .ok_or_else(invalid)?
+                        .parse()
+                        .map_err(|_| invalid())?;
+                    index += 1;
+                    Some(match word {
+                        "this" => RelativeDay::This(weekday),
+                        "next" => RelativeDay::Next(weekday),
+                        _ => RelativeDay::Last(weekday),
+                    })
+                }
+                _ => word.parse().ok().map(RelativeDay::This),
+            };
+            if let Some(parsed_day) = parsed_day {
+                if day.replace(parsed_day).is_some() {
+                    return Err(invalid());
+                }
+                continue;
+            }
+
+            // A time, possibly followed by a separate "am" or "pm".
+            let parsed_time = match words.get(index) {
+                Some(&meridiem @ ("am" | "pm")) => {
+                    index += 1;
+                    parse_time(&format!("{}{}", word, meridiem))
+                }
+                _ => parse_time(word),
+            };
+            match parsed_time {
+                Some(parsed_time) if time.is_none() => time = Some(parsed_time),
+                _ => return Err(invalid()),
+            }
+        }
+
+        if day.is_none() && time.is_none() {
+            return Err(invalid());
+        }
+        Ok(RelativeExpression::Day {
+            day: day.unwrap_or(RelativeDay::Days(0)),
+            time,
+        })
+    }
+}
+
+fn parse_amount(word: &str) -> Option<i64> {
+    match word {
+        "a" | "an" | "one" => Some(1),
+        _ => word.parse().ok().filter(|amount: &i64| *amount >= 0),
+    }
+}
+
+fn parse_unit(word: &str) -> Option<TimeUnit> {
+    let singular = word.strip_suffix('s').unwrap_or(word);
+    match singular {
+        "second" | "sec" => Some(TimeUnit::Second),
+        "minute" | "min" => Some(TimeUnit::Minute),
+        "hour" | "hr" => Some(TimeUnit::Hour),
+        "day" => Some(TimeUnit::Day),
+        "week" => Some(TimeUnit::Week),
+        "month" => Some(TimeUnit::Month),
+        "year" => Some(TimeUnit::Year),
+        _ => None,
+    }
+}
+
+// Parses `noon`, `midnight`, `9am`, `9:30pm` or `21:00` into an hour and minute.
+fn parse_time(word: &str) -> Option<(u64, u64)> {
+    match word {
+        "noon" | "midday" => return Some((12, 0)),
+        "midnight" => return Some((0, 0)),
+        _ => {}
+    }
+    let (clock, meridiem) = match word.strip_suffix("am") {
+        Some(clock) => (clock, Some(false)),
+        None => match word.strip_suffix("pm") {
+            Some(clock) => (clock, Some(true)),
+            None => (word, None),
+        },
+    };
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) if minute.len() == 2 => (hour, minute.parse().ok()?),
+        Some(_) => return None,
+        // A bare number is only a time with "am" or "pm".
+        None if meridiem.is_some() => (clock, 0),
+        None => return None,
+    };
+    let hour: u64 = hour.parse().ok()?;
+    if minute > 59 {
+        return None;
+    }
+    match meridiem {
+        Some(pm) if (1..=12).contains(&hour) => Some((hour % 12 + if pm { 12 } else { 0 }, minute)),
+        Some(_) => None,
+        None if hour <= 23 => Some((hour, minute)),
+        None => None,
+    }
+}
@@ -0,0 +1,287 @@
+use crate::constants::*;
+use crate::{leap, Date, DateTime, TimeZone};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// Styles for writing a UTC offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetFormat {
+    /// `+05:45`
+    Extended,
+    /// `+0545`
+    Basic,
+    /// `Z` for a zero offset, otherwise `+05:45`
+    Zulu,
+}
+
+/// An instant paired with the exact UTC offset it was observed in.
+///
+/// Unlike `DateTime`, the offset is not limited to the `TimeZone` variants, so a timestamp
+/// such as `2024-05-01T10:00:00+05:45` keeps its offset when it is parsed and written back.
+/// Two values are equal (and ordered) by the instant they represent, regardless of offset.
+#[derive(Debug, Clone, Copy)]
+pub struct OffsetDateTime {
+    unix_seconds: i64,
+    offset_seconds: i64,
+}
+
+impl OffsetDateTime {
+    /// Creates an `OffsetDateTime` from Unix seconds and an offset east of UTC in seconds.
+    ///
+    /// The offset must be a whole number of minutes strictly between -24 and +24 hours, and
+    /// the local wall time must not fall before 1970-01-01.
+    pub fn new(unix_seconds: i64, offset_seconds: i64) -> Result<Self, String> {
+        if offset_seconds.abs() >= SECONDS_IN_DAY {
+            return Err("Offset must be less than 24 hours".to_string());
+        }
+        if offset_seconds % SECONDS_IN_MINUTE != 0 {
+            return Err("Offset must be a whole number of minutes".to_string());
+        }
+        if unix_seconds + offset_seconds < 0 {
+            return Err("Unix seconds cannot represent a date before 1970-01-01".to_string());
+        }
+        Ok(Self {
+            unix_seconds,
+            offset_seconds,
+        })
+    }
+
+    /// Creates an `OffsetDateTime` from local wall-clock fields and an offset in seconds.
+    ///
+    /// `second` may be 60 only where the UTC instant is a leap second, which is then read as
+    /// the second after it, since an `OffsetDateTime` counts Unix seconds.
+    pub fn from_local(
+        year: u64,
+        month: u64,
+        day: u64,
+        hour: u64,
+        minute: u64,
+        second: u64,
+        offset_seconds: i64,
+    ) -> Result<Self, String> {
+        if second == 60 {
+            // A leap second is checked against the UTC instant, not the local wall clock.
+            let before = Self::from_local(year, month, day, hour, minute, 59, offset_seconds)?;
+            if !leap::is_builtin_leap_second(before.unix_seconds + 1) {
+                return Err("Invalid second".to_string());
+            }
+            return Self::new(before.unix_seconds + 1, offset_seconds);
+        }
+        // Validate the wall-clock fields the same way `DateTime` does.
+        DateTime::new(year, month, day, hour, minute, second, TimeZone::UTC)?;
+        let local_seconds =
+            DateTime::calculate_total_seconds(year, month, day, hour, minute, second)?;
+        Self::new(local_seconds - offset_seconds, offset_seconds)
+    }
+
+    /// Parses an RFC 3339 / ISO 8601 timestamp such as `2024-05-01T10:00:00+05:45`.
+    ///
+    /// The date and time may be separated by `T`, `t` or a space, and the offset may be
+    /// written as `Z`, `+05:45`, `+0545` or `+05`. Fractional seconds are accepted and
+    /// truncated, since timekit stores whole seconds.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        let invalid = || format!("Invalid timestamp: {}", s);
+
+        let (date, rest) = s.split_at_checked(10).ok_or_else(invalid)?;
+        let mut rest_chars = rest.chars();
+        if !matches!(rest_chars.next(), Some('T' | 't' | ' ')) {
+            return Err(invalid());
+        }
+        let rest = rest_chars.as_str();
+
+        let date: Date = date.parse()?;
+
+        let offset_start = rest
+            .find(['Z', 'z', '+', '-'])
+            .ok_or_else(|| format!("Missing UTC offset: {}", s))?;
+        let (time, offset) = rest.split_at(offset_start);
+        let (hour, minute, second) = parse_time(time).ok_or_else(invalid)?;
+        let offset_seconds = parse_offset(offset)?;
+
+        Self::from_local(
+            date.year,
+            date.month,
+            date.day,
+            hour,
+            minute,
+            second,
+            offset_seconds,
+        )
+    }
+
+    /// Returns the instant as seconds since the Unix epoch.
+    pub const fn unix_seconds(&self) -> i64 {
+        self.unix_seconds
+    }
+
+    /// Returns the UTC offset in seconds east of UTC.
+    pub const fn offset_in_seconds(&self) -> i64 {
+        self.offset_seconds
+    }
+
+    /// Returns the same instant viewed with a different offset.
+    pub fn with_offset(&self, offset_seconds: i64) -> Result<Self, String> {
+        Self::new(self.unix_seconds, offset_seconds)
+    }
+
+    /// Converts the instant into a `DateTime` in the given time zone.
+    pub fn to_datetime(&self, timezone: TimeZone) -> Result<DateTime, String> {
+        DateTime::from_unix_seconds(self.unix_seconds, timezone)
+    }
+
+    /// Returns the local wall-clock fields as a `DateTime` whose `timezone` is UTC.
+    ///
+    /// The returned value shows the local time in this value's offset; it does not represent
+    /// the same instant unless the offset is zero.
+    pub fn local(&self) -> DateTime {
+        DateTime::from_unix_seconds(self.unix_seconds + self.offset_seconds, TimeZone::UTC)
+            .expect("local time is validated on construction")
+    }
+
+    /// Formats the offset in the requested style.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{OffsetDateTime, OffsetFormat};
+    /// let timestamp = OffsetDateTime::parse("2024-05-01T10:00:00+05:45").unwrap();
+    /// assert_eq!(timestamp.format_offset(OffsetFormat::Extended), "+05:45");
+    /// assert_eq!(timestamp.format_offset(OffsetFormat::Basic), "+0545");
+    /// ```
+    pub fn format_offset(&self, format: OffsetFormat) -> String {
+        if self.offset_seconds == 0 && format == OffsetFormat::Zulu {
+            return "Z".to_string();
+        }
+        let sign = if self.offset_seconds < 0 { '-' } else { '+' };
+        let hours = self.offset_seconds.abs() / SECONDS_IN_HOUR;
+        let minutes = self.offset_seconds.abs() % SECONDS_IN_HOUR / SECONDS_IN_MINUTE;
+        match format {
+            OffsetFormat::Basic => format!("{}{:02}{:02}", sign, hours, minutes),
+            OffsetFormat::Extended | OffsetFormat::Zulu => {
+                format!("{}{:02}:{:02}", sign, hours, minutes)
+            }
+        }
+    }
+
+    /// Formats the value as an RFC 3339 timestamp using the given offset style.
+    pub fn to_rfc3339(&self, format: OffsetFormat) -> String {
+        let local = self.local();
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}",
+            local.year,
+            local.month,
+            local.day,
+            local.hour,
+            local.minute,
+            local.second,
+            self.format_offset(format)
+        )
+    }
+}
+
+impl From<DateTime> for OffsetDateTime {
+    fn from(datetime: DateTime) -> Self {
+        Self {
+            unix_seconds: datetime.to_unix_seconds(),
+            offset_seconds: datetime.timezone.offset_in_seconds(),
+        }
+    }
+}
+
+impl fmt::Display for OffsetDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_rfc3339(OffsetFormat::Extended))
+    }
+}
+
+impl FromStr for OffsetDateTime {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl PartialEq for OffsetDateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.unix_seconds == other.unix_seconds
+    }
+}
+
+impl Eq for OffsetDateTime {}
+
+impl PartialOrd for OffsetDateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OffsetDateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.unix_seconds.cmp(&other.unix_seconds)
+    }
+}
+
+impl Hash for OffsetDateTime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.unix_seconds.hash(state);
+    }
+}
+
+// Parses `HH:MM:SS` with an optional fractional part, which is discarded.
+fn parse_time(s: &str) -> Option<(u64, u64, u64)> {
+    let s = match s.split_once(['.', ',']) {
+        Some((whole, fraction)) => {
+            parse_digits(fraction)?;
+            whole
+        }
+        None => s,
+    };
+    let bytes = s.as_bytes();
+    if bytes.len() != 8 || bytes[2] != b':' || bytes[5] != b':' {
+        return None;
+    }
+    Some((
+        parse_digits(&s[0..2])?,
+        parse_digits(&s[3..5])?,
+        parse_digits(&s[6..8])?,
+    ))
+}
+
+// Parses `Z`, `+HH:MM`, `+HHMM` or `+HH` into seconds east of UTC.
+pub(crate) fn parse_offset(s: &str) -> Result<i64, String> {
+    let invalid = || format!("Invalid UTC offset: {}", s);
+    if s.eq_ignore_ascii_case("Z") {
+        return Ok(0);
+    }
+    let sign = match s.chars().next() {
+        Some('+') => 1,
+        Some('-') => -1,
+        _ => return Err(invalid()),
+    };
+    let digits = &s[1..];
+    if !digits.is_ascii() {
+        return Err(invalid());
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (digits, "00"),
+        4 => digits.split_at(2),
+        5 if digits.as_bytes()[2] == b':' => (&digits[0..2], &digits[3..5]),
+        _ => return Err(invalid()),
+    };
+    let hours = parse_digits(hours).ok_or_else(invalid)? as i64;
+    let minutes = parse_digits(minutes).ok_or_else(invalid)? as i64;
+    if hours > 23 || minutes > 59 {
+        return Err(invalid());
+    }
+    Ok(sign * (hours * SECONDS_IN_HOUR + minutes * SECONDS_IN_MINUTE))
+}
+
+fn parse_digits(s: &str) -> Option<u64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
@@ -0,0 +1,148 @@
+use crate::{days_in_month, Date, DateTime};
+use std::ops::{Add, Sub};
+
+/// A calendar amount of time: years, months and days.
+///
+/// Unlike `TimeDelta`, a `Period` is applied to the local calendar, so one month always lands on
+/// the same day of the following month regardless of how many days that month has. When the
+/// target month is too short, the day is clamped to its last day (January 31st plus one month is
+/// February 28th or 29th). Years and months are applied first, then days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Period {
+    pub years: i64,
+    pub months: i64,
+    pub days: i64,
+}
+
+impl Period {
+    /// Returns a period of the given number of years.
+    pub const fn years(years: i64) -> Self {
+        Self {
+            years,
+            months: 0,
+            days: 0,
+        }
+    }
+
+    /// Returns a period of the given number of months.
+    pub const fn months(months: i64) -> Self {
+        Self {
+            years: 0,
+            months,
+            days: 0,
+        }
+    }
+
+    /// Returns a period of the given number of days.
+    pub const fn days(days: i64) -> Self {
+        Self {
+            years: 0,
+            months: 0,
+            days,
+        }
+    }
+
+    /// Returns `true` if every component is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.years == 0 && self.months == 0 && self.days == 0
+    }
+
+    /// Returns the years and months components combined into months.
+    pub fn total_months(&self) -> Result<i64, String> {
+        self.years
+            .checked_mul(12)
+            .and_then(|months| months.checked_add(self.months))
+            .ok_or_else(|| "Period is too large".to_string())
+    }
+
+    /// Returns the period with every component multiplied by `factor`.
+    pub fn checked_mul(&self, factor: i64) -> Result<Self, String> {
+        let overflow = || "Period is too large".to_string();
+        Ok(Self {
+            years: self.years.checked_mul(factor).ok_or_else(overflow)?,
+            months: self.months.checked_mul(factor).ok_or_else(overflow)?,
+            days: self.days.checked_mul(factor).ok_or_else(overflow)?,
+        })
+    }
+
+    /// Returns the period with every component negated.
+    pub const fn negated(&self) -> Self {
+        Self {
+            years: -self.years,
+            months: -self.months,
+            days: -self.days,
+        }
+    }
+}
+
+impl Date {
+    /// Adds a calendar `Period`, clamping the day to the end of the target month.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{Date, Period};
+    /// let date = Date::new(2024, 1, 31).unwrap();
+    /// assert_eq!(date.add_period(Period::months(1)).unwrap().to_string(), "2024-02-29");
+    /// ```
+    pub fn add_period(&self, period: Period) -> Result<Self, String> {
+        let months = (self.year as i64)
+            .checked_mul(12)
+            .and_then(|months| months.checked_add(self.month as i64 - 1))
+            .and_then(|months| months.checked_add(period.total_months().ok()?))
+            .ok_or_else(|| "Period is too large".to_string())?;
+        if months < 0 {
+            return Err("Resulting date is before year 0".to_string());
+        }
+        let year = (months / 12) as u64;
+        let month = (months % 12) as u64 + 1;
+        let day = self.day.min(days_in_month(month, year));
+        Date::new(year, month, day)?.add_days(period.days)
+    }
+
+    /// Subtracts a calendar `Period`; the inverse of `add_period` up to day clamping.
+    pub fn sub_period(&self, period: Period) -> Result<Self, String> {
+        self.add_period(period.negated())
+    }
+}
+
+impl DateTime {
+    /// Adds a calendar `Period` to the local date, keeping the local time of day.
+    pub fn add_period(&self, period: Period) -> Result<Self, String> {
+        let date = self.date().add_period(period)?;
+        if date.year < 1970 {
+            return Err(
+                "Resulting DateTime is before Unix epoch (1970-01-01 00:00:00 UTC)".to_string(),
+            );
+        }
+        DateTime::new(
+            date.year,
+            date.month,
+            date.day,
+            self.hour,
+            self.minute,
+            self.second,
+            self.timezone,
+        )
+    }
+
+    /// Subtracts a calendar `Period` from the local date, keeping the local time of day.
+    pub fn sub_period(&self, period: Period) -> Result<Self, String> {
+        self.add_period(period.negated())
+    }
+}
+
+impl Add<Period> for DateTime {
+    type Output = Result<DateTime, String>;
+
+    fn add(self, period: Period) -> Self::Output {
+        DateTime::add_period(&self, period)
+    }
+}
+
+impl Sub<Period> for DateTime {
+    type Output = Result<DateTime, String>;
+
+    fn sub(self, period: Period) -> Self::Output {
+        DateTime::sub_period(&self, period)
+    }
+}
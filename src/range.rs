@@ -0,0 +1,244 @@
+use crate::constants::*;
+use crate::{Date, DateTime, Period, TimeDelta};
+use std::iter::FusedIterator;
+
+/// The distance between consecutive values of a `TimeRange`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// A fixed amount of elapsed time.
+    Delta(TimeDelta),
+    /// A calendar amount applied to the local date, such as one month.
+    Period(Period),
+}
+
+impl Step {
+    // Returns 1 for forward steps and -1 for backward steps.
+    fn direction(&self) -> Result<i64, String> {
+        let signs: Vec<i64> = match self {
+            Step::Delta(delta) => vec![delta.total_seconds().signum()],
+            Step::Period(period) => [period.years, period.months, period.days]
+                .iter()
+                .map(|component| component.signum())
+                .filter(|&sign| sign != 0)
+                .collect(),
+        };
+        match signs.as_slice() {
+            [] | [0] => Err("Step must not be zero".to_string()),
+            [first, rest @ ..] if rest.iter().all(|sign| sign == first) => Ok(*first),
+            _ => Err("Period components must all have the same sign".to_string()),
+        }
+    }
+}
+
+impl From<TimeDelta> for Step {
+    fn from(delta: TimeDelta) -> Self {
+        Step::Delta(delta)
+    }
+}
+
+impl From<Period> for Step {
+    fn from(period: Period) -> Self {
+        Step::Period(period)
+    }
+}
+
+/// Values that a `TimeRange` can step through.
+pub trait RangeBound: Copy {
+    /// Returns a key that orders values along the time line.
+    fn sort_key(&self) -> i64;
+
+    /// Returns the value `times` steps away from `self`.
+    fn step_by(&self, step: &Step, times: i64) -> Result<Self, String>;
+
+    /// Checks that `step` can be used with this type.
+    fn validate_step(_step: &Step) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+impl RangeBound for DateTime {
+    fn sort_key(&self) -> i64 {
+        self.to_unix_seconds()
+    }
+
+    fn step_by(&self, step: &Step, times: i64) -> Result<Self, String> {
+        match step {
+            Step::Delta(delta) => {
+                let seconds = delta
+                    .total_seconds()
+                    .checked_mul(times)
+                    .and_then(|seconds| seconds.checked_add(self.to_unix_seconds()))
+                    .ok_or_else(|| "DateTime is out of range".to_string())?;
+                DateTime::from_unix_seconds(seconds, self.timezone)
+            }
+            Step::Period(period) => self.add_period(period.checked_mul(times)?),
+        }
+    }
+}
+
+impl RangeBound for Date {
+    fn sort_key(&self) -> i64 {
+        self.days_since_epoch()
+    }
+
+    fn step_by(&self, step: &Step, times: i64) -> Result<Self, String> {
+        match step {
+            Step::Delta(delta) => {
+                let days = delta_days(delta)?
+                    .checked_mul(times)
+                    .ok_or_else(|| "Date is out of range".to_string())?;
+                self.add_days(days)
+            }
+            Step::Period(period) => self.add_period(period.checked_mul(times)?),
+        }
+    }
+
+    fn validate_step(step: &Step) -> Result<(), String> {
+        match step {
+            Step::Delta(delta) => delta_days(delta).map(|_| ()),
+            Step::Period(_) => Ok(()),
+        }
+    }
+}
+
+fn delta_days(delta: &TimeDelta) -> Result<i64, String> {
+    let seconds = delta.total_seconds();
+    if seconds % SECONDS_IN_DAY != 0 {
+        return Err("Date ranges need a step of whole days".to_string());
+    }
+    Ok(seconds / SECONDS_IN_DAY)
+}
+
+/// A lazy sequence of `Date`s or `DateTime`s from a start towards an end in fixed steps.
+///
+/// The `k`-th value is computed as `start` plus `k` steps, so calendar steps do not drift:
+/// monthly steps from January 31st give February 29th, March 31st, April 30th, and so on.
+/// A negative step walks backwards from a later `start` to an earlier `end`; a step pointing
+/// away from `end` produces an empty range. Only the number of values is computed up front.
+///
+/// # Example:
+/// ```
+/// use timekit::{DateTime, DateTimeRange, TimeDelta, TimeZone};
+/// let start = DateTime::new(2024, 5, 1, 0, 0, 0, TimeZone::KST).unwrap();
+/// let end = DateTime::new(2024, 5, 1, 3, 0, 0, TimeZone::KST).unwrap();
+/// let hour = TimeDelta { hours: 1, ..Default::default() };
+///
+/// let hours: Vec<String> = DateTimeRange::new(start, end, hour)
+///     .unwrap()
+///     .map(|datetime| datetime.to_string())
+///     .collect();
+/// assert_eq!(hours, ["2024-05-01 00:00:00", "2024-05-01 01:00:00", "2024-05-01 02:00:00"]);
+///
+/// let mut inclusive = DateTimeRange::inclusive(start, end, hour).unwrap();
+/// assert_eq!(inclusive.next_back(), Some(end));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TimeRange<T> {
+    start: T,
+    step: Step,
+    front: i64,
+    back: i64,
+}
+
+/// A `TimeRange` over `Date`s.
+pub type DateRange = TimeRange<Date>;
+
+/// A `TimeRange` over `DateTime`s.
+pub type DateTimeRange = TimeRange<DateTime>;
+
+impl<T: RangeBound> TimeRange<T> {
+    /// Creates a range from `start` up to, but not including, `end`.
+    pub fn new<S: Into<Step>>(start: T, end: T, step: S) -> Result<Self, String> {
+        Self::build(start, end, step.into(), false)
+    }
+
+    /// Creates a range from `start` up to and including `end`.
+    pub fn inclusive<S: Into<Step>>(start: T, end: T, step: S) -> Result<Self, String> {
+        Self::build(start, end, step.into(), true)
+    }
+
+    fn build(start: T, end: T, step: Step, inclusive: bool) -> Result<Self, String> {
+        let direction = step.direction()?;
+        T::validate_step(&step)?;
+
+        // Whether the value `k` steps from `start` still lies inside the range.
+        let end_key = end.sort_key();
+        let within = |k: i64| match start.step_by(&step, k) {
+            Ok(value) => {
+                let distance = (end_key - value.sort_key()) * direction;
+                distance > 0 || (inclusive && distance == 0)
+            }
+            Err(_) => false,
+        };
+
+        // Values are monotonic in `k`, so count them with an exponential then binary search.
+        let mut count = 0;
+        if within(0) {
+            let mut high = 1;
+            while within(high) {
+                count = high;
+                high = high.checked_mul(2).ok_or("Range is too large")?;
+            }
+            let mut low = count;
+            while high - low > 1 {
+                let middle = low + (high - low) / 2;
+                if within(middle) {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            count = low + 1;
+        }
+
+        Ok(Self {
+            start,
+            step,
+            front: 0,
+            back: count,
+        })
+    }
+
+    fn value_at(&self, k: i64) -> T {
+        self.start
+            .step_by(&self.step, k)
+            .expect("values inside the range are representable")
+    }
+}
+
+impl<T: RangeBound> Iterator for TimeRange<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front >= self.back {
+            return None;
+        }
+        let value = self.value_at(self.front);
+        self.front += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.front = self.front.saturating_add(n as i64).min(self.back);
+        self.next()
+    }
+}
+
+impl<T: RangeBound> DoubleEndedIterator for TimeRange<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.value_at(self.back))
+    }
+}
+
+impl<T: RangeBound> ExactSizeIterator for TimeRange<T> {}
+
+impl<T: RangeBound> FusedIterator for TimeRange<T> {}
@@ -0,0 +1,1187 @@
+use crate::constants::*;
+use crate::{days_in_month, is_leap_year, Date, DateTime, TimeZone, Weekday};
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::iter::{FusedIterator, Peekable};
+use std::str::FromStr;
+
+// Expansion stops at the end of this year even when a rule has no COUNT or UNTIL.
+const LAST_YEAR: i64 = 9999;
+
+/// How often a `RecurrenceRule` repeats (the RRULE `FREQ` part).
+///
+/// Frequencies are ordered from the shortest to the longest period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Frequency {
+    Secondly,
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl fmt::Display for Frequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Frequency::Secondly => "SECONDLY",
+            Frequency::Minutely => "MINUTELY",
+            Frequency::Hourly => "HOURLY",
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Frequency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "SECONDLY" => Ok(Frequency::Secondly),
+            "MINUTELY" => Ok(Frequency::Minutely),
+            "HOURLY" => Ok(Frequency::Hourly),
+            "DAILY" => Ok(Frequency::Daily),
+            "WEEKLY" => Ok(Frequency::Weekly),
+            "MONTHLY" => Ok(Frequency::Monthly),
+            "YEARLY" => Ok(Frequency::Yearly),
+            _ => Err(format!("Invalid frequency: {}", s)),
+        }
+    }
+}
+
+/// A `BYDAY` entry: a weekday, optionally limited to its `n`-th occurrence in the month or year.
+///
+/// Negative `n` counts from the end, so `-1FR` is the last Friday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NthWeekday {
+    pub n: Option<i64>,
+    pub weekday: Weekday,
+}
+
+impl NthWeekday {
+    /// Every `weekday` of the period, such as `MO`.
+    pub const fn every(weekday: Weekday) -> Self {
+        Self { n: None, weekday }
+    }
+
+    /// Only the `n`-th `weekday` of the period, such as `2MO` or `-1FR`.
+    pub const fn nth(n: i64, weekday: Weekday) -> Self {
+        Self {
+            n: Some(n),
+            weekday,
+        }
+    }
+}
+
+impl fmt::Display for NthWeekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(n) = self.n {
+            write!(f, "{}", n)?;
+        }
+        write!(f, "{}", weekday_code(self.weekday))
+    }
+}
+
+impl FromStr for NthWeekday {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid BYDAY value: {}", s);
+        if s.len() < 2 || !s.is_char_boundary(s.len() - 2) {
+            return Err(invalid());
+        }
+        let (number, code) = s.split_at(s.len() - 2);
+        let weekday = parse_weekday_code(code).map_err(|_| invalid())?;
+        if number.is_empty() {
+            return Ok(Self::every(weekday));
+        }
+        let n = number.parse().map_err(|_| invalid())?;
+        Ok(Self::nth(n, weekday))
+    }
+}
+
+/// The end of a recurrence (the RRULE `UNTIL` part). The bound is inclusive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Until {
+    /// `UNTIL=20241231T150000Z`: an exact instant.
+    Instant(DateTime),
+    /// `UNTIL=20241231T090000`: a wall-clock time in the zone of DTSTART.
+    Local {
+        date: Date,
+        hour: u64,
+        minute: u64,
+        second: u64,
+    },
+    /// `UNTIL=20241231`: the end of a date in the zone of DTSTART.
+    Date(Date),
+}
+
+impl Until {
+    // Returns the bound in seconds of the local wall clock in `timezone`.
+    fn local_seconds(&self, timezone: TimeZone) -> i64 {
+        match *self {
+            Until::Instant(datetime) => datetime.to_unix_seconds() + timezone.offset_in_seconds(),
+            Until::Local {
+                date,
+                hour,
+                minute,
+                second,
+            } => date.days_since_epoch() * SECONDS_IN_DAY + time_of_day(hour, minute, second),
+            Until::Date(date) => (date.days_since_epoch() + 1) * SECONDS_IN_DAY - 1,
+        }
+    }
+}
+
+impl From<DateTime> for Until {
+    fn from(datetime: DateTime) -> Self {
+        Until::Instant(datetime)
+    }
+}
+
+impl fmt::Display for Until {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Until::Instant(datetime) => {
+                let utc = DateTime::from_unix_seconds(datetime.to_unix_seconds(), TimeZone::UTC)
+                    .map_err(|_| fmt::Error)?;
+                write!(f, "{}", utc.strftime("%Y%m%dT%H%M%SZ"))
+            }
+            Until::Local {
+                date,
+                hour,
+                minute,
+                second,
+            } => write!(
+                f,
+                "{:04}{:02}{:02}T{:02}{:02}{:02}",
+                date.year, date.month, date.day, hour, minute, second
+            ),
+            Until::Date(date) => write!(f, "{:04}{:02}{:02}", date.year, date.month, date.day),
+        }
+    }
+}
+
+impl FromStr for Until {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, time) = parse_basic_datetime(s)?;
+        match time {
+            None => Ok(Until::Date(date)),
+            Some((hour, minute, second, true)) => Ok(Until::Instant(date.and_hms(
+                hour,
+                minute,
+                second,
+                TimeZone::UTC,
+            )?)),
+            Some((hour, minute, second, false)) => Ok(Until::Local {
+                date,
+                hour,
+                minute,
+                second,
+            }),
+        }
+    }
+}
+
+/// An RFC 5545 recurrence rule, such as `FREQ=MONTHLY;BYDAY=-1FR;COUNT=10`.
+///
+/// A rule on its own has no start; `occurrences` expands it from a DTSTART. Rule parts that
+/// are left empty take their value from DTSTART as RFC 5545 describes: a yearly rule without
+/// other `BY*` parts repeats on the month and day of DTSTART, a weekly rule on its weekday,
+/// and every rule coarser than hourly at its time of day.
+///
+/// Expansion follows the local wall clock in the zone of DTSTART. timekit zones have fixed
+/// offsets, so every local time exists exactly once and no occurrence is skipped or repeated.
+///
+/// # Example:
+/// ```
+/// use timekit::{DateTime, RecurrenceRule, TimeZone};
+/// let rule: RecurrenceRule = "FREQ=MONTHLY;BYDAY=-1FR;COUNT=3".parse().unwrap();
+/// let dtstart = DateTime::new(2024, 1, 26, 18, 0, 0, TimeZone::KST).unwrap();
+///
+/// let fridays: Vec<String> = rule
+///     .occurrences(dtstart)
+///     .unwrap()
+///     .map(|datetime| datetime.to_string())
+///     .collect();
+/// assert_eq!(
+///     fridays,
+///     ["2024-01-26 18:00:00", "2024-02-23 18:00:00", "2024-03-29 18:00:00"]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurrenceRule {
+    pub frequency: Frequency,
+    pub interval: u64,
+    pub count: Option<u64>,
+    pub until: Option<Until>,
+    pub by_second: Vec<u64>,
+    pub by_minute: Vec<u64>,
+    pub by_hour: Vec<u64>,
+    pub by_day: Vec<NthWeekday>,
+    pub by_month_day: Vec<i64>,
+    pub by_year_day: Vec<i64>,
+    pub by_week_no: Vec<i64>,
+    pub by_month: Vec<u64>,
+    pub by_set_pos: Vec<i64>,
+    pub week_start: Weekday,
+}
+
+impl RecurrenceRule {
+    /// Creates a rule that repeats every period of `frequency` without an end.
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+            count: None,
+            until: None,
+            by_second: Vec::new(),
+            by_minute: Vec::new(),
+            by_hour: Vec::new(),
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_year_day: Vec::new(),
+            by_week_no: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: Weekday::Monday,
+        }
+    }
+
+    /// Returns the occurrences of the rule starting at `dtstart`, in the zone of `dtstart`.
+    ///
+    /// Only times that match the rule are returned, so `dtstart` itself is skipped when it does
+    /// not match; `RecurrenceSet` always includes it and counts it toward COUNT.
+    pub fn occurrences(&self, dtstart: DateTime) -> Result<Occurrences, String> {
+        self.validate()?;
+        Ok(Occurrences::new(self, dtstart))
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let frequency = self.frequency;
+        if self.interval == 0 {
+            return Err("INTERVAL must be at least 1".to_string());
+        }
+        if self.count.is_some() && self.until.is_some() {
+            return Err("COUNT and UNTIL must not both be set".to_string());
+        }
+        check_range("BYSECOND", &self.by_second, 0, 59)?;
+        check_range("BYMINUTE", &self.by_minute, 0, 59)?;
+        check_range("BYHOUR", &self.by_hour, 0, 23)?;
+        check_range("BYMONTH", &self.by_month, 1, 12)?;
+        check_signed_range("BYMONTHDAY", &self.by_month_day, 31)?;
+        check_signed_range("BYYEARDAY", &self.by_year_day, 366)?;
+        check_signed_range("BYWEEKNO", &self.by_week_no, 53)?;
+        check_signed_range("BYSETPOS", &self.by_set_pos, 366)?;
+        let ordinals: Vec<i64> = self.by_day.iter().filter_map(|day| day.n).collect();
+        check_signed_range("BYDAY", &ordinals, 53)?;
+
+        if !ordinals.is_empty() && (frequency < Frequency::Monthly || !self.by_week_no.is_empty()) {
+            return Err(
+                "Numbered BYDAY values need FREQ=MONTHLY or FREQ=YEARLY without BYWEEKNO"
+                    .to_string(),
+            );
+        }
+        if !self.by_week_no.is_empty() && frequency != Frequency::Yearly {
+            return Err("BYWEEKNO needs FREQ=YEARLY".to_string());
+        }
+        if !self.by_year_day.is_empty()
+            && matches!(
+                frequency,
+                Frequency::Daily | Frequency::Weekly | Frequency::Monthly
+            )
+        {
+            return Err("BYYEARDAY cannot be used with FREQ=DAILY, WEEKLY or MONTHLY".to_string());
+        }
+        if !self.by_month_day.is_empty() && frequency == Frequency::Weekly {
+            return Err("BYMONTHDAY cannot be used with FREQ=WEEKLY".to_string());
+        }
+        if !self.by_set_pos.is_empty() && !self.has_by_parts() {
+            return Err("BYSETPOS needs another BY* part".to_string());
+        }
+        Ok(())
+    }
+
+    // Returns `true` if any `BY*` part other than BYSETPOS is set.
+    fn has_by_parts(&self) -> bool {
+        !(self.by_second.is_empty()
+            && self.by_minute.is_empty()
+            && self.by_hour.is_empty()
+            && self.by_day.is_empty()
+            && self.by_month_day.is_empty()
+            && self.by_year_day.is_empty()
+            && self.by_week_no.is_empty()
+            && self.by_month.is_empty())
+    }
+}
+
+impl fmt::Display for RecurrenceRule {
+    /// Formats the rule as an RRULE value, without the `RRULE:` prefix.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FREQ={}", self.frequency)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until)?;
+        }
+        write_list(f, "BYSECOND", &self.by_second)?;
+        write_list(f, "BYMINUTE", &self.by_minute)?;
+        write_list(f, "BYHOUR", &self.by_hour)?;
+        write_list(f, "BYDAY", &self.by_day)?;
+        write_list(f, "BYMONTHDAY", &self.by_month_day)?;
+        write_list(f, "BYYEARDAY", &self.by_year_day)?;
+        write_list(f, "BYWEEKNO", &self.by_week_no)?;
+        write_list(f, "BYMONTH", &self.by_month)?;
+        write_list(f, "BYSETPOS", &self.by_set_pos)?;
+        if self.week_start != Weekday::Monday {
+            write!(f, ";WKST={}", weekday_code(self.week_start))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = String;
+
+    /// Parses an RRULE value. A leading `RRULE:` is accepted, and part names are
+    /// case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let body = match s.get(..6) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("RRULE:") => &s[6..],
+            _ => s,
+        };
+
+        let mut frequency = None;
+        let mut rule = RecurrenceRule::new(Frequency::Yearly);
+        let mut seen = HashSet::new();
+        for part in body.split(';').filter(|part| !part.is_empty()) {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid RRULE part: {}", part))?;
+            let name = name.to_ascii_uppercase();
+            if !seen.insert(name.clone()) {
+                return Err(format!("Duplicate RRULE part: {}", name));
+            }
+            match name.as_str() {
+                "FREQ" => frequency = Some(value.parse()?),
+                "INTERVAL" => rule.interval = parse_value(&name, value)?,
+                "COUNT" => rule.count = Some(parse_value(&name, value)?),
+                "UNTIL" => rule.until = Some(value.parse()?),
+                "BYSECOND" => rule.by_second = parse_list(&name, value)?,
+                "BYMINUTE" => rule.by_minute = parse_list(&name, value)?,
+                "BYHOUR" => rule.by_hour = parse_list(&name, value)?,
+                "BYDAY" => rule.by_day = parse_list(&name, value)?,
+                "BYMONTHDAY" => rule.by_month_day = parse_list(&name, value)?,
+                "BYYEARDAY" => rule.by_year_day = parse_list(&name, value)?,
+                "BYWEEKNO" => rule.by_week_no = parse_list(&name, value)?,
+                "BYMONTH" => rule.by_month = parse_list(&name, value)?,
+                "BYSETPOS" => rule.by_set_pos = parse_list(&name, value)?,
+                "WKST" => rule.week_start = parse_weekday_code(value)?,
+                _ => return Err(format!("Unknown RRULE part: {}", name)),
+            }
+        }
+        rule.frequency = frequency.ok_or_else(|| "RRULE must contain FREQ".to_string())?;
+        rule.validate()?;
+        Ok(rule)
+    }
+}
+
+/// The occurrences of a `RecurrenceRule`, in chronological order.
+///
+/// Each period of the rule (a year, a month, a week, ...) is expanded only when the previous
+/// one is exhausted. Rules without COUNT or UNTIL end after the year 9999.
+#[derive(Debug, Clone)]
+pub struct Occurrences {
+    // The rule with its missing parts filled in from DTSTART.
+    rule: RecurrenceRule,
+    timezone: TimeZone,
+    // DTSTART, UNTIL and pending occurrences are in seconds of the local wall clock.
+    start: i64,
+    until: Option<i64>,
+    // The current period: a year, a month index, the first day of a week, a day, or the first
+    // second of an hour, a minute or a second, depending on the frequency.
+    period: i64,
+    last_period: i64,
+    pending: VecDeque<i64>,
+    emitted: u64,
+    finished: bool,
+}
+
+impl Occurrences {
+    fn new(rule: &RecurrenceRule, dtstart: DateTime) -> Self {
+        let mut rule = rule.clone();
+        let date = dtstart.date();
+        let frequency = rule.frequency;
+        if rule.by_week_no.is_empty()
+            && rule.by_year_day.is_empty()
+            && rule.by_month_day.is_empty()
+            && rule.by_day.is_empty()
+        {
+            match frequency {
+                Frequency::Yearly => {
+                    if rule.by_month.is_empty() {
+                        rule.by_month = vec![date.month];
+                    }
+                    rule.by_month_day = vec![date.day as i64];
+                }
+                Frequency::Monthly => rule.by_month_day = vec![date.day as i64],
+                Frequency::Weekly => rule.by_day = vec![NthWeekday::every(date.weekday())],
+                _ => {}
+            }
+        }
+        if frequency > Frequency::Hourly && rule.by_hour.is_empty() {
+            rule.by_hour = vec![dtstart.hour];
+        }
+        if frequency > Frequency::Minutely && rule.by_minute.is_empty() {
+            rule.by_minute = vec![dtstart.minute];
+        }
+        if frequency > Frequency::Secondly && rule.by_second.is_empty() {
+            rule.by_second = vec![dtstart.second.min(59)];
+        }
+        for list in [&mut rule.by_second, &mut rule.by_minute, &mut rule.by_hour] {
+            list.sort_unstable();
+            list.dedup();
+        }
+
+        let start = local_seconds(&dtstart);
+        let days = date.days_since_epoch();
+        let end_day = Date {
+            year: LAST_YEAR as u64 + 1,
+            month: 1,
+            day: 1,
+        }
+        .days_since_epoch();
+        let (period, last_period) = match frequency {
+            Frequency::Yearly => (date.year as i64, LAST_YEAR),
+            Frequency::Monthly => (
+                date.year as i64 * 12 + date.month as i64 - 1,
+                LAST_YEAR * 12 + 11,
+            ),
+            Frequency::Weekly => (
+                days - date.weekday().days_since(rule.week_start) as i64,
+                end_day - 1,
+            ),
+            Frequency::Daily => (days, end_day - 1),
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => {
+                let unit = unit_seconds(frequency);
+                (start - start.rem_euclid(unit), end_day * SECONDS_IN_DAY - 1)
+            }
+        };
+
+        let mut occurrences = Self {
+            until: rule
+                .until
+                .map(|until| until.local_seconds(dtstart.timezone)),
+            rule,
+            timezone: dtstart.timezone,
+            start,
+            period,
+            last_period,
+            pending: VecDeque::new(),
+            emitted: 0,
+            finished: false,
+        };
+        occurrences.finished = !occurrences.time_of_day_reachable();
+        occurrences
+    }
+
+    // For sub-daily frequencies, checks that some allowed time of day can be reached at all by
+    // stepping from DTSTART, so that rules such as `FREQ=HOURLY;INTERVAL=2;BYHOUR=1` starting
+    // at an even hour end immediately instead of searching until the year 9999.
+    fn time_of_day_reachable(&self) -> bool {
+        let frequency = self.rule.frequency;
+        if frequency > Frequency::Hourly {
+            return true;
+        }
+        let unit = unit_seconds(frequency);
+        let step = (self.rule.interval % SECONDS_IN_DAY as u64) as i64 * unit;
+        let cycle = gcd(step, SECONDS_IN_DAY);
+        let values = |list: &[u64], all: u64, used: bool| -> Vec<u64> {
+            match (used, list.is_empty()) {
+                (false, _) => vec![0],
+                (true, true) => (0..all).collect(),
+                (true, false) => list.to_vec(),
+            }
+        };
+        let hours = values(&self.rule.by_hour, 24, true);
+        let minutes = values(&self.rule.by_minute, 60, frequency <= Frequency::Minutely);
+        let seconds = values(&self.rule.by_second, 60, frequency == Frequency::Secondly);
+        hours.iter().any(|&hour| {
+            minutes.iter().any(|&minute| {
+                seconds.iter().any(|&second| {
+                    (time_of_day(hour, minute, second) - self.period).rem_euclid(cycle) == 0
+                })
+            })
+        })
+    }
+
+    // Returns the first day and the day after the last day of the current period.
+    fn period_days(&self) -> (i64, i64) {
+        match self.rule.frequency {
+            Frequency::Yearly => (
+                first_day_of_year(self.period),
+                first_day_of_year(self.period + 1),
+            ),
+            Frequency::Monthly => {
+                let year = self.period.div_euclid(12) as u64;
+                let month = self.period.rem_euclid(12) as u64 + 1;
+                let first = Date {
+                    year,
+                    month,
+                    day: 1,
+                }
+                .days_since_epoch();
+                (first, first + days_in_month(month, year) as i64)
+            }
+            Frequency::Weekly => (self.period, self.period + 7),
+            Frequency::Daily => (self.period, self.period + 1),
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => {
+                let day = self.period.div_euclid(SECONDS_IN_DAY);
+                (day, day + 1)
+            }
+        }
+    }
+
+    // Returns the candidate occurrences of the current period in local seconds, and for
+    // sub-daily frequencies the earliest local time at which the next candidate can occur.
+    fn expand(&self) -> (Vec<i64>, Option<i64>) {
+        let (first_day, end_day) = self.period_days();
+        let days: Vec<i64> = (first_day..end_day)
+            .filter(|&day| self.matches_day(day))
+            .collect();
+        if days.is_empty() {
+            return (Vec::new(), Some(end_day * SECONDS_IN_DAY));
+        }
+        let (times, resume) = self.times_of_day();
+
+        let mut candidates: Vec<i64> = days
+            .iter()
+            .flat_map(|day| times.iter().map(move |time| day * SECONDS_IN_DAY + time))
+            .collect();
+        if !self.rule.by_set_pos.is_empty() {
+            let len = candidates.len() as i64;
+            let mut selected: Vec<i64> = self
+                .rule
+                .by_set_pos
+                .iter()
+                .map(|&position| {
+                    if position > 0 {
+                        position - 1
+                    } else {
+                        len + position
+                    }
+                })
+                .filter(|index| (0..len).contains(index))
+                .map(|index| candidates[index as usize])
+                .collect();
+            selected.sort_unstable();
+            selected.dedup();
+            candidates = selected;
+        }
+        (candidates, resume)
+    }
+
+    fn matches_day(&self, day: i64) -> bool {
+        let rule = &self.rule;
+        let Ok(date) = Date::from_days_since_epoch(day) else {
+            return false;
+        };
+        if !rule.by_month.is_empty() && !rule.by_month.contains(&date.month) {
+            return false;
+        }
+        if !rule.by_week_no.is_empty() {
+            let (week, weeks) = week_number(date, rule.week_start);
+            if !matches_ordinal(&rule.by_week_no, week, weeks) {
+                return false;
+            }
+        }
+        let year_day = date.ordinal() as i64;
+        let year_days = if is_leap_year(date.year) { 366 } else { 365 };
+        if !rule.by_year_day.is_empty() && !matches_ordinal(&rule.by_year_day, year_day, year_days)
+        {
+            return false;
+        }
+        let month_day = date.day as i64;
+        let month_days = days_in_month(date.month, date.year) as i64;
+        if !rule.by_month_day.is_empty()
+            && !matches_ordinal(&rule.by_month_day, month_day, month_days)
+        {
+            return false;
+        }
+        if !rule.by_day.is_empty() {
+            let weekday = date.weekday();
+            // Numbered weekdays count within the month for monthly rules and for yearly rules
+            // limited by BYMONTH, and within the year otherwise.
+            let (index, count) =
+                if rule.frequency == Frequency::Monthly || !rule.by_month.is_empty() {
+                    (month_day, month_days)
+                } else {
+                    (year_day, year_days)
+                };
+            return rule.by_day.iter().any(|by_day| {
+                by_day.weekday == weekday
+                    && match by_day.n {
+                        None => true,
+                        Some(n) => n == (index - 1) / 7 + 1 || n == -((count - index) / 7 + 1),
+                    }
+            });
+        }
+        true
+    }
+
+    // Returns the times of day of the current period in seconds after midnight.
+    fn times_of_day(&self) -> (Vec<i64>, Option<i64>) {
+        let rule = &self.rule;
+        let frequency = rule.frequency;
+        let time = self.period.rem_euclid(SECONDS_IN_DAY);
+        let midnight = self.period - time;
+        let (hour, minute, second) = (
+            (time / SECONDS_IN_HOUR) as u64,
+            (time % SECONDS_IN_HOUR / SECONDS_IN_MINUTE) as u64,
+            (time % SECONDS_IN_MINUTE) as u64,
+        );
+        let allows = |list: &Vec<u64>, value: u64| list.is_empty() || list.contains(&value);
+
+        let hours = if frequency <= Frequency::Hourly {
+            if !allows(&rule.by_hour, hour) {
+                let next_hour = midnight + (hour as i64 + 1) * SECONDS_IN_HOUR;
+                return (Vec::new(), Some(next_hour));
+            }
+            vec![hour]
+        } else {
+            rule.by_hour.clone()
+        };
+        let minutes = if frequency <= Frequency::Minutely {
+            if !allows(&rule.by_minute, minute) {
+                let next_minute = self.period - second as i64 + SECONDS_IN_MINUTE;
+                return (Vec::new(), Some(next_minute));
+            }
+            vec![minute]
+        } else {
+            rule.by_minute.clone()
+        };
+        let seconds = if frequency == Frequency::Secondly {
+            if !allows(&rule.by_second, second) {
+                return (Vec::new(), None);
+            }
+            vec![second]
+        } else {
+            rule.by_second.clone()
+        };
+
+        let mut times = Vec::with_capacity(hours.len() * minutes.len() * seconds.len());
+        for &hour in &hours {
+            for &minute in &minutes {
+                for &second in &seconds {
+                    times.push(time_of_day(hour, minute, second));
+                }
+            }
+        }
+        (times, None)
+    }
+
+    // Moves to the next period, skipping sub-daily periods before `resume`.
+    fn advance(&mut self, resume: Option<i64>) {
+        let interval = self.rule.interval.min(i64::MAX as u64) as i64;
+        self.period = match self.rule.frequency {
+            Frequency::Yearly | Frequency::Monthly | Frequency::Daily => {
+                self.period.saturating_add(interval)
+            }
+            Frequency::Weekly => self.period.saturating_add(interval.saturating_mul(7)),
+            frequency => {
+                let step = interval.saturating_mul(unit_seconds(frequency));
+                let mut next = self.period.saturating_add(step);
+                if let Some(resume) = resume.filter(|&resume| resume > next) {
+                    let steps = (resume - next + step - 1) / step;
+                    next = next.saturating_add(steps.saturating_mul(step));
+                }
+                next
+            }
+        };
+    }
+}
+
+impl Iterator for Occurrences {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<DateTime> {
+        loop {
+            if self.finished {
+                return None;
+            }
+            if let Some(local) = self.pending.pop_front() {
+                if local < self.start {
+                    continue;
+                }
+                let past_until = self.until.is_some_and(|until| local > until);
+                let counted_out = self.rule.count.is_some_and(|count| self.emitted >= count);
+                if past_until || counted_out {
+                    self.finished = true;
+                    return None;
+                }
+                if let Some(datetime) = from_local_seconds(local, self.timezone) {
+                    self.emitted += 1;
+                    return Some(datetime);
+                }
+                continue;
+            }
+
+            let (first_day, _) = self.period_days();
+            let before_until = self
+                .until
+                .is_none_or(|until| first_day * SECONDS_IN_DAY <= until);
+            if self.period > self.last_period || !before_until {
+                self.finished = true;
+                return None;
+            }
+            let (candidates, resume) = self.expand();
+            self.pending = candidates.into();
+            self.advance(resume);
+        }
+    }
+}
+
+impl FusedIterator for Occurrences {}
+
+/// A complete RFC 5545 recurrence set: DTSTART, any number of RRULEs, and RDATE and EXDATE
+/// lists.
+///
+/// The set always contains DTSTART, which counts as the first occurrence toward the COUNT of
+/// every rule even when the rule does not match it, as RFC 5545 requires. Occurrences of all
+/// rules and the RDATEs are merged in chronological order, duplicates are returned once, and
+/// instants listed as EXDATEs are removed. Every occurrence is returned in the zone of
+/// DTSTART.
+///
+/// # Example:
+/// ```
+/// use timekit::RecurrenceSet;
+/// let set: RecurrenceSet = "DTSTART;TZID=Asia/Seoul:20240506T100000\n\
+///     RRULE:FREQ=WEEKLY;BYDAY=MO,WE;COUNT=4\n\
+///     EXDATE;TZID=Asia/Seoul:20240508T100000\n\
+///     RDATE;TZID=Asia/Seoul:20240510T150000"
+///     .parse()
+///     .unwrap();
+///
+/// let meetings: Vec<String> = set.occurrences().map(|datetime| datetime.to_string()).collect();
+/// assert_eq!(
+///     meetings,
+///     ["2024-05-06 10:00:00", "2024-05-10 15:00:00", "2024-05-13 10:00:00", "2024-05-15 10:00:00"]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurrenceSet {
+    dtstart: DateTime,
+    rules: Vec<RecurrenceRule>,
+    rdates: Vec<DateTime>,
+    exdates: Vec<DateTime>,
+}
+
+impl RecurrenceSet {
+    /// Creates a set that contains only `dtstart`.
+    pub fn new(dtstart: DateTime) -> Self {
+        Self {
+            dtstart,
+            rules: Vec::new(),
+            rdates: Vec::new(),
+            exdates: Vec::new(),
+        }
+    }
+
+    /// Adds a recurrence rule (RRULE).
+    pub fn add_rule(&mut self, rule: RecurrenceRule) -> Result<(), String> {
+        rule.validate()?;
+        self.rules.push(rule);
+        Ok(())
+    }
+
+    /// Adds a single extra occurrence (RDATE).
+    pub fn add_rdate(&mut self, datetime: DateTime) {
+        self.rdates.push(datetime);
+    }
+
+    /// Excludes an instant from the set (EXDATE).
+    pub fn add_exdate(&mut self, datetime: DateTime) {
+        self.exdates.push(datetime);
+    }
+
+    /// Returns the start of the set.
+    pub fn dtstart(&self) -> DateTime {
+        self.dtstart
+    }
+
+    /// Returns the recurrence rules of the set.
+    pub fn rules(&self) -> &[RecurrenceRule] {
+        &self.rules
+    }
+
+    /// Returns the occurrences of the set in chronological order.
+    pub fn occurrences(&self) -> SetOccurrences {
+        let dtstart = self.dtstart.to_unix_seconds();
+        let mut dates: Vec<i64> = std::iter::once(&self.dtstart)
+            .chain(&self.rdates)
+            .map(|datetime| datetime.to_unix_seconds())
+            .collect();
+        dates.sort_unstable();
+        SetOccurrences {
+            rules: self
+                .rules
+                .iter()
+                .map(|rule| {
+                    let mut occurrences = Occurrences::new(rule, self.dtstart);
+                    // DTSTART is always the first occurrence of the set, so a rule that does
+                    // not produce it has one less occurrence left under its COUNT.
+                    let first = occurrences.clone().next();
+                    if first.map(|datetime| datetime.to_unix_seconds()) != Some(dtstart) {
+                        occurrences.emitted = 1;
+                    }
+                    occurrences.peekable()
+                })
+                .collect(),
+            dates: dates.into(),
+            excluded: self
+                .exdates
+                .iter()
+                .map(|datetime| datetime.to_unix_seconds())
+                .collect(),
+            timezone: self.dtstart.timezone,
+            last: None,
+        }
+    }
+}
+
+impl FromStr for RecurrenceSet {
+    type Err = String;
+
+    /// Parses iCalendar content lines: one `DTSTART`, and any number of `RRULE`, `RDATE` and
+    /// `EXDATE` lines. Date-times may carry a `TZID` parameter or a trailing `Z`; floating
+    /// values are read in the zone of DTSTART, and a floating DTSTART is read as UTC.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Unfold continuation lines, which start with a space or a tab.
+        let mut lines: Vec<String> = Vec::new();
+        for line in s.lines() {
+            match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+                (Some(rest), Some(last)) => last.push_str(rest.trim_end()),
+                _ if line.trim().is_empty() => {}
+                _ => lines.push(line.trim().to_string()),
+            }
+        }
+        let properties = lines
+            .iter()
+            .map(|line| parse_property(line))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut dtstarts = properties
+            .iter()
+            .filter(|property| property.name == "DTSTART");
+        let dtstart = match (dtstarts.next(), dtstarts.next()) {
+            (Some(property), None) => property.values(TimeZone::UTC)?[0],
+            (None, _) => return Err("Recurrence set must contain DTSTART".to_string()),
+            (Some(_), Some(_)) => return Err("Recurrence set must contain one DTSTART".to_string()),
+        };
+
+        let mut set = RecurrenceSet::new(dtstart);
+        for property in &properties {
+            match property.name.as_str() {
+                "DTSTART" => {}
+                "RRULE" => set.add_rule(property.value.parse()?)?,
+                "RDATE" => set.rdates.extend(property.values(dtstart.timezone)?),
+                "EXDATE" => set.exdates.extend(property.values(dtstart.timezone)?),
+                name => return Err(format!("Unsupported recurrence property: {}", name)),
+            }
+        }
+        Ok(set)
+    }
+}
+
+/// The occurrences of a `RecurrenceSet`, in chronological order.
+#[derive(Debug, Clone)]
+pub struct SetOccurrences {
+    rules: Vec<Peekable<Occurrences>>,
+    // DTSTART and the RDATEs, as sorted Unix seconds.
+    dates: VecDeque<i64>,
+    excluded: HashSet<i64>,
+    timezone: TimeZone,
+    last: Option<i64>,
+}
+
+impl Iterator for SetOccurrences {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<DateTime> {
+        loop {
+            // Find the earliest pending occurrence among the rules and the listed dates.
+            let mut earliest = self.dates.front().copied().map(|seconds| (seconds, None));
+            for (index, rule) in self.rules.iter_mut().enumerate() {
+                if let Some(seconds) = rule.peek().map(|datetime| datetime.to_unix_seconds()) {
+                    if earliest.is_none_or(|(current, _)| seconds < current) {
+                        earliest = Some((seconds, Some(index)));
+                    }
+                }
+            }
+            let (seconds, source) = earliest?;
+            match source {
+                Some(index) => {
+                    self.rules[index].next();
+                }
+                None => {
+                    self.dates.pop_front();
+                }
+            }
+
+            if self.last == Some(seconds) || self.excluded.contains(&seconds) {
+                continue;
+            }
+            self.last = Some(seconds);
+            if let Ok(datetime) = DateTime::from_unix_seconds(seconds, self.timezone) {
+                return Some(datetime);
+            }
+        }
+    }
+}
+
+impl FusedIterator for SetOccurrences {}
+
+// A parsed iCalendar content line, such as `EXDATE;TZID=Asia/Seoul:20240508T100000`.
+struct Property {
+    name: String,
+    timezone: Option<TimeZone>,
+    value: String,
+}
+
+impl Property {
+    // Parses the comma-separated date-times of the property.
+    fn values(&self, floating: TimeZone) -> Result<Vec<DateTime>, String> {
+        self.value
+            .split(',')
+            .map(|value| {
+                let (date, time) = parse_basic_datetime(value)?;
+                match time {
+                    None => date.and_hms(0, 0, 0, self.timezone.unwrap_or(floating)),
+                    Some((hour, minute, second, true)) => {
+                        date.and_hms(hour, minute, second, TimeZone::UTC)
+                    }
+                    Some((hour, minute, second, false)) => {
+                        date.and_hms(hour, minute, second, self.timezone.unwrap_or(floating))
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+fn parse_property(line: &str) -> Result<Property, String> {
+    let (head, value) = line
+        .split_once(':')
+        .ok_or_else(|| format!("Invalid content line: {}", line))?;
+    let mut parts = head.split(';');
+    let name = parts.next().unwrap_or_default().to_ascii_uppercase();
+    let mut timezone = None;
+    for parameter in parts {
+        let (key, parameter_value) = parameter
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid parameter: {}", parameter))?;
+        match key.to_ascii_uppercase().as_str() {
+            "TZID" => timezone = Some(parameter_value.trim_matches('"').parse()?),
+            "VALUE" => match parameter_value.to_ascii_uppercase().as_str() {
+                "DATE" | "DATE-TIME" => {}
+                other => return Err(format!("Unsupported value type: {}", other)),
+            },
+            _ => {}
+        }
+    }
+    Ok(Property {
+        name,
+        timezone,
+        value: value.to_string(),
+    })
+}
+
+// An hour, minute and second, and whether they are in UTC.
+type BasicTime = (u64, u64, u64, bool);
+
+// Parses `YYYYMMDD`, `YYYYMMDDTHHMMSS` or `YYYYMMDDTHHMMSSZ`.
+fn parse_basic_datetime(s: &str) -> Result<(Date, Option<BasicTime>), String> {
+    let invalid = || format!("Invalid date-time: {}", s);
+    let number = |range: std::ops::Range<usize>| -> Result<u64, String> {
+        let digits = s.get(range).ok_or_else(invalid)?;
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        digits.parse().map_err(|_| invalid())
+    };
+    let date = Date::new(number(0..4)?, number(4..6)?, number(6..8)?).map_err(|_| invalid())?;
+    let bytes = s.as_bytes();
+    match bytes.len() {
+        8 => Ok((date, None)),
+        15 | 16 if bytes[8].eq_ignore_ascii_case(&b'T') => {
+            let utc = match bytes.get(15) {
+                None => false,
+                Some(b'Z' | b'z') => true,
+                Some(_) => return Err(invalid()),
+            };
+            let (hour, minute, second) = (number(9..11)?, number(11..13)?, number(13..15)?);
+            if hour > 23 || minute > 59 || second > 60 {
+                return Err(invalid());
+            }
+            Ok((date, Some((hour, minute, second, utc))))
+        }
+        _ => Err(invalid()),
+    }
+}
+
+fn parse_value<T: FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid {} value: {}", name, value))
+}
+
+fn parse_list<T: FromStr>(name: &str, value: &str) -> Result<Vec<T>, String> {
+    value
+        .split(',')
+        .map(|item| parse_value(name, item))
+        .collect()
+}
+
+fn write_list<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    values: &[T],
+) -> fmt::Result {
+    if values.is_empty() {
+        return Ok(());
+    }
+    let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+    write!(f, ";{}={}", name, values.join(","))
+}
+
+fn check_range(name: &str, values: &[u64], min: u64, max: u64) -> Result<(), String> {
+    if values.iter().any(|value| !(min..=max).contains(value)) {
+        return Err(format!(
+            "{} values must be between {} and {}",
+            name, min, max
+        ));
+    }
+    Ok(())
+}
+
+fn check_signed_range(name: &str, values: &[i64], max: i64) -> Result<(), String> {
+    if values
+        .iter()
+        .any(|value| *value == 0 || value.unsigned_abs() > max as u64)
+    {
+        return Err(format!(
+            "{} values must be between 1 and {} or -{} and -1",
+            name, max, max
+        ));
+    }
+    Ok(())
+}
+
+// Returns `true` if `index` (1-based, out of `count`) appears in `ordinals`, where negative
+// ordinals count from the end.
+fn matches_ordinal(ordinals: &[i64], index: i64, count: i64) -> bool {
+    ordinals
+        .iter()
+        .any(|&ordinal| ordinal == index || ordinal == index - count - 1)
+}
+
+// Returns the week number of `date` and the number of weeks in its week-numbering year. Weeks
+// start on `week_start`, and week 1 is the first week with at least four days in the year.
+fn week_number(date: Date, week_start: Weekday) -> (i64, i64) {
+    let day = date.days_since_epoch();
+    let mut year = date.year as i64;
+    if day < first_day_of_week_one(year, week_start) {
+        year -= 1;
+    } else if day >= first_day_of_week_one(year + 1, week_start) {
+        year += 1;
+    }
+    let first = first_day_of_week_one(year, week_start);
+    let weeks = (first_day_of_week_one(year + 1, week_start) - first) / 7;
+    ((day - first) / 7 + 1, weeks)
+}
+
+fn first_day_of_week_one(year: i64, week_start: Weekday) -> i64 {
+    let january_first = first_day_of_year(year);
+    let offset = Weekday::from_days_since_epoch(january_first).days_since(week_start) as i64;
+    if offset <= 3 {
+        january_first - offset
+    } else {
+        january_first + 7 - offset
+    }
+}
+
+fn first_day_of_year(year: i64) -> i64 {
+    Date {
+        year: year as u64,
+        month: 1,
+        day: 1,
+    }
+    .days_since_epoch()
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Monday => "MO",
+        Weekday::Tuesday => "TU",
+        Weekday::Wednesday => "WE",
+        Weekday::Thursday => "TH",
+        Weekday::Friday => "FR",
+        Weekday::Saturday => "SA",
+        Weekday::Sunday => "SU",
+    }
+}
+
+fn parse_weekday_code(code: &str) -> Result<Weekday, String> {
+    Weekday::all()
+        .find(|weekday| weekday_code(*weekday).eq_ignore_ascii_case(code))
+        .ok_or_else(|| format!("Invalid weekday: {}", code))
+}
+
+fn unit_seconds(frequency: Frequency) -> i64 {
+    match frequency {
+        Frequency::Hourly => SECONDS_IN_HOUR,
+        Frequency::Minutely => SECONDS_IN_MINUTE,
+        _ => 1,
+    }
+}
+
+fn time_of_day(hour: u64, minute: u64, second: u64) -> i64 {
+    hour as i64 * SECONDS_IN_HOUR + minute as i64 * SECONDS_IN_MINUTE + second as i64
+}
+
+fn local_seconds(datetime: &DateTime) -> i64 {
+    datetime.date().days_since_epoch() * SECONDS_IN_DAY
+        + time_of_day(datetime.hour, datetime.minute, datetime.second)
+}
+
+fn from_local_seconds(local: i64, timezone: TimeZone) -> Option<DateTime> {
+    let date = Date::from_days_since_epoch(local.div_euclid(SECONDS_IN_DAY)).ok()?;
+    let time = local.rem_euclid(SECONDS_IN_DAY);
+    date.and_hms(
+        (time / SECONDS_IN_HOUR) as u64,
+        (time % SECONDS_IN_HOUR / SECONDS_IN_MINUTE) as u64,
+        (time % SECONDS_IN_MINUTE) as u64,
+        timezone,
+    )
+    .ok()
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
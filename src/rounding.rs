@@ -0,0 +1,110 @@
+use crate::constants::*;
+use crate::{DateTime, TimeDelta, Weekday};
+
+/// Calendar and clock units that a `DateTime` can be truncated or rounded to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    /// ISO weeks, starting on Monday.
+    Week,
+    Month,
+    Year,
+}
+
+impl DateTime {
+    /// Truncates the `DateTime` to the start of the given unit on the local wall clock.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{DateTime, TimeUnit, TimeZone};
+    /// let datetime = DateTime::new(2024, 5, 17, 7, 45, 12, TimeZone::KST).unwrap();
+    /// let day = datetime.trunc(TimeUnit::Day).unwrap();
+    /// assert_eq!(day.to_string(), "2024-05-17 00:00:00"); // midnight in KST, not UTC
+    /// ```
+    pub fn trunc(&self, unit: TimeUnit) -> Result<Self, String> {
+        let (year, month, day, hour, minute, second) = match unit {
+            TimeUnit::Second => return Ok(*self),
+            TimeUnit::Minute => (self.year, self.month, self.day, self.hour, self.minute, 0),
+            TimeUnit::Hour => (self.year, self.month, self.day, self.hour, 0, 0),
+            TimeUnit::Day => (self.year, self.month, self.day, 0, 0, 0),
+            TimeUnit::Week => return self.start_of_week(Weekday::Monday),
+            TimeUnit::Month => (self.year, self.month, 1, 0, 0, 0),
+            TimeUnit::Year => (self.year, 1, 1, 0, 0, 0),
+        };
+        DateTime::new(year, month, day, hour, minute, second, self.timezone)
+    }
+
+    /// Rounds the `DateTime` to the nearest start of the given unit on the local wall clock.
+    /// Values exactly halfway between two boundaries round up.
+    pub fn round(&self, unit: TimeUnit) -> Result<Self, String> {
+        let lower = self.trunc(unit)?;
+        let upper = lower.next_unit_start(unit)?;
+        let unix_seconds = self.to_unix_seconds();
+        if unix_seconds - lower.to_unix_seconds() >= upper.to_unix_seconds() - unix_seconds {
+            Ok(upper)
+        } else {
+            Ok(lower)
+        }
+    }
+
+    /// Rounds the `DateTime` down to a multiple of `delta`.
+    ///
+    /// Multiples are counted from local midnight of 1970-01-01 in the `DateTime`'s zone, so any
+    /// delta that divides a day evenly (such as 15 minutes or 6 hours) lines up with local
+    /// midnight.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{DateTime, TimeDelta, TimeZone};
+    /// let datetime = DateTime::new(2024, 5, 17, 7, 44, 59, TimeZone::IST).unwrap();
+    /// let quarter = TimeDelta { minutes: 15, ..Default::default() };
+    /// assert_eq!(datetime.floor_to(quarter).unwrap().to_string(), "2024-05-17 07:30:00");
+    /// assert_eq!(datetime.ceil_to(quarter).unwrap().to_string(), "2024-05-17 07:45:00");
+    /// ```
+    pub fn floor_to(&self, delta: TimeDelta) -> Result<Self, String> {
+        let step = positive_seconds(delta)?;
+        let local_seconds = self.to_unix_seconds() + self.timezone.offset_in_seconds();
+        let floored = local_seconds - local_seconds.rem_euclid(step);
+        DateTime::from_unix_seconds(floored - self.timezone.offset_in_seconds(), self.timezone)
+    }
+
+    /// Rounds the `DateTime` up to a multiple of `delta`, aligned as in `floor_to`.
+    pub fn ceil_to(&self, delta: TimeDelta) -> Result<Self, String> {
+        let step = positive_seconds(delta)?;
+        let floored = self.floor_to(delta)?;
+        if floored.to_unix_seconds() == self.to_unix_seconds() {
+            return Ok(floored);
+        }
+        DateTime::from_unix_seconds(floored.to_unix_seconds() + step, self.timezone)
+    }
+
+    // Returns the start of the unit following the one that starts at `self`.
+    fn next_unit_start(&self, unit: TimeUnit) -> Result<Self, String> {
+        let seconds = match unit {
+            TimeUnit::Second => 1,
+            TimeUnit::Minute => SECONDS_IN_MINUTE,
+            TimeUnit::Hour => SECONDS_IN_HOUR,
+            TimeUnit::Day => SECONDS_IN_DAY,
+            TimeUnit::Week => SECONDS_IN_WEEK,
+            TimeUnit::Month if self.month == 12 => {
+                return DateTime::new(self.year + 1, 1, 1, 0, 0, 0, self.timezone)
+            }
+            TimeUnit::Month => {
+                return DateTime::new(self.year, self.month + 1, 1, 0, 0, 0, self.timezone)
+            }
+            TimeUnit::Year => return DateTime::new(self.year + 1, 1, 1, 0, 0, 0, self.timezone),
+        };
+        DateTime::from_unix_seconds(self.to_unix_seconds() + seconds, self.timezone)
+    }
+}
+
+fn positive_seconds(delta: TimeDelta) -> Result<i64, String> {
+    let seconds = delta.total_seconds();
+    if seconds <= 0 {
+        return Err("TimeDelta must be positive".to_string());
+    }
+    Ok(seconds)
+}
@@ -0,0 +1,526 @@
+use crate::holiday::{Holiday, HolidayProvider};
+use crate::{Date, Weekday};
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const ORDINALS: [(&str, &str); 5] = [
+    ("first", "1st"),
+    ("second", "2nd"),
+    ("third", "3rd"),
+    ("fourth", "4th"),
+    ("fifth", "5th"),
+];
+
+/// How a holiday rule finds its date in a given year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HolidayDate {
+    /// The same month and day every year.
+    Fixed { month: u64, day: u64 },
+    /// The `n`-th weekday of a month, counting from the end when `n` is negative.
+    NthWeekday {
+        month: u64,
+        weekday: Weekday,
+        n: i64,
+    },
+    /// A number of days before or after Western Easter Sunday.
+    Easter { offset: i64 },
+    /// A number of days before or after Orthodox Easter Sunday.
+    OrthodoxEaster { offset: i64 },
+}
+
+/// Moves a holiday that falls on a weekend to a weekday.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Observance {
+    /// The holiday is observed on its date.
+    #[default]
+    None,
+    /// Saturday moves to Friday and Sunday to Monday, as for US federal holidays.
+    NearestWeekday,
+    /// Saturday and Sunday both move to the following Monday, as for UK bank holidays.
+    /// In `HolidayRules`, a holiday that lands on another holiday moves on to the next free
+    /// weekday, so Christmas and Boxing Day on a weekend fall on Monday and Tuesday.
+    NextMonday,
+    /// Only Sunday moves, to the following Monday.
+    SundayToMonday,
+}
+
+impl Observance {
+    /// Returns the day on which a holiday falling on `date` is observed.
+    pub fn apply(&self, date: Date) -> Result<Date, String> {
+        let days = match (self, date.weekday()) {
+            (Observance::NearestWeekday, Weekday::Saturday) => -1,
+            (Observance::NearestWeekday, Weekday::Sunday) => 1,
+            (Observance::NextMonday, Weekday::Saturday) => 2,
+            (Observance::NextMonday, Weekday::Sunday) => 1,
+            (Observance::SundayToMonday, Weekday::Sunday) => 1,
+            _ => 0,
+        };
+        date.add_days(days)
+    }
+
+    fn keyword(&self) -> &'static str {
+        match self {
+            Observance::None => "none",
+            Observance::NearestWeekday => "nearest-weekday",
+            Observance::NextMonday => "next-monday",
+            Observance::SundayToMonday => "sunday-to-monday",
+        }
+    }
+}
+
+/// A rule that yields one named holiday per year.
+///
+/// Rules are built with the constructors and the `observe`, `from_year` and `until_year`
+/// builder methods, or parsed from one line of the text format described on
+/// `HolidayRules`.
+///
+/// # Example:
+/// ```
+/// use timekit::{Date, HolidayRule, Observance, Weekday};
+/// let rule = HolidayRule::fixed("Juneteenth", 6, 19)
+///     .unwrap()
+///     .observe(Observance::NearestWeekday)
+///     .from_year(2021);
+/// assert_eq!(rule.to_string(), "Juneteenth = 06-19 observe nearest-weekday from 2021");
+/// // June 19th 2027 is a Saturday
+/// let holiday = rule.holiday(2027).unwrap().unwrap();
+/// assert_eq!(holiday.date, Date::new(2027, 6, 18).unwrap());
+/// assert_eq!(rule.holiday(2020).unwrap(), None);
+///
+/// let memorial = HolidayRule::nth_weekday("Memorial Day", 5, Weekday::Monday, -1).unwrap();
+/// assert_eq!(memorial.to_string(), "Memorial Day = last Monday of May");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HolidayRule {
+    pub name: String,
+    pub date: HolidayDate,
+    pub observance: Observance,
+    /// The first year the rule applies, if it has one.
+    pub first_year: Option<u64>,
+    /// The last year the rule applies, if it has one.
+    pub last_year: Option<u64>,
+}
+
+impl HolidayRule {
+    /// Creates a rule for a fixed month and day. February 29th only occurs in leap years.
+    pub fn fixed(name: &str, month: u64, day: u64) -> Result<Self, String> {
+        // Check against a leap year, so that February 29th is accepted.
+        Date::new(2000, month, day)?;
+        Ok(Self::new(name, HolidayDate::Fixed { month, day }))
+    }
+
+    /// Creates a rule for the `n`-th `weekday` of a month; a negative `n` counts from the end.
+    pub fn nth_weekday(name: &str, month: u64, weekday: Weekday, n: i64) -> Result<Self, String> {
+        if !(1..=12).contains(&month) {
+            return Err("Invalid month".to_string());
+        }
+        if n == 0 || n.unsigned_abs() > 5 {
+            return Err("n must be between 1 and 5, or -5 and -1".to_string());
+        }
+        Ok(Self::new(
+            name,
+            HolidayDate::NthWeekday { month, weekday, n },
+        ))
+    }
+
+    /// Creates a rule for a day relative to Western Easter Sunday, e.g. -2 for Good Friday.
+    pub fn easter(name: &str, offset: i64) -> Self {
+        Self::new(name, HolidayDate::Easter { offset })
+    }
+
+    /// Creates a rule for a day relative to Orthodox Easter Sunday.
+    pub fn orthodox_easter(name: &str, offset: i64) -> Self {
+        Self::new(name, HolidayDate::OrthodoxEaster { offset })
+    }
+
+    fn new(name: &str, date: HolidayDate) -> Self {
+        Self {
+            name: name.to_string(),
+            date,
+            observance: Observance::None,
+            first_year: None,
+            last_year: None,
+        }
+    }
+
+    /// Returns the rule with a weekend observance shift.
+    pub fn observe(mut self, observance: Observance) -> Self {
+        self.observance = observance;
+        self
+    }
+
+    /// Returns the rule applying from `year` onwards.
+    pub fn from_year(mut self, year: u64) -> Self {
+        self.first_year = Some(year);
+        self
+    }
+
+    /// Returns the rule applying up to and including `year`.
+    pub fn until_year(mut self, year: u64) -> Self {
+        self.last_year = Some(year);
+        self
+    }
+
+    /// Returns `true` if the rule applies in `year`.
+    pub fn applies_in(&self, year: u64) -> bool {
+        self.first_year.is_none_or(|first| year >= first)
+            && self.last_year.is_none_or(|last| year <= last)
+    }
+
+    /// Returns the holiday the rule produces for `year`, on its observed date, or `None` when
+    /// the rule does not apply that year (or the date is February 29th in a common year).
+    ///
+    /// The observed date may fall in a neighbouring year, e.g. a Saturday January 1st
+    /// observed on December 31st. The rule is resolved on its own; `HolidayRules` also moves
+    /// a shifted holiday off days taken by its other rules.
+    pub fn holiday(&self, year: u64) -> Result<Option<Holiday>, String> {
+        match self.actual_date(year)? {
+            Some(date) => Ok(Some(Holiday::new(self.observance.apply(date)?, &self.name))),
+            None => Ok(None),
+        }
+    }
+
+    // The date of the holiday in `year` before any observance shift.
+    fn actual_date(&self, year: u64) -> Result<Option<Date>, String> {
+        if !self.applies_in(year) {
+            return Ok(None);
+        }
+        let date = match self.date {
+            HolidayDate::Fixed { month, day } => match Date::new(year, month, day) {
+                Ok(date) => date,
+                Err(_) => return Ok(None),
+            },
+            HolidayDate::NthWeekday { month, weekday, n } => {
+                match Date::nth_weekday_of_month(year, month, weekday, n) {
+                    Ok(date) => date,
+                    // Not every month has a fifth weekday.
+                    Err(_) => return Ok(None),
+                }
+            }
+            HolidayDate::Easter { offset } => Date::easter(year)?.add_days(offset)?,
+            HolidayDate::OrthodoxEaster { offset } => {
+                Date::orthodox_easter(year)?.add_days(offset)?
+            }
+        };
+        Ok(Some(date))
+    }
+}
+
+impl fmt::Display for HolidayRule {
+    /// Formats the rule as a line of the `HolidayRules` text format.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} = ", self.name)?;
+        match self.date {
+            HolidayDate::Fixed { month, day } => write!(f, "{:02}-{:02}", month, day)?,
+            HolidayDate::NthWeekday { month, weekday, n } => {
+                let month = MONTH_NAMES[month as usize - 1];
+                match n {
+                    -1 => write!(f, "last {} of {}", weekday, month)?,
+                    n if n < 0 => write!(
+                        f,
+                        "{} last {} of {}",
+                        ORDINALS[(-n) as usize - 1].1,
+                        weekday,
+                        month
+                    )?,
+                    n => write!(f, "{} {} of {}", ORDINALS[n as usize - 1].1, weekday, month)?,
+                }
+            }
+            HolidayDate::Easter { offset: 0 } => write!(f, "easter")?,
+            HolidayDate::Easter { offset } => write!(f, "easter {:+}", offset)?,
+            HolidayDate::OrthodoxEaster { offset: 0 } => write!(f, "orthodox-easter")?,
+            HolidayDate::OrthodoxEaster { offset } => write!(f, "orthodox-easter {:+}", offset)?,
+        }
+        if self.observance != Observance::None {
+            write!(f, " observe {}", self.observance.keyword())?;
+        }
+        if let Some(first) = self.first_year {
+            write!(f, " from {}", first)?;
+        }
+        if let Some(last) = self.last_year {
+            write!(f, " until {}", last)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for HolidayRule {
+    type Err = String;
+
+    /// Parses one rule line, e.g. `Memorial Day = last Monday of May`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, spec) = s
+            .split_once('=')
+            .ok_or_else(|| format!("Missing '=' in holiday rule: {}", s.trim()))?;
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Holiday rule has no name".to_string());
+        }
+        let words: Vec<String> = spec.split_whitespace().map(str::to_lowercase).collect();
+        // The date runs up to the first option keyword.
+        let end = words
+            .iter()
+            .position(|word| matches!(word.as_str(), "observe" | "from" | "until"))
+            .unwrap_or(words.len());
+        let mut rule = Self::new(name, parse_date(&words[..end])?);
+
+        let mut options = words[end..].iter();
+        while let Some(keyword) = options.next() {
+            let value = options
+                .next()
+                .ok_or_else(|| format!("Missing value after '{}'", keyword))?;
+            match keyword.as_str() {
+                "observe" => {
+                    rule.observance = [
+                        Observance::None,
+                        Observance::NearestWeekday,
+                        Observance::NextMonday,
+                        Observance::SundayToMonday,
+                    ]
+                    .into_iter()
+                    .find(|observance| observance.keyword() == value)
+                    .ok_or_else(|| format!("Unknown observance: {}", value))?;
+                }
+                "from" | "until" => {
+                    let year: u64 = value
+                        .parse()
+                        .map_err(|_| format!("Invalid year: {}", value))?;
+                    if keyword == "from" {
+                        rule.first_year = Some(year);
+                    } else {
+                        rule.last_year = Some(year);
+                    }
+                }
+                _ => return Err(format!("Unexpected '{}' in holiday rule", keyword)),
+            }
+        }
+        Ok(rule)
+    }
+}
+
+// Parses the date part of a rule: `MM-DD`, `<month> <day>`, `[<ordinal>] [last] <weekday>
+// of <month>`, `easter [+/-days]` or `orthodox-easter [+/-days]`.
+fn parse_date(words: &[String]) -> Result<HolidayDate, String> {
+    let invalid = || format!("Invalid holiday date: {}", words.join(" "));
+    let month = |word: &str| {
+        MONTH_NAMES
+            .iter()
+            .position(|name| {
+                name.eq_ignore_ascii_case(word) || name[..3].eq_ignore_ascii_case(word)
+            })
+            .map(|index| index as u64 + 1)
+    };
+    let fixed = |month: u64, day: &str| -> Result<HolidayDate, String> {
+        let day: u64 = day.parse().map_err(|_| invalid())?;
+        HolidayRule::fixed("", month, day).map(|rule| rule.date)
+    };
+
+    match words {
+        [easter] if easter == "easter" => Ok(HolidayDate::Easter { offset: 0 }),
+        [easter, offset] if easter == "easter" => {
+            let offset: i64 = offset.parse().map_err(|_| invalid())?;
+            Ok(HolidayDate::Easter { offset })
+        }
+        [easter] if easter == "orthodox-easter" => Ok(HolidayDate::OrthodoxEaster { offset: 0 }),
+        [easter, offset] if easter == "orthodox-easter" => {
+            let offset: i64 = offset.parse().map_err(|_| invalid())?;
+            Ok(HolidayDate::OrthodoxEaster { offset })
+        }
+        [date] => {
+            let (month, day) = date.split_once('-').ok_or_else(invalid)?;
+            fixed(month.parse().map_err(|_| invalid())?, day)
+        }
+        [name, day] if month(name).is_some() => fixed(month(name).unwrap_or(0), day),
+        [position @ .., weekday, of, name] if of == "of" => {
+            let month = month(name).ok_or_else(invalid)?;
+            let weekday: Weekday = weekday.parse().map_err(|_| invalid())?;
+            let ordinal = |word: &str| {
+                ORDINALS
+                    .iter()
+                    .position(|(long, short)| *long == word || *short == word)
+                    .map(|index| index as i64 + 1)
+            };
+            let n = match position {
+                [last] if last == "last" => -1,
+                [nth, last] if last == "last" => -ordinal(nth).ok_or_else(invalid)?,
+                [nth] => ordinal(nth).ok_or_else(invalid)?,
+                _ => return Err(invalid()),
+            };
+            HolidayRule::nth_weekday("", month, weekday, n).map(|rule| rule.date)
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// A user-defined holiday calendar made of `HolidayRule`s.
+///
+/// Calendars can be built in code or loaded from a text file with one rule per line:
+///
+/// ```text
+/// # name = date [observe <shift>] [from <year>] [until <year>]
+/// New Year's Day = 01-01 observe nearest-weekday
+/// Labour Day = May 1
+/// Memorial Day = last Monday of May
+/// Thanksgiving = 4th Thursday of November
+/// Good Friday = easter -2
+/// Veterans Day = 4th Monday of October from 1971 until 1977
+/// ```
+///
+/// Dates are `MM-DD`, a month name and day, `<ordinal> <weekday> of <month>` (with
+/// ordinals `1st` to `5th` or `first` to `fifth`, `last` and e.g. `2nd last`), or `easter`
+/// or `orthodox-easter` with an optional day offset. The shifts are `none`,
+/// `nearest-weekday`, `next-monday` and `sunday-to-monday`. Keywords and names of months and
+/// weekdays ignore case, and blank lines and lines starting with `#` are skipped.
+///
+/// # Example:
+/// ```
+/// use timekit::{BusinessCalendar, Date, HolidayProvider, HolidayRules};
+/// let rules: HolidayRules = "
+///     ## United Kingdom (England and Wales)
+///     New Year's Day = 01-01 observe next-monday
+///     Good Friday = easter -2
+///     Easter Monday = easter +1
+///     Early May Bank Holiday = first Monday of May
+/// "
+/// .parse()
+/// .unwrap();
+/// let holidays = rules.holidays(2022).unwrap();
+/// assert_eq!(holidays[0].date, Date::new(2022, 1, 3).unwrap());
+/// assert_eq!(holidays[1].name, "Good Friday");
+///
+/// let calendar = BusinessCalendar::default()
+///     .with_holidays_from(&rules, 2022..=2022)
+///     .unwrap();
+/// assert!(!calendar.is_business_day(Date::new(2022, 4, 18).unwrap()));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HolidayRules {
+    rules: Vec<HolidayRule>,
+}
+
+impl HolidayRules {
+    /// Creates an empty set of rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the set with a rule added.
+    pub fn with_rule(mut self, rule: HolidayRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Adds a rule.
+    pub fn add_rule(&mut self, rule: HolidayRule) {
+        self.rules.push(rule);
+    }
+
+    /// Returns the rules in the order they were added.
+    pub fn rules(&self) -> &[HolidayRule] {
+        &self.rules
+    }
+
+    /// Loads rules from a text file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let contents = fs::read_to_string(path.as_ref())
+            .map_err(|e| format!("Failed to read {}: {}", path.as_ref().display(), e))?;
+        contents.parse()
+    }
+}
+
+impl HolidayProvider for HolidayRules {
+    /// Returns every holiday observed in `year`, including those whose shifted date moved
+    /// them in from a neighbouring year.
+    ///
+    /// Holidays observed on their own date keep it. A holiday shifted off a weekend that
+    /// lands on a day already taken moves on, in the direction of its shift, to the next
+    /// weekday that is free; clashing shifted holidays are placed in order of their dates.
+    fn holidays(&self, year: u64) -> Result<Vec<Holiday>, String> {
+        // (date before the shift, observed date, name)
+        let mut candidates = Vec::new();
+        for rule in &self.rules {
+            for rule_year in year.saturating_sub(1)..=year + 1 {
+                // A neighbouring year outside a rule's range, such as Easter before 1583,
+                // just contributes nothing.
+                let date = match rule.actual_date(rule_year) {
+                    Err(_) if rule_year != year => continue,
+                    result => result?,
+                };
+                if let Some(date) = date {
+                    candidates.push((date, rule.observance.apply(date)?, &rule.name));
+                }
+            }
+        }
+        candidates.sort_by_key(|&(date, _, _)| date);
+
+        let mut taken: HashSet<Date> = candidates
+            .iter()
+            .filter(|(date, observed, _)| date == observed)
+            .map(|&(date, _, _)| date)
+            .collect();
+        let mut holidays = Vec::new();
+        for (date, mut observed, name) in candidates {
+            if observed != date {
+                let step = if observed > date { 1 } else { -1 };
+                while taken.contains(&observed)
+                    || matches!(observed.weekday(), Weekday::Saturday | Weekday::Sunday)
+                {
+                    observed = observed.add_days(step)?;
+                }
+                taken.insert(observed);
+            }
+            if observed.year == year {
+                holidays.push(Holiday::new(observed, name));
+            }
+        }
+        holidays.sort_by_key(|holiday| holiday.date);
+        Ok(holidays)
+    }
+}
+
+impl fmt::Display for HolidayRules {
+    /// Formats the rules in the text format, one per line.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for rule in &self.rules {
+            writeln!(f, "{}", rule)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for HolidayRules {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rules = Self::new();
+        for (number, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let rule = line
+                .parse()
+                .map_err(|e| format!("Line {}: {}", number + 1, e))?;
+            rules.add_rule(rule);
+        }
+        Ok(rules)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::constants::*;
+use crate::leap::builtin_table;
+use crate::{DateTime, TimeZone};
+
+/// A GPS timestamp expressed as a week number and seconds into that week.
+///
+/// Weeks are counted from the GPS epoch (1980-01-06 00:00:00 UTC) without the 1024-week
+/// rollover applied by the broadcast navigation message.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GpsTime {
+    pub week: u64,
+    pub time_of_week: f64,
+}
+
+impl GpsTime {
+    /// Returns the number of seconds since the GPS epoch.
+    pub fn total_seconds(&self) -> f64 {
+        self.week as f64 * SECONDS_IN_WEEK as f64 + self.time_of_week
+    }
+}
+
+impl DateTime {
+    /// Returns the instant as TAI seconds (POSIX seconds plus TAI − UTC), using the leap second
+    /// table compiled into timekit.
+    ///
+    /// See `LeapSecondTable` for converting with a table loaded at runtime.
+    pub fn to_tai_seconds(&self) -> i64 {
+        builtin_table().utc_to_tai(self)
+    }
+
+    /// Converts TAI seconds back to a `DateTime`; the inverse of `to_tai_seconds`.
+    pub fn from_tai_seconds(tai_seconds: i64, timezone: TimeZone) -> Result<Self, String> {
+        builtin_table().tai_to_utc(tai_seconds, timezone)
+    }
+
+    /// Returns the instant as Terrestrial Time seconds (TAI + 32.184 s) on the same scale
+    /// as `to_tai_seconds`.
+    pub fn to_tt_seconds(&self) -> f64 {
+        self.to_tai_seconds() as f64 + TT_MINUS_TAI
+    }
+
+    /// Converts Terrestrial Time seconds back to a `DateTime`, rounding to the nearest second.
+    pub fn from_tt_seconds(tt_seconds: f64, timezone: TimeZone) -> Result<Self, String> {
+        let tai_seconds = round_to_seconds(tt_seconds - TT_MINUS_TAI)?;
+        Self::from_tai_seconds(tai_seconds, timezone)
+    }
+
+    /// Returns the instant as GPS time. GPS time does not observe leap seconds, so it runs
+    /// a fixed 19 s behind TAI.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{DateTime, TimeZone};
+    /// let datetime = DateTime::new(2024, 1, 7, 0, 0, 0, TimeZone::UTC).unwrap();
+    /// let gps = datetime.to_gps_time().unwrap();
+    /// assert_eq!(gps.week, 2296);
+    /// assert_eq!(gps.time_of_week, 18.0); // GPS was 18 s ahead of UTC in 2024
+    /// ```
+    pub fn to_gps_time(&self) -> Result<GpsTime, String> {
+        let gps_seconds = self.to_tai_seconds() - TAI_MINUS_GPS - GPS_EPOCH_UNIX;
+        if gps_seconds < 0 {
+            return Err("DateTime is before the GPS epoch (1980-01-06 00:00:00 UTC)".to_string());
+        }
+        Ok(GpsTime {
+            week: (gps_seconds / SECONDS_IN_WEEK) as u64,
+            time_of_week: (gps_seconds % SECONDS_IN_WEEK) as f64,
+        })
+    }
+
+    /// Converts GPS time back to a `DateTime`, rounding to the nearest second.
+    pub fn from_gps_time(gps: GpsTime, timezone: TimeZone) -> Result<Self, String> {
+        let gps_seconds = round_to_seconds(gps.total_seconds())?;
+        Self::from_tai_seconds(gps_seconds + GPS_EPOCH_UNIX + TAI_MINUS_GPS, timezone)
+    }
+
+    /// Returns the Julian Date of the instant on the UTC time scale.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{DateTime, TimeZone};
+    /// let j2000 = DateTime::new(2000, 1, 1, 12, 0, 0, TimeZone::UTC).unwrap();
+    /// assert_eq!(j2000.to_julian_date(), 2_451_545.0);
+    /// ```
+    pub fn to_julian_date(&self) -> f64 {
+        self.to_unix_seconds() as f64 / SECONDS_IN_DAY as f64 + UNIX_EPOCH_JULIAN_DATE
+    }
+
+    /// Converts a Julian Date on the UTC time scale back to a `DateTime`, rounding to the
+    /// nearest second.
+    pub fn from_julian_date(julian_date: f64, timezone: TimeZone) -> Result<Self, String> {
+        let days = julian_date - UNIX_EPOCH_JULIAN_DATE;
+        let unix_seconds = round_to_seconds(days * SECONDS_IN_DAY as f64)?;
+        Self::from_unix_seconds(unix_seconds, timezone)
+    }
+
+    /// Returns the Modified Julian Date (JD − 2400000.5) of the instant on the UTC time scale.
+    pub fn to_modified_julian_date(&self) -> f64 {
+        self.to_julian_date() - MJD_OFFSET
+    }
+
+    /// Converts a Modified Julian Date back to a `DateTime`, rounding to the nearest second.
+    pub fn from_modified_julian_date(mjd: f64, timezone: TimeZone) -> Result<Self, String> {
+        Self::from_julian_date(mjd + MJD_OFFSET, timezone)
+    }
+}
+
+fn round_to_seconds(seconds: f64) -> Result<i64, String> {
+    if !seconds.is_finite() || seconds.abs() > i64::MAX as f64 {
+        return Err("Time value is out of range".to_string());
+    }
+    Ok(seconds.round() as i64)
+}
@@ -0,0 +1,413 @@
+use crate::constants::*;
+use crate::duration::parse_units;
+use crate::{days_in_month, Date, DateTime, TimeDelta, TimeZone, Weekday};
+use std::fmt;
+use std::str::FromStr;
+
+// Searches give up after this year.
+const LAST_YEAR: u64 = 9999;
+
+// One comma-separated entry of a calendar component: `5`, `1..5`, `0/15` or `1..20/5`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Entry {
+    first: u64,
+    last: u64,
+    step: u64,
+}
+
+// A year, month, day, hour, minute or second component. No entries means `*`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Component {
+    entries: Vec<Entry>,
+    max: u64,
+}
+
+impl Component {
+    // With `from_end`, a repetition such as `7/2` walks towards the end of the month, so the
+    // day offsets are 7, 5, 3 and 1.
+    fn parse(text: &str, min: u64, max: u64, from_end: bool) -> Result<Self, String> {
+        let invalid = || format!("Invalid calendar component: {}", text);
+        let mut entries = Vec::new();
+        for item in text.split(',') {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => (range, Some(step.parse::<u64>().map_err(|_| invalid())?)),
+                None => (item, None),
+            };
+            if range == "*" && step.is_none() && text == "*" {
+                return Ok(Self {
+                    entries: Vec::new(),
+                    max,
+                });
+            }
+            let number = |value: &str| value.parse::<u64>().map_err(|_| invalid());
+            let (first, last) = match range.split_once("..") {
+                _ if range == "*" => (min, max),
+                Some((first, last)) => (number(first)?, number(last)?),
+                None => {
+                    let value = number(range)?;
+                    match step {
+                        Some(step) if from_end && step > 0 && value > 0 => {
+                            ((value - 1) % step + 1, value)
+                        }
+                        Some(_) => (value, max),
+                        None => (value, value),
+                    }
+                }
+            };
+            let step = step.unwrap_or(1);
+            if step == 0 || first < min || last > max || first > last {
+                return Err(invalid());
+            }
+            entries.push(Entry { first, last, step });
+        }
+        Ok(Self { entries, max })
+    }
+
+    fn any() -> Self {
+        Self {
+            entries: Vec::new(),
+            max: 0,
+        }
+    }
+
+    fn value(value: u64) -> Self {
+        Self {
+            entries: vec![Entry {
+                first: value,
+                last: value,
+                step: 1,
+            }],
+            max: value,
+        }
+    }
+
+    fn matches(&self, value: u64) -> bool {
+        self.entries.is_empty()
+            || self.entries.iter().any(|entry| {
+                (entry.first..=entry.last).contains(&value)
+                    && (value - entry.first) % entry.step == 0
+            })
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, width: usize) -> fmt::Result {
+        if self.entries.is_empty() {
+            return write!(f, "*");
+        }
+        for (index, entry) in self.entries.iter().enumerate() {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{:0width$}", entry.first, width = width)?;
+            if entry.step > 1 && entry.last == self.max {
+                write!(f, "/{}", entry.step)?;
+                continue;
+            }
+            if entry.last != entry.first {
+                write!(f, "..{:0width$}", entry.last, width = width)?;
+            }
+            if entry.step > 1 {
+                write!(f, "/{}", entry.step)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A systemd calendar event, the syntax of `OnCalendar=` in timer units, such as
+/// `Mon..Fri *-*-* 09:00:00 Asia/Seoul`.
+///
+/// An event has the form `[weekdays] [year-month-day] [hour:minute[:second]] [zone]`.
+/// Weekdays are names (`Mon`, `Monday`) in lists and `..` ranges. Every other component is
+/// `*`, a number, a list (`1,15`), a range (`9..17`) or a repetition (`0/15` is every 15th
+/// value starting at 0). `*-02~01` is the last day of February: after `~`, days count from
+/// the end of the month. The date defaults to `*-*-*`, the time to `00:00:00` and the
+/// seconds to `00`. The shorthands `minutely`, `hourly`, `daily`, `weekly`, `monthly`,
+/// `yearly`, `annually`, `quarterly` and `semiannually` are also accepted.
+///
+/// The zone is any name accepted by `TimeZone::from_str`, including IANA names; without
+/// one, the event is evaluated in the zone of the `DateTime` passed to `next_elapse`.
+///
+/// # Example:
+/// ```
+/// use timekit::{CalendarEvent, DateTime, TimeZone};
+/// let event: CalendarEvent = "Mon..Fri *-*-* 09:00:00 Asia/Seoul".parse().unwrap();
+/// // Saturday noon in UTC is Saturday evening in Seoul
+/// let saturday = DateTime::new(2024, 5, 18, 12, 0, 0, TimeZone::UTC).unwrap();
+/// let next = event.next_elapse(&saturday).unwrap();
+/// assert_eq!(next.to_string(), "2024-05-20 09:00:00");
+/// assert_eq!(next.timezone, TimeZone::KST);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEvent {
+    weekdays: Vec<Weekday>,
+    year: Component,
+    month: Component,
+    day: Component,
+    // Whether days count from the end of the month (`~`).
+    day_from_end: bool,
+    hour: Component,
+    minute: Component,
+    second: Component,
+    timezone: Option<(String, TimeZone)>,
+}
+
+impl CalendarEvent {
+    /// Returns the zone given in the event, if any.
+    pub fn timezone(&self) -> Option<TimeZone> {
+        self.timezone.as_ref().map(|(_, timezone)| *timezone)
+    }
+
+    /// Returns the first time the event elapses strictly after `datetime`, or `None` if it
+    /// never elapses again before the year 10000.
+    ///
+    /// The result is in the event's zone, or in the zone of `datetime` when the event has none.
+    pub fn next_elapse(&self, datetime: &DateTime) -> Option<DateTime> {
+        let timezone = self.timezone().unwrap_or(datetime.timezone);
+        let local = datetime.to_unix_seconds() + timezone.offset_in_seconds() + 1;
+        let mut date = Date::from_days_since_epoch(local.div_euclid(SECONDS_IN_DAY)).ok()?;
+        let mut from = local.rem_euclid(SECONDS_IN_DAY);
+        while date.year <= LAST_YEAR {
+            if !self.year.matches(date.year) {
+                date = Date::new(date.year + 1, 1, 1).ok()?;
+            } else if !self.month.matches(date.month) {
+                date = match date.month {
+                    12 => Date::new(date.year + 1, 1, 1).ok()?,
+                    month => Date::new(date.year, month + 1, 1).ok()?,
+                };
+            } else {
+                if self.matches_day(date) {
+                    if let Some(time) = self.first_time_from(from) {
+                        return date.and_hms(time.0, time.1, time.2, timezone).ok();
+                    }
+                }
+                date = date.add_days(1).ok()?;
+            }
+            from = 0;
+        }
+        None
+    }
+
+    fn matches_day(&self, date: Date) -> bool {
+        let day = if self.day_from_end {
+            days_in_month(date.month, date.year) + 1 - date.day
+        } else {
+            date.day
+        };
+        self.day.matches(day)
+            && (self.weekdays.is_empty() || self.weekdays.contains(&date.weekday()))
+    }
+
+    fn first_time_from(&self, from: i64) -> Option<(u64, u64, u64)> {
+        let from_hour = (from / SECONDS_IN_HOUR) as u64;
+        for hour in (from_hour..24).filter(|hour| self.hour.matches(*hour)) {
+            let from_minute = if hour == from_hour {
+                (from % SECONDS_IN_HOUR / SECONDS_IN_MINUTE) as u64
+            } else {
+                0
+            };
+            for minute in (from_minute..60).filter(|minute| self.minute.matches(*minute)) {
+                let from_second = if hour == from_hour && minute == from_minute {
+                    (from % SECONDS_IN_MINUTE) as u64
+                } else {
+                    0
+                };
+                if let Some(second) = (from_second..60).find(|second| self.second.matches(*second))
+                {
+                    return Some((hour, minute, second));
+                }
+            }
+        }
+        None
+    }
+
+    fn parse_weekdays(text: &str) -> Result<Vec<Weekday>, String> {
+        let mut weekdays = Vec::new();
+        for item in text.split(',') {
+            match item.split_once("..").or_else(|| item.split_once('-')) {
+                Some((first, last)) => {
+                    let (first, last) = (first.parse::<Weekday>()?, last.parse::<Weekday>()?);
+                    let mut weekday = first;
+                    weekdays.push(weekday);
+                    while weekday != last {
+                        weekday = weekday.succ();
+                        weekdays.push(weekday);
+                    }
+                }
+                None => weekdays.push(item.parse()?),
+            }
+        }
+        weekdays.sort();
+        weekdays.dedup();
+        Ok(weekdays)
+    }
+}
+
+impl FromStr for CalendarEvent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens: Vec<&str> = s.split_whitespace().collect();
+        let shorthand = match tokens.first().map(|token| token.to_ascii_lowercase()) {
+            Some(token) => match token.as_str() {
+                "minutely" => Some("*-*-* *:*:00"),
+                "hourly" => Some("*-*-* *:00:00"),
+                "daily" => Some("*-*-* 00:00:00"),
+                "weekly" => Some("Mon *-*-* 00:00:00"),
+                "monthly" => Some("*-*-01 00:00:00"),
+                "yearly" | "annually" => Some("*-01-01 00:00:00"),
+                "quarterly" => Some("*-01,04,07,10-01 00:00:00"),
+                "semiannually" => Some("*-01,07-01 00:00:00"),
+                _ => None,
+            },
+            None => return Err("Calendar event must not be empty".to_string()),
+        };
+        if let Some(expansion) = shorthand {
+            tokens.splice(0..1, expansion.split(' '));
+        }
+
+        let mut event = CalendarEvent {
+            weekdays: Vec::new(),
+            year: Component::any(),
+            month: Component::any(),
+            day: Component::any(),
+            day_from_end: false,
+            hour: Component::value(0),
+            minute: Component::value(0),
+            second: Component::value(0),
+            timezone: None,
+        };
+
+        let mut rest = tokens.as_slice();
+        if let Some((first, tail)) = rest.split_first() {
+            if first.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                if let Ok(weekdays) = Self::parse_weekdays(first) {
+                    event.weekdays = weekdays;
+                    rest = tail;
+                }
+            }
+        }
+        if let Some((last, head)) = rest.split_last() {
+            if !last.contains([':', '*', '~']) && !last.starts_with(|c: char| c.is_ascii_digit()) {
+                event.timezone = Some((last.to_string(), last.parse()?));
+                rest = head;
+            }
+        }
+        let (date, time) = match rest {
+            [] => (None, None),
+            [token] if token.contains(':') => (None, Some(*token)),
+            [token] => (Some(*token), None),
+            [date, time] => (Some(*date), Some(*time)),
+            _ => return Err(format!("Invalid calendar event: {}", s)),
+        };
+
+        if let Some(date) = date {
+            let (year_month, day, from_end) = match date.split_once('~') {
+                Some((year_month, day)) => (year_month, day, true),
+                None => match date.rsplit_once('-') {
+                    Some((year_month, day)) => (year_month, day, false),
+                    None => return Err(format!("Invalid calendar date: {}", date)),
+                },
+            };
+            match year_month.split_once('-') {
+                Some((year, month)) => {
+                    event.year = Component::parse(year, 1970, LAST_YEAR, false)?;
+                    event.month = Component::parse(month, 1, 12, false)?;
+                }
+                None => event.month = Component::parse(year_month, 1, 12, false)?,
+            }
+            event.day = Component::parse(day, 1, 31, from_end)?;
+            event.day_from_end = from_end;
+        }
+        if let Some(time) = time {
+            let parts: Vec<&str> = time.split(':').collect();
+            let (hour, minute, second) = match parts.as_slice() {
+                [hour, minute] => (*hour, *minute, "00"),
+                [hour, minute, second] => (*hour, *minute, *second),
+                _ => return Err(format!("Invalid calendar time: {}", time)),
+            };
+            event.hour = Component::parse(hour, 0, 23, false)?;
+            event.minute = Component::parse(minute, 0, 59, false)?;
+            event.second = Component::parse(second, 0, 59, false)?;
+        }
+        Ok(event)
+    }
+}
+
+impl fmt::Display for CalendarEvent {
+    /// Formats the event in normalized form, such as `Mon..Fri *-*-* 09:00:00 Asia/Seoul`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Runs of three or more consecutive weekdays are written as ranges.
+        let mut runs: Vec<(Weekday, Weekday)> = Vec::new();
+        for &weekday in &self.weekdays {
+            match runs.last_mut() {
+                Some((_, last)) if last.succ() == weekday => *last = weekday,
+                _ => runs.push((weekday, weekday)),
+            }
+        }
+        let short = |weekday: Weekday| &weekday.name()[..3];
+        let mut names = Vec::new();
+        for (first, last) in runs {
+            match last.days_since(first) {
+                0 => names.push(short(first).to_string()),
+                1 => names.extend([short(first).to_string(), short(last).to_string()]),
+                _ => names.push(format!("{}..{}", short(first), short(last))),
+            }
+        }
+        if !names.is_empty() {
+            write!(f, "{} ", names.join(","))?;
+        }
+
+        self.year.write(f, 4)?;
+        write!(f, "-")?;
+        self.month.write(f, 2)?;
+        write!(f, "{}", if self.day_from_end { "~" } else { "-" })?;
+        self.day.write(f, 2)?;
+        write!(f, " ")?;
+        self.hour.write(f, 2)?;
+        write!(f, ":")?;
+        self.minute.write(f, 2)?;
+        write!(f, ":")?;
+        self.second.write(f, 2)?;
+        if let Some((name, _)) = &self.timezone {
+            write!(f, " {}", name)?;
+        }
+        Ok(())
+    }
+}
+
+impl TimeDelta {
+    /// Parses a systemd time span, such as `1h 30min`, `2weeks`, `1.5d` or `500ms`.
+    ///
+    /// A span is a sequence of numbers, each followed by an optional unit; a number without a
+    /// unit is in seconds. The units are `usec`/`us`, `msec`/`ms`, `seconds`/`second`/`sec`/`s`,
+    /// `minutes`/`minute`/`min`/`m`, `hours`/`hour`/`hr`/`h`, `days`/`day`/`d`,
+    /// `weeks`/`week`/`w`, `months`/`month`/`M` (30.44 days) and `years`/`year`/`y`
+    /// (365.25 days). Parts of a second go into `nanoseconds`.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::TimeDelta;
+    /// let span = TimeDelta::parse_timespan("1h 30min").unwrap();
+    /// assert_eq!(span.total_seconds(), 5400);
+    /// assert_eq!(TimeDelta::parse_timespan("2weeks").unwrap().days, 14);
+    /// assert_eq!(TimeDelta::parse_timespan("1.5s").unwrap().nanoseconds, 500_000_000);
+    /// ```
+    pub fn parse_timespan(s: &str) -> Result<TimeDelta, String> {
+        let invalid = || format!("Invalid time span: {}", s);
+        let nanoseconds = parse_units(s, TIMESPAN_UNITS).ok_or_else(invalid)?;
+        TimeDelta::from_nanoseconds(nanoseconds).map_err(|_| invalid())
+    }
+}
+
+// The units of a systemd time span, in nanoseconds.
+const TIMESPAN_UNITS: &[(&[&str], i128)] = &[
+    (&["usec", "us"], 1_000),
+    (&["msec", "ms"], 1_000_000),
+    (&["", "seconds", "second", "sec", "s"], 1_000_000_000),
+    (&["minutes", "minute", "min", "m"], 60_000_000_000),
+    (&["hours", "hour", "hr", "h"], 3_600_000_000_000),
+    (&["days", "day", "d"], 86_400_000_000_000),
+    (&["weeks", "week", "w"], 604_800_000_000_000),
+    (&["months", "month", "M"], 2_629_800_000_000_000),
+    (&["years", "year", "y"], 31_557_600_000_000_000),
+];
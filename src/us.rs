@@ -0,0 +1,90 @@
+use crate::holiday::{Holiday, HolidayProvider};
+use crate::{Date, Weekday};
+
+/// United States federal holidays (5 U.S.C. 6103) from 1971, the first year of the Uniform
+/// Monday Holiday Act.
+///
+/// Dates are the days observed by federal employees: a holiday falling on a Saturday is
+/// observed on the Friday before and one falling on a Sunday on the Monday after, with
+/// `" (observed)"` added to the name. A New Year's Day on a Saturday is therefore observed on
+/// December 31st and listed with the previous year. Inauguration Day, a holiday only in the
+/// Washington, D.C. area, is not included.
+///
+/// # Example:
+/// ```
+/// use timekit::{Date, HolidayProvider, UsFederalHolidays};
+/// let holidays = UsFederalHolidays.holidays(2021).unwrap();
+/// let last = holidays.last().unwrap();
+/// assert_eq!(last.date, Date::new(2021, 12, 31).unwrap());
+/// assert_eq!(last.name, "New Year's Day (observed)");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UsFederalHolidays;
+
+impl UsFederalHolidays {
+    // Holidays on their actual dates.
+    fn actual(year: u64) -> Result<Vec<(Date, &'static str)>, String> {
+        let date = |month, day| Date::new(year, month, day);
+        let nth = |month, weekday, n| Date::nth_weekday_of_month(year, month, weekday, n);
+        let mut holidays = vec![(date(1, 1)?, "New Year's Day")];
+        if year >= 1986 {
+            holidays.push((nth(1, Weekday::Monday, 3)?, "Martin Luther King Jr. Day"));
+        }
+        holidays.push((nth(2, Weekday::Monday, 3)?, "Washington's Birthday"));
+        holidays.push((nth(5, Weekday::Monday, -1)?, "Memorial Day"));
+        if year >= 2021 {
+            holidays.push((date(6, 19)?, "Juneteenth National Independence Day"));
+        }
+        holidays.push((date(7, 4)?, "Independence Day"));
+        holidays.push((nth(9, Weekday::Monday, 1)?, "Labor Day"));
+        holidays.push((nth(10, Weekday::Monday, 2)?, "Columbus Day"));
+        // Veterans Day moved to the fourth Monday of October from 1971 to 1977.
+        let veterans = if year <= 1977 {
+            nth(10, Weekday::Monday, 4)?
+        } else {
+            date(11, 11)?
+        };
+        holidays.push((veterans, "Veterans Day"));
+        holidays.push((nth(11, Weekday::Thursday, 4)?, "Thanksgiving Day"));
+        holidays.push((date(12, 25)?, "Christmas Day"));
+        Ok(holidays)
+    }
+}
+
+// The weekday on which a holiday falling on a weekend is observed.
+pub(crate) fn observed(date: Date) -> Result<Date, String> {
+    match date.weekday() {
+        Weekday::Saturday => date.add_days(-1),
+        Weekday::Sunday => date.add_days(1),
+        _ => Ok(date),
+    }
+}
+
+// The holiday name, marked when it is observed on another day.
+pub(crate) fn observed_name(name: &str, date: Date, observed: Date) -> String {
+    if date == observed {
+        name.to_string()
+    } else {
+        format!("{} (observed)", name)
+    }
+}
+
+impl HolidayProvider for UsFederalHolidays {
+    fn holidays(&self, year: u64) -> Result<Vec<Holiday>, String> {
+        if year < 1971 {
+            return Err("US federal holidays are only available from 1971".to_string());
+        }
+        let mut holidays = Vec::new();
+        let next_new_year = (Date::new(year + 1, 1, 1)?, "New Year's Day");
+        for (date, name) in Self::actual(year)?.into_iter().chain([next_new_year]) {
+            let day = observed(date)?;
+            if day.year == year {
+                holidays.push(Holiday {
+                    date: day,
+                    name: observed_name(name, date, day),
+                });
+            }
+        }
+        Ok(holidays)
+    }
+}
@@ -0,0 +1,126 @@
+use crate::DateTime;
+use std::fmt;
+use std::str::FromStr;
+
+/// Days of the week, in ISO 8601 order starting on Monday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+const ALL_WEEKDAYS: [Weekday; 7] = [
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+    Weekday::Sunday,
+];
+
+impl Weekday {
+    /// Returns an iterator over the days of the week, starting on Monday.
+    pub fn all() -> impl Iterator<Item = Weekday> {
+        ALL_WEEKDAYS.iter().copied()
+    }
+
+    /// Returns the ISO 8601 day number, from 1 (Monday) to 7 (Sunday).
+    pub const fn number_from_monday(&self) -> u64 {
+        *self as u64 + 1
+    }
+
+    /// Returns the day number counted from 0 (Sunday) to 6 (Saturday), as used by cron.
+    pub const fn number_from_sunday(&self) -> u64 {
+        (*self as u64 + 1) % 7
+    }
+
+    /// Returns the weekday with the given ISO 8601 number (1 = Monday ... 7 = Sunday).
+    pub fn from_number_from_monday(number: u64) -> Option<Weekday> {
+        match number {
+            1..=7 => Some(ALL_WEEKDAYS[number as usize - 1]),
+            _ => None,
+        }
+    }
+
+    /// Returns the following day.
+    pub const fn succ(&self) -> Weekday {
+        ALL_WEEKDAYS[(*self as usize + 1) % 7]
+    }
+
+    /// Returns the preceding day.
+    pub const fn pred(&self) -> Weekday {
+        ALL_WEEKDAYS[(*self as usize + 6) % 7]
+    }
+
+    /// Returns how many days after `other` this weekday comes, from 0 to 6.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::Weekday;
+    /// assert_eq!(Weekday::Friday.days_since(Weekday::Monday), 4);
+    /// assert_eq!(Weekday::Monday.days_since(Weekday::Friday), 3);
+    /// ```
+    pub const fn days_since(&self, other: Weekday) -> u64 {
+        (*self as u64 + 7 - other as u64) % 7
+    }
+
+    /// Returns the English name of the day, e.g. `"Monday"`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+    }
+
+    // Weekday of the day `days` after 1970-01-01, which was a Thursday.
+    pub(crate) fn from_days_since_epoch(days: i64) -> Weekday {
+        ALL_WEEKDAYS[(days + 3).rem_euclid(7) as usize]
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Weekday {
+    type Err = String;
+
+    /// Parses a full English name (`"Monday"`) or a three-letter abbreviation (`"Mon"`),
+    /// ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Weekday::all()
+            .find(|weekday| {
+                weekday.name().eq_ignore_ascii_case(s)
+                    || (s.len() == 3 && weekday.name()[..3].eq_ignore_ascii_case(s))
+            })
+            .ok_or_else(|| format!("Unknown weekday: {}", s))
+    }
+}
+
+impl DateTime {
+    /// Returns the day of the week on the local wall clock.
+    ///
+    /// # Example:
+    /// ```
+    /// use timekit::{DateTime, TimeZone, Weekday};
+    /// let datetime = DateTime::new(2024, 5, 17, 0, 30, 0, TimeZone::KST).unwrap();
+    /// assert_eq!(datetime.weekday(), Weekday::Friday);
+    /// ```
+    pub fn weekday(&self) -> Weekday {
+        self.date().weekday()
+    }
+}
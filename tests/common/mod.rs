@@ -0,0 +1,12 @@
+// Helpers shared by the integration tests. Each test crate uses only some of them.
+#![allow(dead_code)]
+
+use timekit::{Date, DateTime, TimeZone};
+
+pub fn date(year: u64, month: u64, day: u64) -> Date {
+    Date::new(year, month, day).unwrap()
+}
+
+pub fn utc(year: u64, month: u64, day: u64, hour: u64, minute: u64) -> DateTime {
+    DateTime::new(year, month, day, hour, minute, 0, TimeZone::UTC).unwrap()
+}
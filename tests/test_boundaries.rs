@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{DateTime, TimeZone, Weekday};
+
+    // Test start and end of day in the DateTime's own zone
+    #[test]
+    fn test_day_boundaries() {
+        let datetime = DateTime::new(2024, 5, 17, 7, 45, 12, TimeZone::KST).unwrap();
+        let start = datetime.start_of_day().unwrap();
+        let end = datetime.end_of_day().unwrap();
+        assert_eq!(start.to_string(), "2024-05-17 00:00:00");
+        assert_eq!(end.to_string(), "2024-05-17 23:59:59");
+        assert_eq!(start.timezone, TimeZone::KST);
+        assert_eq!(end.to_unix_seconds() - start.to_unix_seconds(), 86_399);
+    }
+
+    // Test week boundaries with different first weekdays
+    #[test]
+    fn test_week_boundaries() {
+        let friday = DateTime::new(2024, 5, 17, 15, 0, 0, TimeZone::KST).unwrap();
+        assert_eq!(
+            friday.start_of_week(Weekday::Monday).unwrap().to_string(),
+            "2024-05-13 00:00:00"
+        );
+        assert_eq!(
+            friday.end_of_week(Weekday::Monday).unwrap().to_string(),
+            "2024-05-19 23:59:59"
+        );
+        assert_eq!(
+            friday.start_of_week(Weekday::Sunday).unwrap().to_string(),
+            "2024-05-12 00:00:00"
+        );
+        assert_eq!(
+            friday.start_of_week(Weekday::Friday).unwrap().to_string(),
+            "2024-05-17 00:00:00"
+        );
+        assert_eq!(
+            friday.end_of_week(Weekday::Saturday).unwrap().to_string(),
+            "2024-05-17 23:59:59"
+        );
+    }
+
+    // Test month, quarter and year boundaries
+    #[test]
+    fn test_month_quarter_year_boundaries() {
+        let datetime = DateTime::new(2024, 2, 10, 12, 0, 0, TimeZone::EST).unwrap();
+        assert_eq!(
+            datetime.start_of_month().unwrap().to_string(),
+            "2024-02-01 00:00:00"
+        );
+        assert_eq!(
+            datetime.end_of_month().unwrap().to_string(),
+            "2024-02-29 23:59:59"
+        );
+        assert_eq!(
+            datetime.start_of_quarter().unwrap().to_string(),
+            "2024-01-01 00:00:00"
+        );
+        assert_eq!(
+            datetime.end_of_quarter().unwrap().to_string(),
+            "2024-03-31 23:59:59"
+        );
+        assert_eq!(
+            datetime.start_of_year().unwrap().to_string(),
+            "2024-01-01 00:00:00"
+        );
+        assert_eq!(
+            datetime.end_of_year().unwrap().to_string(),
+            "2024-12-31 23:59:59"
+        );
+
+        let november = DateTime::new(2023, 11, 30, 1, 0, 0, TimeZone::UTC).unwrap();
+        assert_eq!(
+            november.start_of_quarter().unwrap().to_string(),
+            "2023-10-01 00:00:00"
+        );
+        assert_eq!(
+            november.end_of_quarter().unwrap().to_string(),
+            "2023-12-31 23:59:59"
+        );
+    }
+}
@@ -0,0 +1,126 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::date;
+    use timekit::{BusinessCalendar, DateTime, TimeZone, Weekday};
+
+    // Test weekends and holidays for Saturday/Sunday and Friday/Saturday calendars
+    #[test]
+    fn test_is_business_day() {
+        let calendar = BusinessCalendar::default().with_holidays([date(2024, 1, 1)]);
+        assert_eq!(calendar.weekend(), [Weekday::Saturday, Weekday::Sunday]);
+        assert!(!calendar.is_business_day(date(2024, 1, 1))); // holiday
+        assert!(calendar.is_business_day(date(2024, 1, 5))); // Friday
+        assert!(!calendar.is_business_day(date(2024, 1, 6))); // Saturday
+        assert!(calendar.is_holiday(date(2024, 1, 1)));
+        assert!(calendar.is_weekend(date(2024, 1, 7)));
+
+        let calendar = BusinessCalendar::friday_saturday();
+        assert!(!calendar.is_business_day(date(2024, 1, 5)));
+        assert!(calendar.is_business_day(date(2024, 1, 7))); // Sunday
+
+        let datetime = DateTime::new(2024, 1, 6, 23, 0, 0, TimeZone::KST).unwrap();
+        assert!(!BusinessCalendar::default().is_business_day(datetime));
+    }
+
+    // Test stepping over weekends and holidays in both directions
+    #[test]
+    fn test_add_business_days() {
+        let mut calendar = BusinessCalendar::default();
+        calendar.add_holiday(date(2024, 5, 6));
+        calendar.add_holiday(date(2024, 5, 15));
+
+        assert_eq!(
+            calendar.next_business_day(date(2024, 5, 3)).unwrap(),
+            date(2024, 5, 7)
+        );
+        assert_eq!(
+            calendar.next_business_day(date(2024, 5, 4)).unwrap(),
+            date(2024, 5, 7)
+        );
+        assert_eq!(
+            calendar.previous_business_day(date(2024, 5, 7)).unwrap(),
+            date(2024, 5, 3)
+        );
+        assert_eq!(
+            calendar.add_business_days(date(2024, 5, 3), 10).unwrap(),
+            date(2024, 5, 21)
+        );
+        assert_eq!(
+            calendar.add_business_days(date(2024, 5, 21), -10).unwrap(),
+            date(2024, 5, 3)
+        );
+        assert_eq!(
+            calendar.add_business_days(date(2024, 5, 4), 0).unwrap(),
+            date(2024, 5, 4)
+        );
+
+        // A DateTime keeps its time of day and zone
+        let deadline = DateTime::new(2024, 5, 3, 18, 30, 0, TimeZone::KST).unwrap();
+        let due = calendar.add_business_days(deadline, 2).unwrap();
+        assert_eq!(due.to_string(), "2024-05-08 18:30:00");
+        assert_eq!(due.timezone, TimeZone::KST);
+
+        let never = BusinessCalendar::new(&Weekday::all().collect::<Vec<_>>());
+        assert!(never.next_business_day(date(2024, 5, 3)).is_err());
+    }
+
+    // Test counting business days over ranges
+    #[test]
+    fn test_business_days_between() {
+        let calendar =
+            BusinessCalendar::default().with_holidays([date(2024, 5, 6), date(2024, 5, 15)]);
+        assert_eq!(
+            calendar.business_days_between(date(2024, 5, 1), date(2024, 6, 1)),
+            21
+        );
+        assert_eq!(
+            calendar.business_days_between(date(2024, 6, 1), date(2024, 5, 1)),
+            -21
+        );
+        assert_eq!(
+            calendar.business_days_between(date(2024, 5, 6), date(2024, 5, 6)),
+            0
+        );
+        assert_eq!(
+            calendar.business_days_between(date(2024, 5, 3), date(2024, 5, 8)),
+            2
+        );
+
+        // The count agrees with checking every day of the year
+        let start = date(2024, 1, 1);
+        for days in [0, 1, 6, 7, 8, 100, 365] {
+            let end = start.add_days(days).unwrap();
+            let expected = (0..days)
+                .filter(|offset| calendar.is_business_day(start.add_days(*offset).unwrap()))
+                .count() as i64;
+            assert_eq!(calendar.business_days_between(start, end), expected);
+        }
+    }
+
+    // Test combining calendars by union and intersection
+    #[test]
+    fn test_combine_calendars() {
+        let korea = BusinessCalendar::saturday_sunday()
+            .with_holidays([date(2024, 5, 6), date(2024, 5, 15)]);
+        let gulf = BusinessCalendar::friday_saturday()
+            .with_holidays([date(2024, 5, 13), date(2024, 5, 15)]);
+
+        let either = korea.union(&gulf);
+        assert_eq!(
+            either.weekend(),
+            [Weekday::Friday, Weekday::Saturday, Weekday::Sunday]
+        );
+        assert!(!either.is_business_day(date(2024, 5, 6)));
+        assert!(!either.is_business_day(date(2024, 5, 13)));
+        assert!(either.is_business_day(date(2024, 5, 14)));
+
+        let both = korea.intersection(&gulf);
+        assert_eq!(both.weekend(), [Weekday::Saturday]);
+        assert!(both.is_business_day(date(2024, 5, 6))); // open in the Gulf
+        assert!(both.is_business_day(date(2024, 5, 10))); // Friday, open in Korea
+        assert!(!both.is_business_day(date(2024, 5, 15))); // closed in both
+        assert!(both.is_business_day(date(2024, 5, 12))); // Sunday, a working day in the Gulf
+    }
+}
@@ -0,0 +1,137 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::utc;
+    use timekit::{CronSchedule, DateTime, TimeZone};
+
+    fn upcoming(expression: &str, after: DateTime, count: usize) -> Vec<String> {
+        expression
+            .parse::<CronSchedule>()
+            .unwrap()
+            .upcoming(after)
+            .take(count)
+            .map(|datetime| datetime.to_string())
+            .collect()
+    }
+
+    // Test parsing of fields, names, steps, macros and invalid expressions
+    #[test]
+    fn test_cron_parse() {
+        let start = utc(2024, 5, 1, 0, 0);
+        assert_eq!(
+            upcoming("*/20 9-10 * * *", start, 3),
+            [
+                "2024-05-01 09:00:00",
+                "2024-05-01 09:20:00",
+                "2024-05-01 09:40:00"
+            ]
+        );
+        assert_eq!(
+            upcoming("0 12 1 jan,jul ?", start, 2),
+            ["2024-07-01 12:00:00", "2025-01-01 12:00:00"]
+        );
+        // Six fields start with seconds
+        assert_eq!(
+            upcoming("15,45 0 0 * * *", start, 2),
+            ["2024-05-01 00:00:15", "2024-05-01 00:00:45"]
+        );
+        assert_eq!(upcoming("@daily", start, 1), ["2024-05-02 00:00:00"]);
+        assert_eq!(upcoming("@weekly", start, 1), ["2024-05-05 00:00:00"]);
+        assert_eq!(upcoming("@yearly", start, 1), ["2025-01-01 00:00:00"]);
+
+        let schedule: CronSchedule = "  0   9 * *  MON-FRI ".parse().unwrap();
+        assert_eq!(schedule.to_string(), "0 9 * * MON-FRI");
+        assert_eq!(schedule.timezone(), TimeZone::UTC);
+
+        for invalid in [
+            "* * * *",
+            "60 * * * *",
+            "* 24 * * *",
+            "* * 0 * *",
+            "* * * 13 *",
+            "* * * * 8",
+            "*/0 * * * *",
+            "5-1 * * * *",
+            "* * * * MON#6",
+            "@reboot",
+        ] {
+            assert!(invalid.parse::<CronSchedule>().is_err(), "{}", invalid);
+        }
+    }
+
+    // Test the L, W and # special characters
+    #[test]
+    fn test_cron_special_days() {
+        let start = utc(2024, 1, 1, 0, 0);
+        assert_eq!(
+            upcoming("0 0 L * *", start, 3),
+            [
+                "2024-01-31 00:00:00",
+                "2024-02-29 00:00:00",
+                "2024-03-31 00:00:00"
+            ]
+        );
+        assert_eq!(upcoming("0 0 L-2 2 *", start, 1), ["2024-02-27 00:00:00"]);
+        // June 2024 ends on a Sunday, so its last weekday is Friday the 28th
+        assert_eq!(upcoming("0 0 LW 6 *", start, 1), ["2024-06-28 00:00:00"]);
+        // 2024-06-01 is a Saturday: 1W moves forward to Monday the 3rd, not into May
+        assert_eq!(upcoming("0 0 1W 6 *", start, 1), ["2024-06-03 00:00:00"]);
+        // 2024-06-15 is a Saturday, so 15W is Friday the 14th
+        assert_eq!(upcoming("0 0 15W 6 *", start, 1), ["2024-06-14 00:00:00"]);
+        assert_eq!(
+            upcoming("0 0 * * FRI#3", start, 2),
+            ["2024-01-19 00:00:00", "2024-02-16 00:00:00"]
+        );
+        assert_eq!(
+            upcoming("0 0 * * 5L", start, 2),
+            ["2024-01-26 00:00:00", "2024-02-23 00:00:00"]
+        );
+        // Both day fields restricted: the 13th of the month or any Friday
+        assert_eq!(
+            upcoming("0 0 13 * 5", start, 3),
+            [
+                "2024-01-05 00:00:00",
+                "2024-01-12 00:00:00",
+                "2024-01-13 00:00:00"
+            ]
+        );
+        // February 30th never happens
+        let never: CronSchedule = "0 0 30 2 *".parse().unwrap();
+        assert_eq!(never.next_after(&start), None);
+    }
+
+    // Test next and previous fire times in a chosen time zone
+    #[test]
+    fn test_cron_next_and_prev() {
+        let schedule = CronSchedule::new("30 9 * * MON-FRI", TimeZone::KST).unwrap();
+
+        // Friday 2024-05-17 00:00 UTC is 09:00 KST, before the 09:30 run
+        let friday = utc(2024, 5, 17, 0, 0);
+        let next = schedule.next_after(&friday).unwrap();
+        assert_eq!(next.to_string(), "2024-05-17 09:30:00");
+        assert_eq!(next.timezone, TimeZone::KST);
+        assert!(schedule.matches(&next));
+
+        // next_after and prev_before are strict
+        let after = schedule.next_after(&next).unwrap();
+        assert_eq!(after.to_string(), "2024-05-20 09:30:00");
+        assert_eq!(schedule.prev_before(&after).unwrap(), next);
+        assert_eq!(
+            schedule.prev_before(&next).unwrap().to_string(),
+            "2024-05-16 09:30:00"
+        );
+
+        // The same expression evaluated in UTC fires nine hours later
+        let in_utc = schedule.with_timezone(TimeZone::UTC);
+        let next_utc = in_utc.next_after(&friday).unwrap();
+        assert_eq!(next_utc.to_string(), "2024-05-17 09:30:00");
+        assert_eq!(
+            next_utc.to_unix_seconds() - next.to_unix_seconds(),
+            9 * 3600
+        );
+
+        let first: CronSchedule = "0 0 1 1 *".parse().unwrap();
+        assert_eq!(first.prev_before(&utc(1970, 1, 1, 0, 0)), None);
+    }
+}
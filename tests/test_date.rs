@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{Date, DateTime, TimeZone, Weekday};
+
+    // Test Date validation, parsing and display
+    #[test]
+    fn test_date_new_and_parse() {
+        assert!(Date::new(2024, 2, 29).is_ok());
+        assert!(Date::new(2023, 2, 29).is_err());
+        assert!(Date::new(2023, 13, 1).is_err());
+        assert!(Date::new(1900, 1, 31).is_ok());
+
+        let date: Date = "2024-05-17".parse().unwrap();
+        assert_eq!(date, Date::new(2024, 5, 17).unwrap());
+        assert_eq!(date.to_string(), "2024-05-17");
+        assert!("2024-5-17".parse::<Date>().is_err());
+        assert!("2024-02-30".parse::<Date>().is_err());
+    }
+
+    // Test day counting from the Unix epoch, including dates before 1970
+    #[test]
+    fn test_days_since_epoch() {
+        let epoch = Date::new(1970, 1, 1).unwrap();
+        assert_eq!(epoch.days_since_epoch(), 0);
+        assert_eq!(epoch.weekday(), Weekday::Thursday);
+
+        let date = Date::new(2000, 3, 1).unwrap();
+        assert_eq!(date.days_since_epoch(), 11_017);
+        assert_eq!(Date::from_days_since_epoch(11_017).unwrap(), date);
+
+        let date = Date::new(1900, 1, 1).unwrap();
+        assert_eq!(date.days_since_epoch(), -25_567);
+        assert_eq!(date.weekday(), Weekday::Monday);
+        assert_eq!(Date::from_days_since_epoch(-25_567).unwrap(), date);
+
+        for days in -40_000..40_000 {
+            let date = Date::from_days_since_epoch(days).unwrap();
+            assert_eq!(date.days_since_epoch(), days);
+        }
+
+        assert_eq!(Date::new(2024, 12, 31).unwrap().ordinal(), 366);
+        assert_eq!(
+            Date::new(2024, 2, 28).unwrap().add_days(2).unwrap(),
+            Date::new(2024, 3, 1).unwrap()
+        );
+    }
+
+    // Test combining a date with a time of day
+    #[test]
+    fn test_and_hms() {
+        let date = Date::new(2024, 5, 17).unwrap();
+        assert_eq!(
+            date.and_hms(9, 30, 0, TimeZone::KST).unwrap(),
+            DateTime::new(2024, 5, 17, 9, 30, 0, TimeZone::KST).unwrap()
+        );
+        assert!(date.and_hms(24, 0, 0, TimeZone::UTC).is_err());
+
+        // A DateTime cannot hold a date before 1970
+        let before_epoch = Date::new(1969, 6, 1).unwrap();
+        assert_eq!(
+            before_epoch.and_hms(0, 0, 0, TimeZone::UTC),
+            Err("Year must be 1970 or later".to_string())
+        );
+        assert!(Date::new(1970, 1, 1)
+            .unwrap()
+            .and_hms(0, 0, 0, TimeZone::UTC)
+            .is_ok());
+    }
+
+    // Test nth weekday of the month, from the start and from the end
+    #[test]
+    fn test_nth_weekday_of_month() {
+        let nth = |n| Date::nth_weekday_of_month(2024, 9, Weekday::Monday, n);
+        assert_eq!(nth(1).unwrap().to_string(), "2024-09-02");
+        assert_eq!(nth(3).unwrap().to_string(), "2024-09-16");
+        assert_eq!(nth(5).unwrap().to_string(), "2024-09-30");
+        assert_eq!(nth(-1).unwrap().to_string(), "2024-09-30");
+        assert_eq!(nth(-5).unwrap().to_string(), "2024-09-02");
+        assert!(nth(0).is_err());
+        assert!(nth(6).is_err());
+        assert!(nth(-6).is_err());
+        assert!(nth(i64::MIN).is_err());
+
+        // November 2024 has only four Thursdays; Thanksgiving is the fourth
+        let thanksgiving = Date::nth_weekday_of_month(2024, 11, Weekday::Thursday, 4).unwrap();
+        assert_eq!(thanksgiving.to_string(), "2024-11-28");
+        assert!(Date::nth_weekday_of_month(2024, 11, Weekday::Thursday, 5).is_err());
+        assert!(Date::nth_weekday_of_month(2024, 13, Weekday::Thursday, 1).is_err());
+    }
+
+    // Test relative weekday navigation on Date and DateTime
+    #[test]
+    fn test_next_and_previous_weekday() {
+        let friday = Date::new(2024, 5, 17).unwrap();
+        assert_eq!(
+            friday.next_weekday(Weekday::Tuesday).unwrap().to_string(),
+            "2024-05-21"
+        );
+        assert_eq!(
+            friday.next_weekday(Weekday::Friday).unwrap().to_string(),
+            "2024-05-24"
+        );
+        assert_eq!(
+            friday
+                .previous_weekday(Weekday::Friday)
+                .unwrap()
+                .to_string(),
+            "2024-05-10"
+        );
+        assert_eq!(
+            friday
+                .previous_weekday(Weekday::Sunday)
+                .unwrap()
+                .to_string(),
+            "2024-05-12"
+        );
+
+        let datetime = DateTime::new(2024, 5, 17, 9, 30, 0, TimeZone::KST).unwrap();
+        let next = datetime.next_weekday(Weekday::Tuesday).unwrap();
+        assert_eq!(next.to_string(), "2024-05-21 09:30:00");
+        assert_eq!(next.timezone, TimeZone::KST);
+        let previous = datetime.previous_weekday(Weekday::Saturday).unwrap();
+        assert_eq!(previous.to_string(), "2024-05-11 09:30:00");
+        assert_eq!(datetime.date(), friday);
+    }
+}
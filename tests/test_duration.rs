@@ -0,0 +1,239 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{DateTime, IsoDuration, Period, TimeDelta, TimeZone};
+
+    // Test parsing ISO 8601 durations into a TimeDelta
+    #[test]
+    fn test_parse_iso8601() {
+        let delta = TimeDelta::parse_iso8601("P3DT4H").unwrap();
+        assert_eq!((delta.days, delta.hours), (3, 4));
+        assert_eq!(
+            TimeDelta::parse_iso8601("P2W").unwrap().total_seconds(),
+            1_209_600
+        );
+        assert_eq!(TimeDelta::parse_iso8601("PT90S").unwrap().seconds, 90);
+        assert_eq!(TimeDelta::parse_iso8601("pt1h").unwrap().hours, 1);
+        assert_eq!(
+            TimeDelta::parse_iso8601("P0D").unwrap(),
+            TimeDelta::default()
+        );
+
+        // Fractions in the last component, with either decimal sign
+        assert_eq!(
+            TimeDelta::parse_iso8601("PT1.5H").unwrap().total_seconds(),
+            5400
+        );
+        assert_eq!(TimeDelta::parse_iso8601("P0,5D").unwrap().hours, 12);
+        assert_eq!(TimeDelta::parse_iso8601("PT30.000S").unwrap().seconds, 30);
+    }
+
+    // Test keeping fractions of a second
+    #[test]
+    fn test_fractional_seconds() {
+        let half = TimeDelta::parse_iso8601("PT0.5S").unwrap();
+        assert_eq!((half.seconds, half.nanoseconds), (0, 500_000_000));
+        assert_eq!(half.total_nanoseconds(), 500_000_000);
+        assert_eq!(half.total_seconds(), 0);
+
+        let delta = TimeDelta::parse_iso8601("PT1.25S").unwrap();
+        assert_eq!((delta.seconds, delta.nanoseconds), (1, 250_000_000));
+        assert_eq!(delta.to_string(), "1.25 seconds");
+        let delta = TimeDelta::parse_iso8601("-PT1,5S").unwrap();
+        assert_eq!(delta.total_nanoseconds(), -1_500_000_000);
+        assert_eq!(delta.total_seconds(), -1);
+        assert_eq!(delta.to_iso8601(), "-PT1.5S");
+
+        // Fractions of larger units and nanosecond precision
+        let delta = TimeDelta::parse_iso8601("PT0.0001H").unwrap();
+        assert_eq!((delta.seconds, delta.nanoseconds), (0, 360_000_000));
+        let delta = TimeDelta::parse_iso8601("PT0.000000001S").unwrap();
+        assert_eq!(delta.total_nanoseconds(), 1);
+        let delta = TimeDelta::parse_iso8601("PT0.0000000019S").unwrap();
+        assert_eq!(delta.total_nanoseconds(), 1);
+
+        // The DateTime arithmetic drops the part below a second
+        let start = DateTime::new(2024, 1, 1, 0, 0, 0, TimeZone::UTC).unwrap();
+        let delta = TimeDelta::parse_iso8601("PT1.75S").unwrap();
+        assert_eq!(
+            start.add_timedelta(delta).unwrap().to_string(),
+            "2024-01-01 00:00:01"
+        );
+
+        let duration: IsoDuration = "P1DT0.5S".parse().unwrap();
+        assert_eq!(duration.delta.nanoseconds, 500_000_000);
+        assert_eq!(duration.to_string(), "P1DT0.5S");
+        assert!(!"PT0.001S".parse::<IsoDuration>().unwrap().is_zero());
+        assert!("PT-0.5S"
+            .parse::<IsoDuration>()
+            .unwrap()
+            .has_negative_component());
+        let doubled = duration.checked_mul(3).unwrap();
+        assert_eq!(doubled.to_string(), "P3DT1.5S");
+        assert_eq!(
+            TimeDelta::from_nanoseconds(-2_500_000_000).unwrap(),
+            TimeDelta {
+                seconds: -2,
+                nanoseconds: -500_000_000,
+                ..Default::default()
+            }
+        );
+    }
+
+    // Test negative durations
+    #[test]
+    fn test_negative_iso8601() {
+        let delta = TimeDelta::parse_iso8601("-PT1H30M").unwrap();
+        assert_eq!((delta.hours, delta.minutes), (-1, -30));
+        assert_eq!(delta.to_iso8601(), "-PT1H30M");
+        let mixed = TimeDelta::parse_iso8601("PT1H-15M").unwrap();
+        assert_eq!(mixed.total_seconds(), 2700);
+        assert_eq!(mixed.to_iso8601(), "PT1H-15M");
+        assert_eq!(TimeDelta::parse_iso8601("-PT-5M").unwrap().minutes, 5);
+        assert_eq!(TimeDelta::parse_iso8601("-PT0.5H").unwrap().minutes, -30);
+    }
+
+    // Test that formatting writes parsed durations back the same way
+    #[test]
+    fn test_iso8601_round_trip() {
+        for input in [
+            "PT1H30M",
+            "P3DT4H",
+            "P2W",
+            "P1W2DT3H4M5S",
+            "PT45S",
+            "-P1D",
+            "PT0S",
+            "PT0.5S",
+            "PT1.25S",
+            "PT1M0.001S",
+            "-PT2.000000001S",
+        ] {
+            assert_eq!(TimeDelta::parse_iso8601(input).unwrap().to_iso8601(), input);
+        }
+        assert_eq!(TimeDelta::from_seconds(90_061).to_iso8601(), "P1DT1H1M1S");
+        for input in ["P1Y2M10D", "P3M", "-P1Y6M", "P1Y-3D", "P0D"] {
+            let period: Period = input.parse().unwrap();
+            assert_eq!(period.to_string(), input);
+        }
+    }
+
+    // Test calendar components and rejected inputs
+    #[test]
+    fn test_ambiguous_iso8601() {
+        let period = Period::parse_iso8601("P1Y2M").unwrap();
+        assert_eq!((period.years, period.months, period.days), (1, 2, 0));
+        assert_eq!(Period::parse_iso8601("P2W3D").unwrap().days, 17);
+
+        // Years and months have no fixed length
+        assert!(TimeDelta::parse_iso8601("P1Y").is_err());
+        assert!(TimeDelta::parse_iso8601("P1M").is_err());
+        // A Period has no time of day, and a fraction of a month is ambiguous
+        assert!(Period::parse_iso8601("P1DT2H").is_err());
+        assert!(Period::parse_iso8601("P1.5M").is_err());
+
+        for input in [
+            "",
+            "P",
+            "PT",
+            "1H",
+            "P1H",
+            "PT1D",
+            "PT1M1H",
+            "PT1H1H",
+            "PT1.5H30M",
+            "P1DT",
+            "PT.5S",
+            "PT1.S",
+            "P1X",
+        ] {
+            assert!(TimeDelta::parse_iso8601(input).is_err(), "{}", input);
+        }
+    }
+
+    // Test parsing Go and Prometheus style duration strings
+    #[test]
+    fn test_parse_compact() {
+        let seconds = |s: &str| s.parse::<TimeDelta>().unwrap().total_seconds();
+        assert_eq!(seconds("90s"), 90);
+        assert_eq!(seconds("1h30m"), 5400);
+        assert_eq!(seconds("2d4h"), 187_200);
+        assert_eq!(seconds("1.5h"), 5400);
+        assert_eq!(seconds("1w"), 604_800);
+        assert_eq!(seconds("-1m30s"), -90);
+        assert_eq!(seconds("2500ms500ms"), 3);
+        assert_eq!(seconds("500ms500000us"), 1);
+        assert_eq!(seconds("2000000000ns"), 2);
+        assert_eq!(seconds(".5m"), 30);
+        assert_eq!(seconds("0"), 0);
+
+        // Whitespace between parts
+        assert_eq!(seconds("2d 4h"), 187_200);
+        assert_eq!(seconds("1h 30m 15s"), 5415);
+        assert_eq!(seconds(" -1m 30s "), -90);
+
+        // Parts of a second
+        let nanoseconds = |s: &str| s.parse::<TimeDelta>().unwrap().total_nanoseconds();
+        assert_eq!(nanoseconds("500ms"), 500_000_000);
+        assert_eq!(nanoseconds("1.5s"), 1_500_000_000);
+        assert_eq!(nanoseconds("0.5s"), 500_000_000);
+        assert_eq!(nanoseconds("-1.5s"), -1_500_000_000);
+        assert_eq!(nanoseconds("1µs"), 1_000);
+        assert_eq!(nanoseconds("2.5us"), 2_500);
+        assert_eq!(nanoseconds("1s1ns"), 1_000_000_001);
+        assert_eq!(nanoseconds("1.0000000001s"), 1_000_000_000);
+        let delta: TimeDelta = "1.25s".parse().unwrap();
+        assert_eq!((delta.seconds, delta.nanoseconds), (1, 250_000_000));
+
+        for input in [
+            "", "-", "1", "1x", "h", "1..5h", "1.2.3h", "--1s", "- 1s", "1h,30m", "1 2s",
+        ] {
+            assert!(input.parse::<TimeDelta>().is_err(), "{}", input);
+        }
+    }
+
+    // Test the compact formatter and its round trip through from_str
+    #[test]
+    fn test_to_compact() {
+        let delta = TimeDelta {
+            days: 2,
+            hours: 4,
+            ..Default::default()
+        };
+        assert_eq!(delta.to_compact(), "2d4h");
+        assert_eq!(TimeDelta::from_seconds(90).to_compact(), "1m30s");
+        assert_eq!(TimeDelta::from_seconds(-5400).to_compact(), "-1h30m");
+        assert_eq!(TimeDelta::from_seconds(694_861).to_compact(), "1w1d1h1m1s");
+        assert_eq!(TimeDelta::default().to_compact(), "0s");
+
+        for seconds in [0, 1, 59, 3600, 86_399, -86_400, 1_000_000, -7_654_321] {
+            let delta = TimeDelta::from_seconds(seconds);
+            let parsed: TimeDelta = delta.to_compact().parse().unwrap();
+            assert_eq!(parsed.total_seconds(), seconds);
+        }
+
+        // Parts of a second
+        let compact = |nanoseconds: i128| {
+            TimeDelta::from_nanoseconds(nanoseconds)
+                .unwrap()
+                .to_compact()
+        };
+        assert_eq!(compact(500_000_000), "500ms");
+        assert_eq!(compact(1_500_000_000), "1.5s");
+        assert_eq!(compact(-250_000), "-250us");
+        assert_eq!(compact(1_500), "1.5us");
+        assert_eq!(compact(7), "7ns");
+        assert_eq!(compact(60_000_000_001), "1m0.000000001s");
+        for nanoseconds in [
+            1,
+            999,
+            1_000_001,
+            500_000_000,
+            -1_500_000_000,
+            90_000_000_123,
+        ] {
+            let delta = TimeDelta::from_nanoseconds(nanoseconds).unwrap();
+            let parsed: TimeDelta = delta.to_compact().parse().unwrap();
+            assert_eq!(parsed.total_nanoseconds(), nanoseconds);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::date;
+    use timekit::{Church, Date, HolidayRule, MovableFeast, Weekday};
+
+    // Test Western Easter against known dates, including the earliest and latest possible
+    #[test]
+    fn test_western_easter() {
+        assert_eq!(Date::easter(1818).unwrap(), date(1818, 3, 22));
+        assert_eq!(Date::easter(1943).unwrap(), date(1943, 4, 25));
+        assert_eq!(Date::easter(2000).unwrap(), date(2000, 4, 23));
+        assert_eq!(Date::easter(2008).unwrap(), date(2008, 3, 23));
+        assert_eq!(Date::easter(2019).unwrap(), date(2019, 4, 21));
+        assert_eq!(Date::easter(2038).unwrap(), date(2038, 4, 25));
+        assert!(Date::easter(1582).is_err());
+    }
+
+    // Test Orthodox Easter, returned as a Gregorian date
+    #[test]
+    fn test_orthodox_easter() {
+        assert_eq!(Date::orthodox_easter(2000).unwrap(), date(2000, 4, 30));
+        assert_eq!(Date::orthodox_easter(2008).unwrap(), date(2008, 4, 27));
+        assert_eq!(Date::orthodox_easter(2019).unwrap(), date(2019, 4, 28));
+        assert_eq!(Date::orthodox_easter(2023).unwrap(), date(2023, 4, 16));
+        assert_eq!(Date::orthodox_easter(2026).unwrap(), date(2026, 4, 12));
+        // The churches share Easter in some years
+        assert_eq!(Date::orthodox_easter(2011).unwrap(), date(2011, 4, 24));
+        assert_eq!(Date::easter(2011).unwrap(), date(2011, 4, 24));
+    }
+
+    // Test that Easter is always a Sunday, between March 22nd and May 8th in 1900-2099
+    #[test]
+    fn test_easter_is_sunday() {
+        for year in 1900..=2099 {
+            let western = Date::easter(year).unwrap();
+            let orthodox = Date::orthodox_easter(year).unwrap();
+            assert_eq!(western.weekday(), Weekday::Sunday);
+            assert_eq!(orthodox.weekday(), Weekday::Sunday);
+            assert!(western >= date(year, 3, 22) && western <= date(year, 4, 25));
+            assert!(orthodox >= western && orthodox <= date(year, 5, 8));
+        }
+    }
+
+    // Test the Easter-relative feasts and holiday rules
+    #[test]
+    fn test_movable_feasts() {
+        let dates: Vec<String> = MovableFeast::all()
+            .map(|feast| feast.date(2025, Church::Western).unwrap().to_string())
+            .collect();
+        assert_eq!(
+            dates,
+            [
+                "2025-03-05",
+                "2025-04-13",
+                "2025-04-17",
+                "2025-04-18",
+                "2025-04-19",
+                "2025-04-20",
+                "2025-04-21",
+                "2025-05-29",
+                "2025-06-08",
+                "2025-06-09",
+                "2025-06-15",
+                "2025-06-19"
+            ]
+        );
+        assert_eq!(MovableFeast::Ascension.to_string(), "Ascension Day");
+        assert_eq!(
+            MovableFeast::Pentecost
+                .date(2024, Church::Orthodox)
+                .unwrap(),
+            date(2024, 6, 23)
+        );
+
+        let rule: HolidayRule = "Orthodox Good Friday = orthodox-easter -2".parse().unwrap();
+        assert_eq!(rule.holiday(2024).unwrap().unwrap().date, date(2024, 5, 3));
+        assert_eq!(
+            rule.to_string(),
+            "Orthodox Good Friday = orthodox-easter -2"
+        );
+    }
+}
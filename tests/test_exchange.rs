@@ -0,0 +1,163 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::{date, utc};
+    use timekit::{Date, DateTime, Exchange, HolidayProvider, TimeZone, UsFederalHolidays};
+
+    fn session(exchange: Exchange, day: Date) -> Option<(String, String)> {
+        exchange
+            .session(day)
+            .unwrap()
+            .map(|session| (session.start().to_string(), session.end().to_string()))
+    }
+
+    // Test federal holidays and their observed dates
+    #[test]
+    fn test_us_federal_holidays() {
+        let holidays = UsFederalHolidays.holidays(2026).unwrap();
+        let dates: Vec<String> = holidays
+            .iter()
+            .map(|holiday| holiday.date.to_string())
+            .collect();
+        assert_eq!(
+            dates,
+            [
+                "2026-01-01",
+                "2026-01-19",
+                "2026-02-16",
+                "2026-05-25",
+                "2026-06-19",
+                "2026-07-03",
+                "2026-09-07",
+                "2026-10-12",
+                "2026-11-11",
+                "2026-11-26",
+                "2026-12-25"
+            ]
+        );
+        assert_eq!(holidays[5].name, "Independence Day (observed)");
+
+        // New Year's Day 2022 was a Saturday, observed in 2021
+        assert!(UsFederalHolidays
+            .holidays(2022)
+            .unwrap()
+            .iter()
+            .all(|holiday| holiday.name != "New Year's Day"));
+        assert!(UsFederalHolidays.holidays(1970).is_err());
+    }
+
+    // Test NYSE sessions across daylight saving time, early closes and closures
+    #[test]
+    fn test_nyse_sessions() {
+        let nyse = Exchange::Nyse;
+        // Eastern Standard Time in January, daylight time in July
+        assert_eq!(
+            session(nyse, date(2024, 1, 2)),
+            Some(("2024-01-02 14:30:00".into(), "2024-01-02 21:00:00".into()))
+        );
+        assert_eq!(
+            session(nyse, date(2024, 7, 3)),
+            Some(("2024-07-03 13:30:00".into(), "2024-07-03 17:00:00".into()))
+        );
+        assert_eq!(session(nyse, date(2024, 3, 29)), None); // Good Friday
+        assert_eq!(session(nyse, date(2024, 6, 19)), None); // Juneteenth
+        assert_eq!(session(nyse, date(2012, 10, 29)), None); // Hurricane Sandy
+                                                             // New Year's Day 2022 fell on a Saturday, but the NYSE traded on the 31st
+        assert!(session(nyse, date(2021, 12, 31)).is_some());
+        assert_eq!(session(nyse, date(2021, 12, 24)), None);
+
+        let holidays = nyse.holidays(2024).unwrap();
+        assert_eq!(holidays.len(), 10);
+    }
+
+    // Test KRX sessions and closures
+    #[test]
+    fn test_krx_sessions() {
+        let krx = Exchange::Krx;
+        // The first trading day of the year opens at 10:00 KST
+        assert_eq!(
+            session(krx, date(2024, 1, 2)),
+            Some(("2024-01-02 01:00:00".into(), "2024-01-02 06:30:00".into()))
+        );
+        assert_eq!(
+            session(krx, date(2024, 1, 3)),
+            Some(("2024-01-03 00:00:00".into(), "2024-01-03 06:30:00".into()))
+        );
+        assert_eq!(session(krx, date(2024, 5, 1)), None); // Labour Day
+        assert_eq!(session(krx, date(2024, 12, 31)), None);
+        // 2023-12-31 was a Sunday, so the market closed on Friday the 29th
+        assert_eq!(session(krx, date(2023, 12, 29)), None);
+        assert_eq!(session(krx, date(2024, 9, 17)), None); // Chuseok
+
+        let calendar = krx.business_calendar(2024..=2024).unwrap();
+        assert_eq!(
+            calendar.next_business_day(date(2024, 9, 13)).unwrap(),
+            date(2024, 9, 19)
+        );
+    }
+
+    // Test is_open, next_open and next_close
+    #[test]
+    fn test_open_and_close() {
+        let nyse = Exchange::Nyse;
+        let thursday_noon = DateTime::new(2024, 3, 28, 12, 0, 0, TimeZone::EST).unwrap();
+        assert!(nyse.is_open(&thursday_noon).unwrap());
+        // Closed for Good Friday, reopening on Monday in daylight time
+        let close = nyse.next_close(&thursday_noon).unwrap();
+        assert_eq!(
+            close.to_unix_seconds(),
+            utc(2024, 3, 28, 20, 0).to_unix_seconds()
+        );
+        assert!(!nyse.is_open(&close).unwrap());
+        let open = nyse.next_open(&close).unwrap();
+        assert_eq!(
+            open.to_unix_seconds(),
+            utc(2024, 4, 1, 13, 30).to_unix_seconds()
+        );
+        assert_eq!(open.timezone, TimeZone::EST);
+        // next_open is strict
+        assert_eq!(
+            nyse.next_open(&open).unwrap().to_unix_seconds(),
+            utc(2024, 4, 2, 13, 30).to_unix_seconds()
+        );
+
+        let krx = Exchange::Krx;
+        let evening = DateTime::new(2024, 12, 30, 20, 0, 0, TimeZone::KST).unwrap();
+        assert!(!krx.is_open(&evening).unwrap());
+        let open = krx.next_open(&evening).unwrap();
+        assert_eq!(open.to_string(), "2025-01-02 10:00:00");
+        assert_eq!(
+            krx.next_close(&open).unwrap().to_string(),
+            "2025-01-02 15:30:00"
+        );
+    }
+
+    // Test the NYSE special closures since 1971
+    #[test]
+    fn test_nyse_special_closures() {
+        let closed = |day: Date| -> Option<String> {
+            Exchange::Nyse
+                .holidays(day.year)
+                .unwrap()
+                .into_iter()
+                .find(|holiday| holiday.date == day)
+                .map(|holiday| holiday.name)
+        };
+        for (day, name) in [
+            (date(1972, 11, 7), "Presidential Election Day"),
+            (date(1972, 12, 28), "Funeral of Harry S. Truman"),
+            (date(1973, 1, 25), "Funeral of Lyndon B. Johnson"),
+            (date(1977, 7, 14), "New York City Blackout"),
+            (date(1985, 9, 27), "Hurricane Gloria"),
+            (date(2001, 9, 14), "September 11 Attacks"),
+            (date(2025, 1, 9), "Funeral of Jimmy Carter"),
+        ] {
+            assert_eq!(closed(day).as_deref(), Some(name));
+            assert_eq!(session(Exchange::Nyse, day), None);
+        }
+        // Election Day has been a trading day since 1984
+        assert_eq!(closed(date(1984, 11, 6)), None);
+        assert!(Exchange::Nyse.holidays(1970).is_err());
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{DateTime, Humanizer, Language, Thresholds, TimeDelta, TimeUnit, TimeZone};
+
+    fn seconds(seconds: i64) -> TimeDelta {
+        TimeDelta::from_seconds(seconds)
+    }
+
+    // Test the default single-unit output and its thresholds
+    #[test]
+    fn test_humanize_thresholds() {
+        assert_eq!(seconds(0).humanize(), "0 seconds");
+        assert_eq!(seconds(1).humanize(), "1 second");
+        assert_eq!(seconds(44).humanize(), "44 seconds");
+        assert_eq!(seconds(45).humanize(), "1 minute");
+        assert_eq!(seconds(21 * 3600).humanize(), "21 hours");
+        assert_eq!(seconds(22 * 3600).humanize(), "1 day");
+        assert_eq!(seconds(10 * 86_400).humanize(), "1 week");
+        assert_eq!(seconds(45 * 86_400).humanize(), "2 months");
+        assert_eq!(seconds(400 * 86_400).humanize(), "1 year");
+        assert_eq!(seconds(-90).humanize(), "2 minutes");
+
+        let no_weeks = Humanizer::default().thresholds(Thresholds {
+            days: 26,
+            ..Default::default()
+        });
+        assert_eq!(seconds(10 * 86_400).humanize_with(&no_weeks), "10 days");
+    }
+
+    // Test multi-unit output with a granularity
+    #[test]
+    fn test_humanize_units() {
+        let delta = TimeDelta {
+            days: 1,
+            hours: 2,
+            minutes: 3,
+            seconds: 40,
+            ..Default::default()
+        };
+        let two = Humanizer::default().max_units(2);
+        assert_eq!(delta.humanize_with(&two), "1 day, 2 hours");
+        let minutes = Humanizer::default()
+            .max_units(3)
+            .granularity(TimeUnit::Minute);
+        assert_eq!(delta.humanize_with(&minutes), "1 day, 2 hours, 4 minutes");
+        // A zero component still counts towards the limit
+        let sparse = TimeDelta {
+            hours: 1,
+            seconds: 20,
+            ..Default::default()
+        };
+        assert_eq!(sparse.humanize_with(&two), "1 hour");
+        assert_eq!(seconds(20).humanize_with(&minutes), "0 minutes");
+    }
+
+    // Test relative phrasing between two instants
+    #[test]
+    fn test_humanize_relative_to() {
+        let now = DateTime::new(2024, 5, 17, 12, 0, 0, TimeZone::UTC).unwrap();
+        let earlier = DateTime::new(2024, 5, 17, 11, 55, 0, TimeZone::UTC).unwrap();
+        let later = DateTime::new(2024, 5, 19, 12, 0, 0, TimeZone::UTC).unwrap();
+        assert_eq!(earlier.humanize_relative_to(&now), "5 minutes ago");
+        assert_eq!(later.humanize_relative_to(&now), "in 2 days");
+        assert_eq!(now.humanize_relative_to(&now), "just now");
+
+        let custom = Humanizer::default().phrasing("{} before", "{} after", "at the same time");
+        assert_eq!(
+            earlier.humanize_relative_to_with(&now, &custom),
+            "5 minutes before"
+        );
+        assert_eq!(
+            now.humanize_relative_to_with(&now, &custom),
+            "at the same time"
+        );
+    }
+
+    // Test Korean and Japanese output
+    #[test]
+    fn test_humanize_languages() {
+        let korean = Humanizer::new(Language::Korean);
+        assert_eq!(korean.relative(-300), "5분 전");
+        assert_eq!(korean.relative(3 * 86_400), "3일 후");
+        assert_eq!(korean.relative(0), "방금");
+
+        let japanese = Humanizer::new(Language::Japanese).max_units(2);
+        assert_eq!(japanese.relative(-5400), "1時間30分前");
+        assert_eq!(japanese.relative(14 * 86_400), "2週間後");
+        assert_eq!(japanese.relative(0), "たった今");
+    }
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{DateTime, Interval, IntervalSet, TimeDelta, TimeZone};
+
+    fn at(hour: u64) -> DateTime {
+        DateTime::new(2024, 5, 1, hour, 0, 0, TimeZone::UTC).unwrap()
+    }
+
+    fn interval(start: u64, end: u64) -> Interval {
+        Interval::new(at(start), at(end)).unwrap()
+    }
+
+    // Test construction, containment and duration of half-open intervals
+    #[test]
+    fn test_interval_basics() {
+        let slot = interval(9, 12);
+        assert!(slot.contains(&at(9)));
+        assert!(slot.contains(&at(11)));
+        assert!(!slot.contains(&at(12)));
+        assert_eq!(
+            slot.duration(),
+            TimeDelta {
+                hours: 3,
+                ..Default::default()
+            }
+        );
+        assert!(!slot.is_empty());
+        assert!(interval(9, 9).is_empty());
+        assert!(Interval::new(at(12), at(9)).is_err());
+
+        let duration = TimeDelta {
+            minutes: 90,
+            ..Default::default()
+        };
+        let slot = Interval::from_duration(at(9), duration).unwrap();
+        assert_eq!(slot.end().to_string(), "2024-05-01 10:30:00");
+
+        // The same instants in another zone form an equal interval
+        let kst = Interval::new(
+            DateTime::new(2024, 5, 1, 18, 0, 0, TimeZone::KST).unwrap(),
+            DateTime::new(2024, 5, 1, 21, 0, 0, TimeZone::KST).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(kst, interval(9, 12));
+    }
+
+    // Test overlap, intersection, union and gap between two intervals
+    #[test]
+    fn test_interval_operations() {
+        let a = interval(9, 12);
+        let b = interval(11, 14);
+        let c = interval(12, 13);
+        let d = interval(15, 16);
+
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&c));
+        assert_eq!(a.intersection(&b), Some(interval(11, 12)));
+        assert_eq!(a.intersection(&c), None);
+
+        assert_eq!(a.union(&b), Some(interval(9, 14)));
+        assert_eq!(a.union(&c), Some(interval(9, 13)));
+        assert_eq!(a.union(&d), None);
+
+        assert_eq!(a.gap(&d), Some(interval(12, 15)));
+        assert_eq!(d.gap(&a), Some(interval(12, 15)));
+        assert_eq!(a.gap(&c), None);
+        assert_eq!(a.gap(&b), None);
+    }
+
+    // Test that sets merge overlapping and touching intervals
+    #[test]
+    fn test_interval_set_merge() {
+        let set: IntervalSet = [
+            interval(13, 15),
+            interval(9, 10),
+            interval(10, 11),
+            interval(14, 16),
+            interval(20, 20),
+        ]
+        .into_iter()
+        .collect();
+        let intervals: Vec<Interval> = set.iter().copied().collect();
+        assert_eq!(intervals, [interval(9, 11), interval(13, 16)]);
+        assert!(set.contains(&at(15)));
+        assert!(!set.contains(&at(12)));
+        assert_eq!(set.total_duration().hours, 5);
+        assert_eq!(
+            set.gaps().iter().copied().collect::<Vec<_>>(),
+            [interval(11, 13)]
+        );
+
+        let other: IntervalSet = [interval(10, 14)].into_iter().collect();
+        let union = set.union(&other);
+        assert_eq!(union.iter().copied().collect::<Vec<_>>(), [interval(9, 16)]);
+        let intersection = set.intersection(&other);
+        assert_eq!(
+            intersection.iter().copied().collect::<Vec<_>>(),
+            [interval(10, 11), interval(13, 14)]
+        );
+    }
+
+    // Test subtracting one set from another
+    #[test]
+    fn test_interval_set_subtract() {
+        let open: IntervalSet = [interval(9, 18)].into_iter().collect();
+        let booked: IntervalSet = [interval(8, 10), interval(12, 13), interval(17, 19)]
+            .into_iter()
+            .collect();
+        let free = open.subtract(&booked);
+        assert_eq!(
+            free.iter().copied().collect::<Vec<_>>(),
+            [interval(10, 12), interval(13, 17)]
+        );
+
+        assert!(open.subtract(&open).is_empty());
+        assert_eq!(open.subtract(&IntervalSet::new()), open);
+        assert!(IntervalSet::new().subtract(&open).is_empty());
+    }
+}
@@ -0,0 +1,191 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{DateTime, IsoDuration, IsoInterval, RepeatingInterval, TimeZone};
+
+    // Test durations that mix calendar and clock components
+    #[test]
+    fn test_iso_duration() {
+        let duration: IsoDuration = "P1Y2M10DT2H30M".parse().unwrap();
+        assert_eq!(
+            (
+                duration.period.years,
+                duration.period.months,
+                duration.period.days
+            ),
+            (1, 2, 10)
+        );
+        assert_eq!((duration.delta.hours, duration.delta.minutes), (2, 30));
+        assert_eq!(duration.to_string(), "P1Y2M10DT2H30M");
+        assert_eq!("P1W".parse::<IsoDuration>().unwrap().to_string(), "P7D");
+        assert_eq!(
+            "P1.5D".parse::<IsoDuration>().unwrap().to_string(),
+            "P1DT12H"
+        );
+        assert_eq!("PT0S".parse::<IsoDuration>().unwrap().to_string(), "PT0S");
+        assert!("P1.5M".parse::<IsoDuration>().is_err());
+
+        // Subtracting does not undo a clamped addition
+        let month: IsoDuration = "P1M".parse().unwrap();
+        let january = DateTime::new(2024, 1, 31, 0, 0, 0, TimeZone::UTC).unwrap();
+        let february = month.add_to(&january).unwrap();
+        assert_eq!(february.to_string(), "2024-02-29 00:00:00");
+        assert_eq!(
+            month.sub_from(&february).unwrap().to_string(),
+            "2024-01-29 00:00:00"
+        );
+    }
+
+    // Test parsing, formatting and resolving the four interval forms
+    #[test]
+    fn test_interval_forms() {
+        let start_end: IsoInterval = "2024-03-01T13:00:00Z/2024-03-02T09:00:00+09:00"
+            .parse()
+            .unwrap();
+        let resolved = start_end.to_interval(TimeZone::UTC).unwrap();
+        assert_eq!(resolved.duration().total_seconds(), 11 * 3600);
+        assert_eq!(
+            start_end.to_string(),
+            "2024-03-01T13:00:00Z/2024-03-02T09:00:00+09:00"
+        );
+
+        let start_duration: IsoInterval = "2024-01-31T00:00:00Z/P1M".parse().unwrap();
+        let resolved = start_duration.to_interval(TimeZone::UTC).unwrap();
+        assert_eq!(resolved.end().to_string(), "2024-02-29 00:00:00");
+
+        let duration_end: IsoInterval = "PT2H30M/2024-03-01T13:00:00Z".parse().unwrap();
+        let resolved = duration_end.to_interval(TimeZone::UTC).unwrap();
+        assert_eq!(resolved.start().to_string(), "2024-03-01 10:30:00");
+        assert_eq!(duration_end.to_string(), "PT2H30M/2024-03-01T13:00:00Z");
+
+        let duration: IsoInterval = "P3D".parse().unwrap();
+        assert_eq!(duration, IsoInterval::Duration("P3D".parse().unwrap()));
+        assert!(duration.to_interval(TimeZone::UTC).is_err());
+    }
+
+    // Test date-only endpoints and ends that leave out the fields shared with the start
+    #[test]
+    fn test_abbreviated_endpoints() {
+        let day: IsoInterval = "2024-01-01/P1D".parse().unwrap();
+        let resolved = day.to_interval(TimeZone::UTC).unwrap();
+        assert_eq!(resolved.start().to_string(), "2024-01-01 00:00:00");
+        assert_eq!(resolved.end().to_string(), "2024-01-02 00:00:00");
+        assert_eq!(day.to_string(), "2024-01-01T00:00:00Z/P1D");
+        let before: IsoInterval = "PT6H/2024-01-02".parse().unwrap();
+        let resolved = before.to_interval(TimeZone::UTC).unwrap();
+        assert_eq!(resolved.start().to_string(), "2024-01-01 18:00:00");
+
+        let afternoon: IsoInterval = "2024-01-01T00:00:00Z/15:30".parse().unwrap();
+        assert_eq!(
+            afternoon.to_string(),
+            "2024-01-01T00:00:00Z/2024-01-01T15:30:00Z"
+        );
+        // The end takes the start's offset and local date
+        let seoul: IsoInterval = "2024-03-01T23:00:00+09:00/23:45:30".parse().unwrap();
+        let resolved = seoul.to_interval(TimeZone::UTC).unwrap();
+        assert_eq!(resolved.duration().total_seconds(), 45 * 60 + 30);
+        let overnight: IsoInterval = "2024-03-01T22:00:00+09:00/02T06:00".parse().unwrap();
+        assert_eq!(
+            overnight.to_string(),
+            "2024-03-01T22:00:00+09:00/2024-03-02T06:00:00+09:00"
+        );
+        let other_offset: IsoInterval = "2024-03-01T22:00:00+09:00/22:30Z".parse().unwrap();
+        let resolved = other_offset.to_interval(TimeZone::UTC).unwrap();
+        assert_eq!(resolved.end().to_string(), "2024-03-01 22:30:00");
+        let dates: IsoInterval = "2008-02-15/03-14".parse().unwrap();
+        assert_eq!(
+            dates.to_string(),
+            "2008-02-15T00:00:00Z/2008-03-14T00:00:00Z"
+        );
+        let repeating: RepeatingInterval = "R2/2024-01-01T09:00:00Z/17:00".parse().unwrap();
+        let hours: Vec<u64> = repeating
+            .occurrences(TimeZone::UTC)
+            .unwrap()
+            .map(|datetime| datetime.hour)
+            .collect();
+        assert_eq!(hours, [9, 17]);
+
+        for input in [
+            "2024-01-01T12:00:00Z/11:00",
+            "2024-01-01T00:00:00Z/1:30",
+            "2024-01-01/3",
+            "2024-01-01/02-30",
+            "2024-01-01T00:00:00Z/25:00",
+            "15:30/2024-01-01T00:00:00Z",
+            "2024-13-01/P1D",
+        ] {
+            assert!(input.parse::<IsoInterval>().is_err(), "{}", input);
+        }
+    }
+
+    // Test rejecting malformed intervals
+    #[test]
+    fn test_invalid_intervals() {
+        assert!("P1D/P2D".parse::<IsoInterval>().is_err());
+        assert!("2024-03-02T00:00:00Z/2024-03-01T00:00:00Z"
+            .parse::<IsoInterval>()
+            .is_err());
+        assert!("2024-03-01T00:00:00Z/-P1D".parse::<IsoInterval>().is_err());
+        assert!("2024-03-01T00:00:00/P1D".parse::<IsoInterval>().is_err());
+        assert!("X5/2024-03-01T00:00:00Z/P1D"
+            .parse::<RepeatingInterval>()
+            .is_err());
+        assert!("R-1/2024-03-01T00:00:00Z/P1D"
+            .parse::<RepeatingInterval>()
+            .is_err());
+        let no_anchor: RepeatingInterval = "R3/P1D".parse().unwrap();
+        assert!(no_anchor.occurrences(TimeZone::UTC).is_err());
+    }
+
+    // Test the occurrences of repeating intervals
+    #[test]
+    fn test_repeating_occurrences() {
+        let daily: RepeatingInterval = "R5/2024-01-01T00:00:00Z/P1D".parse().unwrap();
+        assert_eq!(daily.repetitions, Some(5));
+        assert_eq!(daily.to_string(), "R5/2024-01-01T00:00:00Z/P1D");
+        let days: Vec<u64> = daily
+            .occurrences(TimeZone::UTC)
+            .unwrap()
+            .map(|datetime| datetime.day)
+            .collect();
+        assert_eq!(days, [1, 2, 3, 4, 5]);
+
+        // The step of a start/end interval is its length
+        let shifts: RepeatingInterval = "R3/2024-01-01T06:00:00Z/2024-01-01T14:00:00Z"
+            .parse()
+            .unwrap();
+        let hours: Vec<u64> = shifts
+            .occurrences(TimeZone::UTC)
+            .unwrap()
+            .map(|datetime| datetime.hour)
+            .collect();
+        assert_eq!(hours, [6, 14, 22]);
+
+        // Bounded duration/end occurrences finish at the end
+        let before: RepeatingInterval = "R2/PT1H/2024-01-01T12:00:00Z".parse().unwrap();
+        let hours: Vec<u64> = before
+            .occurrences(TimeZone::UTC)
+            .unwrap()
+            .map(|datetime| datetime.hour)
+            .collect();
+        assert_eq!(hours, [10, 11]);
+
+        // Unbounded intervals are lazy
+        let forever: RepeatingInterval = "R/2024-01-01T00:00:00Z/PT1H".parse().unwrap();
+        assert_eq!(forever.repetitions, None);
+        assert_eq!(forever.to_string(), "R/2024-01-01T00:00:00Z/PT1H");
+        let last = forever
+            .occurrences(TimeZone::UTC)
+            .unwrap()
+            .nth(1000)
+            .unwrap();
+        assert_eq!(last.to_string(), "2024-02-11 16:00:00");
+        let backwards: RepeatingInterval = "R/P1D/2024-01-03T00:00:00Z".parse().unwrap();
+        let days: Vec<u64> = backwards
+            .occurrences(TimeZone::UTC)
+            .unwrap()
+            .take(2)
+            .map(|datetime| datetime.day)
+            .collect();
+        assert_eq!(days, [2, 1]);
+    }
+}
@@ -0,0 +1,110 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::date;
+    use timekit::{Date, DateTime, HolidayProvider, JapaneseEra, JapaneseHolidays, TimeZone};
+
+    fn holidays(year: u64) -> Vec<(String, String)> {
+        JapaneseHolidays
+            .holidays(year)
+            .unwrap()
+            .into_iter()
+            .map(|holiday| (holiday.date.to_string(), holiday.name))
+            .collect()
+    }
+
+    fn dates(year: u64, name: &str) -> Vec<String> {
+        holidays(year)
+            .into_iter()
+            .filter(|(_, holiday)| holiday == name)
+            .map(|(date, _)| date)
+            .collect()
+    }
+
+    // Test converting dates to and from era years
+    #[test]
+    fn test_japanese_era() {
+        assert_eq!(
+            date(2019, 4, 30).japanese_era().unwrap(),
+            (JapaneseEra::Heisei, 31)
+        );
+        assert_eq!(
+            date(2019, 5, 1).japanese_era().unwrap(),
+            (JapaneseEra::Reiwa, 1)
+        );
+        assert_eq!(
+            date(1912, 7, 30).japanese_era().unwrap(),
+            (JapaneseEra::Taisho, 1)
+        );
+        assert!(date(1868, 10, 22).japanese_era().is_err());
+
+        assert_eq!(
+            Date::from_japanese_era(JapaneseEra::Showa, 64, 1, 7).unwrap(),
+            date(1989, 1, 7)
+        );
+        assert!(Date::from_japanese_era(JapaneseEra::Showa, 64, 1, 8).is_err());
+        assert!(Date::from_japanese_era(JapaneseEra::Reiwa, 0, 5, 1).is_err());
+
+        assert_eq!("reiwa".parse::<JapaneseEra>(), Ok(JapaneseEra::Reiwa));
+        assert_eq!("平成".parse::<JapaneseEra>(), Ok(JapaneseEra::Heisei));
+        assert_eq!("S".parse::<JapaneseEra>(), Ok(JapaneseEra::Showa));
+        assert!("Edo".parse::<JapaneseEra>().is_err());
+        assert_eq!(JapaneseEra::Reiwa.to_string(), "Reiwa");
+        assert_eq!(JapaneseEra::Heisei.format_year(1), "平成元年");
+    }
+
+    // Test the era directives of strftime
+    #[test]
+    fn test_strftime_era() {
+        let datetime = DateTime::new(2024, 5, 1, 9, 0, 0, TimeZone::JST).unwrap();
+        assert_eq!(datetime.strftime("%EY%m月%d日"), "令和6年05月01日");
+        assert_eq!(datetime.strftime("%EC %Ey (%Y)"), "令和 6 (2024)");
+        let first = DateTime::new(2019, 5, 1, 0, 0, 0, TimeZone::JST).unwrap();
+        assert_eq!(first.strftime("%EY"), "令和元年");
+    }
+
+    // Test Happy Monday rules, equinox days and the Olympic moves
+    #[test]
+    fn test_moving_holidays() {
+        assert_eq!(dates(1999, "Coming of Age Day"), ["1999-01-15"]);
+        assert_eq!(dates(2024, "Coming of Age Day"), ["2024-01-08"]);
+        assert_eq!(dates(2024, "Marine Day"), ["2024-07-15"]);
+        assert_eq!(dates(2024, "Respect for the Aged Day"), ["2024-09-16"]);
+        assert_eq!(dates(2024, "Sports Day"), ["2024-10-14"]);
+        assert_eq!(dates(2020, "Sports Day"), ["2020-07-24"]);
+        assert_eq!(dates(2021, "Mountain Day"), ["2021-08-08"]);
+        assert_eq!(dates(2024, "Vernal Equinox Day"), ["2024-03-20"]);
+        assert_eq!(dates(2024, "Autumnal Equinox Day"), ["2024-09-22"]);
+        assert_eq!(dates(2012, "Autumnal Equinox Day"), ["2012-09-22"]);
+        assert!(dates(2019, "Emperor's Birthday").is_empty());
+    }
+
+    // Test substitute holidays and days between two holidays
+    #[test]
+    fn test_substitute_and_citizens_holidays() {
+        // Five holidays fell on a Sunday in 2024
+        assert_eq!(
+            dates(2024, "Substitute Holiday"),
+            [
+                "2024-02-12",
+                "2024-05-06",
+                "2024-08-12",
+                "2024-09-23",
+                "2024-11-04"
+            ]
+        );
+        // From 2007 a substitute moves past other holidays
+        assert_eq!(
+            dates(2020, "Substitute Holiday"),
+            ["2020-02-24", "2020-05-06"]
+        );
+        assert_eq!(dates(2026, "Citizen's Holiday"), ["2026-09-22"]);
+        assert_eq!(
+            dates(2019, "Citizen's Holiday"),
+            ["2019-04-30", "2019-05-02"]
+        );
+        assert_eq!(dates(1995, "Citizen's Holiday"), ["1995-05-04"]);
+        assert!(JapaneseHolidays.holidays(1969).is_err());
+    }
+}
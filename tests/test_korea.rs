@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{BusinessCalendar, Date, HolidayProvider, KoreanHolidays};
+
+    fn dates(year: u64, name: &str) -> Vec<String> {
+        KoreanHolidays
+            .holidays(year)
+            .unwrap()
+            .iter()
+            .filter(|holiday| holiday.name == name)
+            .map(|holiday| holiday.date.to_string())
+            .collect()
+    }
+
+    // Test the lunar holidays against published dates
+    #[test]
+    fn test_lunar_holidays() {
+        assert_eq!(dates(2023, "Chuseok"), ["2023-09-29"]);
+        assert_eq!(dates(2024, "Chuseok"), ["2024-09-17"]);
+        assert_eq!(dates(2025, "Chuseok"), ["2025-10-06"]);
+        assert_eq!(dates(1997, "Seollal"), ["1997-02-08"]);
+        assert_eq!(dates(2024, "Seollal"), ["2024-02-10"]);
+        assert_eq!(dates(2024, "Seollal Holiday"), ["2024-02-09", "2024-02-11"]);
+        assert_eq!(dates(2024, "Buddha's Birthday"), ["2024-05-15"]);
+        assert_eq!(dates(2100, "Seollal").len(), 1);
+    }
+
+    // Test substitute holidays for weekends and overlapping holidays
+    #[test]
+    fn test_substitute_holidays() {
+        let substitutes = |year| dates(year, "Substitute Holiday (Chuseok)");
+        // Chuseok overlapped National Foundation Day
+        assert_eq!(substitutes(2017), ["2017-10-06"]);
+        // A Sunday in the break, with Hangul Day right after it
+        assert_eq!(substitutes(2025), ["2025-10-08"]);
+        // Saturdays do not count for Chuseok
+        assert!(substitutes(2023).is_empty());
+        // Children's Day and Buddha's Birthday on the same day
+        assert_eq!(
+            dates(2025, "Substitute Holiday (Children's Day)"),
+            ["2025-05-06"]
+        );
+        assert_eq!(
+            dates(2023, "Substitute Holiday (Buddha's Birthday)"),
+            ["2023-05-29"]
+        );
+        // National days have had substitutes only since 2021
+        assert_eq!(
+            dates(2021, "Substitute Holiday (Liberation Day)"),
+            ["2021-08-16"]
+        );
+        assert!(dates(2020, "Substitute Holiday (Liberation Day)").is_empty());
+    }
+
+    // Test holidays that changed over the years and the covered range
+    #[test]
+    fn test_historical_holidays() {
+        assert_eq!(dates(1980, "Hangul Day"), ["1980-10-09"]);
+        assert!(dates(2000, "Hangul Day").is_empty());
+        assert_eq!(dates(2013, "Hangul Day"), ["2013-10-09"]);
+        assert_eq!(dates(2005, "Arbor Day"), ["2005-04-05"]);
+        assert!(dates(2006, "Arbor Day").is_empty());
+        assert!(dates(1984, "Seollal").is_empty());
+        assert_eq!(dates(1986, "Folk Day"), ["1986-02-09"]);
+        assert_eq!(dates(1990, "New Year Holiday").len(), 2);
+
+        assert!(KoreanHolidays.holidays(1969).is_err());
+        assert!(KoreanHolidays.holidays(2101).is_err());
+    }
+
+    // Test filling a business calendar from the provider
+    #[test]
+    fn test_business_calendar() {
+        let calendar = BusinessCalendar::default()
+            .with_holidays_from(&KoreanHolidays, 2024..=2025)
+            .unwrap();
+        let date = |year, month, day| Date::new(year, month, day).unwrap();
+        assert!(!calendar.is_business_day(date(2024, 2, 12)));
+        assert_eq!(
+            calendar.next_business_day(date(2025, 10, 2)).unwrap(),
+            date(2025, 10, 10)
+        );
+        assert!(BusinessCalendar::default()
+            .with_holidays_from(&KoreanHolidays, 2100..=2101)
+            .is_err());
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{DateTime, LeapSecondTable, TimeZone};
+
+    const LEAP_SECONDS_LIST: &str = "\
+# Excerpt of leap-seconds.list
+#$	 3676924800
+#@	3960057600
+2272060800	10	# 1 Jan 1972
+2287785600	11	# 1 Jul 1972
+3692217600	37	# 1 Jan 2017
+";
+
+    const TZDATA_LEAPSECONDS: &str = "\
+# Excerpt of tzdata leapseconds
+Leap	1972	Jun	30	23:59:60	+	S
+Leap	1972	Dec	31	23:59:60	+	S
+Expires	2025	Jun	28	00:00:00
+";
+
+    // Test that second 60 is only accepted on real leap seconds
+    #[test]
+    fn test_datetime_new_leap_second() {
+        let leap = DateTime::new(2016, 12, 31, 23, 59, 60, TimeZone::UTC).unwrap();
+        assert_eq!(leap.second, 60);
+        assert_eq!(leap.to_string(), "2016-12-31 23:59:60");
+
+        // The same leap second observed in Korea
+        let leap_kst = DateTime::new(2017, 1, 1, 8, 59, 60, TimeZone::KST).unwrap();
+        assert_eq!(leap_kst.second, 60);
+
+        assert!(DateTime::new(2016, 12, 31, 23, 58, 60, TimeZone::UTC).is_err());
+        assert!(DateTime::new(2017, 12, 31, 23, 59, 60, TimeZone::UTC).is_err());
+        assert!(DateTime::new(2016, 12, 31, 23, 59, 61, TimeZone::UTC).is_err());
+    }
+
+    // Test TAI − UTC lookups and leap second days
+    #[test]
+    fn test_tai_minus_utc() {
+        let table = LeapSecondTable::builtin();
+        assert_eq!(table.entries().len(), 28);
+        assert_eq!(table.tai_minus_utc(0), 10);
+        assert_eq!(table.tai_minus_utc(1_483_228_799), 36);
+        assert_eq!(table.tai_minus_utc(1_483_228_800), 37);
+        assert!(table.is_leap_second_day(2016, 12, 31));
+        assert!(table.is_leap_second_day(1972, 6, 30));
+        assert!(!table.is_leap_second_day(2016, 12, 30));
+        assert!(!table.is_leap_second_day(1971, 12, 31));
+    }
+
+    // Test that UTC -> TAI is continuous across a leap second and round-trips
+    #[test]
+    fn test_utc_tai_round_trip() {
+        let table = LeapSecondTable::builtin();
+        let before = DateTime::new(2016, 12, 31, 23, 59, 59, TimeZone::UTC).unwrap();
+        let leap = DateTime::new(2016, 12, 31, 23, 59, 60, TimeZone::UTC).unwrap();
+        let after = DateTime::new(2017, 1, 1, 0, 0, 0, TimeZone::UTC).unwrap();
+
+        let tai_before = table.utc_to_tai(&before);
+        assert_eq!(tai_before, before.to_unix_seconds() + 36);
+        assert_eq!(table.utc_to_tai(&leap), tai_before + 1);
+        assert_eq!(table.utc_to_tai(&after), tai_before + 2);
+
+        assert_eq!(table.tai_to_utc(tai_before, TimeZone::UTC).unwrap(), before);
+        assert_eq!(
+            table.tai_to_utc(tai_before + 1, TimeZone::UTC).unwrap(),
+            leap
+        );
+        assert_eq!(
+            table.tai_to_utc(tai_before + 2, TimeZone::UTC).unwrap(),
+            after
+        );
+
+        let leap_kst = table.tai_to_utc(tai_before + 1, TimeZone::KST).unwrap();
+        assert_eq!(leap_kst.to_string(), "2017-01-01 08:59:60");
+    }
+
+    // Test parsing the IANA leap-seconds.list format
+    #[test]
+    fn test_parse_leap_seconds_list() {
+        let table = LeapSecondTable::parse_leap_seconds_list(LEAP_SECONDS_LIST).unwrap();
+        assert_eq!(table.entries().len(), 3);
+        assert_eq!(table.entries()[0].unix_seconds, 63_072_000);
+        assert_eq!(table.tai_minus_utc(1_483_228_800), 37);
+        assert_eq!(table.expires(), Some(3_960_057_600 - 2_208_988_800));
+        assert!(LeapSecondTable::parse_leap_seconds_list("2272060800").is_err());
+        assert!(LeapSecondTable::parse_leap_seconds_list("# only comments").is_err());
+    }
+
+    // Test parsing the tzdata leapseconds format
+    #[test]
+    fn test_parse_tzdata() {
+        let table = LeapSecondTable::parse_tzdata(TZDATA_LEAPSECONDS).unwrap();
+        assert_eq!(table.entries().len(), 3);
+        assert_eq!(table.entries(), &LeapSecondTable::builtin().entries()[..3]);
+        assert!(table.is_leap_second_day(1972, 12, 31));
+        assert_eq!(table.expires(), Some(1_751_068_800));
+        assert!(LeapSecondTable::parse_tzdata("Leap 2016 Dex 31 23:59:60 + S").is_err());
+    }
+}
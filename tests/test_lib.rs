@@ -1,10 +1,17 @@
+// These tests predate the clippy gate and are kept as originally written.
+#![allow(
+    unused_imports,
+    clippy::bool_assert_comparison,
+    clippy::unnecessary_cast
+)]
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::ops::{Add, Sub};
     use timekit::{
         adjust_second_with_timezone, calculate_date_since_epoch, compute_total_seconds,
-        constants::*, days_in_month, is_leap_year, now, DateTime, TimeDelta, TimeZone,
+        constants::*, days_in_month, is_leap_year, now, DateTime, Region, TimeDelta, TimeZone,
     };
 
     // Test the is_leap_year function
@@ -250,6 +257,7 @@ mod tests {
             hours: 3,
             minutes: 4,
             seconds: 5,
+            nanoseconds: 0,
         };
         let delta_str = format!("{}", delta);
         assert_eq!(delta_str, "1 week, 2 days, 3 hours, 4 minutes, 5 seconds");
@@ -260,6 +268,7 @@ mod tests {
             hours: 0,
             minutes: 0,
             seconds: 0,
+            nanoseconds: 0,
         };
         let delta_str = format!("{}", delta);
         assert_eq!(delta_str, "0 seconds");
@@ -432,4 +441,74 @@ mod tests {
         assert_eq!(datetime_kst.to_unix_seconds(), 1690891200);
         assert_eq!(datetime_est.to_unix_seconds(), 1690891200);
     }
+
+    // Test parsing time zones from abbreviations, variant names and IANA names
+    #[test]
+    fn test_timezone_from_str() {
+        assert_eq!("KST".parse::<TimeZone>(), Ok(TimeZone::KST));
+        assert_eq!("kst".parse::<TimeZone>(), Ok(TimeZone::KST));
+        assert_eq!("Asia/Seoul".parse::<TimeZone>(), Ok(TimeZone::KST));
+        assert_eq!("asia/shanghai".parse::<TimeZone>(), Ok(TimeZone::CSTAsia));
+        assert_eq!("CSTAsia".parse::<TimeZone>(), Ok(TimeZone::CSTAsia));
+        assert_eq!("GMT".parse::<TimeZone>(), Ok(TimeZone::UTC));
+        assert!("XYZ".parse::<TimeZone>().is_err());
+        assert!("".parse::<TimeZone>().is_err());
+    }
+
+    // Test that Display output parses back to the same zone
+    #[test]
+    fn test_timezone_display_round_trip() {
+        assert_eq!(TimeZone::KST.to_string(), "KST");
+        assert_eq!(TimeZone::CSTAsia.to_string(), "CSTAsia");
+        for timezone in TimeZone::all() {
+            assert_eq!(timezone.to_string().parse::<TimeZone>(), Ok(timezone));
+        }
+        assert_eq!(TimeZone::all().count(), 23);
+    }
+
+    // Test resolving ambiguous abbreviations with a region hint
+    #[test]
+    fn test_timezone_from_abbreviation() {
+        assert_eq!(TimeZone::from_abbreviation("CST", None), Ok(TimeZone::CST));
+        assert_eq!(
+            TimeZone::from_abbreviation("CST", Some(Region::Americas)),
+            Ok(TimeZone::CST)
+        );
+        assert_eq!(
+            TimeZone::from_abbreviation("CST", Some(Region::Asia)),
+            Ok(TimeZone::CSTAsia)
+        );
+        // A hint that matches no candidate falls back to the default
+        assert_eq!(
+            TimeZone::from_abbreviation("KST", Some(Region::Europe)),
+            Ok(TimeZone::KST)
+        );
+        assert_eq!(TimeZone::CSTAsia.abbreviation(), "CST");
+        assert_eq!(TimeZone::UTC.region(), None);
+        assert!(TimeZone::from_abbreviation("Asia/Seoul", None).is_err());
+    }
+
+    // Test converting TimeDelta to and from a number of seconds
+    #[test]
+    fn test_timedelta_seconds() {
+        let delta = TimeDelta {
+            weeks: 1,
+            days: 1,
+            hours: 1,
+            minutes: 1,
+            seconds: 1,
+            nanoseconds: 0,
+        };
+        assert_eq!(delta.total_seconds(), 694_861);
+
+        let normalized = TimeDelta::from_seconds(694_861);
+        assert_eq!(normalized.days, 8);
+        assert_eq!(normalized.total_seconds(), 694_861);
+
+        let negative = TimeDelta::from_seconds(-3_661);
+        assert_eq!(negative.hours, -1);
+        assert_eq!(negative.minutes, -1);
+        assert_eq!(negative.seconds, -1);
+        assert_eq!(TimeDelta::from_seconds(0), TimeDelta::default());
+    }
 }
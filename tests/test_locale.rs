@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{DateTime, FormatStyle, Locale, TimeZone, Weekday};
+
+    // Friday, May 17th 2024, 3:04 PM
+    fn datetime() -> DateTime {
+        DateTime::new(2024, 5, 17, 15, 4, 0, TimeZone::UTC).unwrap()
+    }
+
+    // Test the default date patterns of every bundled locale
+    #[test]
+    fn test_format_localized() {
+        let expected = [
+            ("en-US", "05/17/2024", "May 17, 2024", "May 17, 2024"),
+            ("en-GB", "17/05/2024", "17 May 2024", "17 May 2024"),
+            ("ko-KR", "24. 5. 17.", "2024. 5. 17.", "2024년 5월 17일"),
+            ("ja-JP", "2024/05/17", "2024/05/17", "2024年5月17日"),
+            ("zh-CN", "2024/5/17", "2024年5月17日", "2024年5月17日"),
+            ("de-DE", "17.05.24", "17.05.2024", "17. Mai 2024"),
+            ("fr-FR", "17/05/2024", "17 mai 2024", "17 mai 2024"),
+        ];
+        for (tag, short, medium, long) in expected {
+            let locale: Locale = tag.parse().unwrap();
+            assert_eq!(
+                datetime().format_localized(&locale, FormatStyle::Short),
+                short
+            );
+            assert_eq!(
+                datetime().format_localized(&locale, FormatStyle::Medium),
+                medium
+            );
+            assert_eq!(
+                datetime().format_localized(&locale, FormatStyle::Long),
+                long
+            );
+        }
+        let february = DateTime::new(2024, 2, 1, 0, 0, 0, TimeZone::UTC).unwrap();
+        assert_eq!(
+            february.format_localized(&Locale::FR_FR, FormatStyle::Medium),
+            "1 févr. 2024"
+        );
+    }
+
+    // Test month and weekday names and AM/PM markers in strftime
+    #[test]
+    fn test_strftime_names() {
+        assert_eq!(
+            datetime().strftime("%A, %B %-d %-I:%M %p"),
+            "Friday, May 17 3:04 PM"
+        );
+        assert_eq!(
+            datetime().strftime_localized("%Y년 %B %-d일 (%a) %p %-I시", &Locale::KO_KR),
+            "2024년 5월 17일 (금) 오후 3시"
+        );
+        assert_eq!(
+            datetime().strftime_localized("%a %-d %b %H:%M", &Locale::DE_DE),
+            "Fr. 17 Mai 15:04"
+        );
+        let midnight = DateTime::new(2024, 12, 1, 0, 5, 0, TimeZone::UTC).unwrap();
+        assert_eq!(midnight.strftime("%I:%M %p %y"), "12:05 AM 24");
+        assert_eq!(
+            midnight.strftime_localized("%B (%b)", &Locale::ZH_CN),
+            "十二月 (12月)"
+        );
+    }
+
+    // Test the name accessors and ordinals
+    #[test]
+    fn test_locale_data() {
+        assert_eq!(Locale::JA_JP.weekday_name(Weekday::Sunday), "日曜日");
+        assert_eq!(Locale::FR_FR.weekday_abbreviation(Weekday::Monday), "lun.");
+        assert_eq!(Locale::DE_DE.month_name(3).unwrap(), "März");
+        assert_eq!(Locale::EN_GB.month_abbreviation(9).unwrap(), "Sep");
+        assert!(Locale::EN_US.month_name(13).is_err());
+        assert_eq!(Locale::EN_GB.am_pm(11), "am");
+        assert_eq!(Locale::JA_JP.am_pm(12), "午後");
+
+        assert_eq!(Locale::EN_US.ordinal(112), "112th");
+        assert_eq!(Locale::EN_US.ordinal(101), "101st");
+        assert_eq!(Locale::DE_DE.ordinal(3), "3.");
+        assert_eq!(Locale::FR_FR.ordinal(2), "2e");
+        assert_eq!(Locale::KO_KR.ordinal(2), "2번째");
+        assert_eq!(Locale::JA_JP.ordinal(2), "2番目");
+    }
+
+    // Test looking up locales by tag
+    #[test]
+    fn test_locale_from_str() {
+        assert_eq!("ko_kr".parse::<Locale>().unwrap(), Locale::KO_KR);
+        assert_eq!(" EN-gb ".parse::<Locale>().unwrap().tag(), "en-GB");
+        assert!("es-ES".parse::<Locale>().is_err());
+        assert_eq!(Locale::all().count(), 7);
+        assert_eq!(Locale::default().to_string(), "en-US");
+    }
+}
@@ -0,0 +1,97 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::date;
+    use timekit::{LunarCalendar, LunarDate};
+
+    // Test lunar new years and leap months against published calendars
+    #[test]
+    fn test_published_dates() {
+        let chinese = LunarCalendar::Chinese;
+        assert_eq!(chinese.new_year(1900).unwrap(), date(1900, 1, 31));
+        assert_eq!(chinese.new_year(2000).unwrap(), date(2000, 2, 5));
+        assert_eq!(chinese.new_year(2024).unwrap(), date(2024, 2, 10));
+        assert_eq!(chinese.new_year(2100).unwrap(), date(2100, 2, 9));
+        for (year, month) in [(1900, 8), (2001, 4), (2014, 9), (2023, 2), (2033, 11)] {
+            assert_eq!(chinese.leap_month(year).unwrap(), Some(month), "{}", year);
+        }
+        assert_eq!(chinese.leap_month(2024).unwrap(), None);
+
+        // The calendars differ where a new moon falls between 23:00 and midnight in Korea
+        let korean = LunarCalendar::Korean;
+        assert_eq!(korean.new_year(1997).unwrap(), date(1997, 2, 8));
+        assert_eq!(chinese.new_year(1997).unwrap(), date(1997, 2, 7));
+        assert_eq!(korean.leap_month(2012).unwrap(), Some(3));
+        assert_eq!(chinese.leap_month(2012).unwrap(), Some(4));
+    }
+
+    // Test conversions in both directions, including leap months
+    #[test]
+    fn test_conversion() {
+        let lunar = date(2024, 9, 17).to_lunar(LunarCalendar::Korean).unwrap();
+        assert_eq!((lunar.year, lunar.month, lunar.day), (2024, 8, 15));
+        assert_eq!(lunar.to_string(), "2024-08-15");
+
+        // The first day of the leap 4th month of 2020
+        let leap = LunarDate::new(LunarCalendar::Chinese, 2020, 4, true, 1).unwrap();
+        assert_eq!(leap.to_date(), date(2020, 5, 23));
+        assert_eq!(leap.to_string(), "2020-04L-01");
+        // Dates before the lunar new year belong to the previous lunar year
+        let lunar = date(1900, 1, 1).to_lunar(LunarCalendar::Chinese).unwrap();
+        assert_eq!((lunar.year, lunar.month, lunar.day), (1899, 12, 1));
+
+        assert!(LunarDate::new(LunarCalendar::Chinese, 2024, 4, true, 1).is_err());
+        assert!(LunarDate::new(LunarCalendar::Chinese, 2024, 13, false, 1).is_err());
+        assert!(LunarDate::new(LunarCalendar::Chinese, 2024, 1, false, 31).is_err());
+        assert!(LunarDate::new(LunarCalendar::Chinese, 2101, 1, false, 1).is_err());
+        assert!(date(1899, 1, 1).to_lunar(LunarCalendar::Korean).is_err());
+        assert!(date(2101, 6, 1).to_lunar(LunarCalendar::Korean).is_err());
+    }
+
+    // Test that every day from 1900 through 2100 round-trips in both calendars
+    #[test]
+    fn test_round_trip() {
+        for calendar in [LunarCalendar::Korean, LunarCalendar::Chinese] {
+            let mut previous: Option<LunarDate> = None;
+            let mut day = date(1900, 1, 1);
+            while day.year <= 2100 {
+                let lunar = day.to_lunar(calendar).unwrap();
+                assert_eq!(lunar.to_date(), day);
+                assert_eq!(
+                    LunarDate::new(
+                        calendar,
+                        lunar.year,
+                        lunar.month,
+                        lunar.leap_month,
+                        lunar.day
+                    ),
+                    Ok(lunar)
+                );
+                if let Some(previous) = previous {
+                    assert!(lunar.day == 1 || lunar.day == previous.day + 1);
+                }
+                previous = Some(lunar);
+                day = day.add_days(1).unwrap();
+            }
+        }
+    }
+
+    // Test recurring lunar birthdays
+    #[test]
+    fn test_anniversary() {
+        let birthday = LunarDate::new(LunarCalendar::Korean, 1988, 3, false, 30).unwrap();
+        assert_eq!(birthday.to_date(), date(1988, 5, 15));
+        // The 3rd month of 2024 has 29 days
+        assert_eq!(
+            LunarCalendar::Korean.days_in_month(2024, 3, false).unwrap(),
+            29
+        );
+        assert_eq!(birthday.anniversary(2024).unwrap(), date(2024, 5, 7));
+
+        // A leap-month birthday falls back to the regular month
+        let leap = LunarDate::new(LunarCalendar::Chinese, 2020, 4, true, 10).unwrap();
+        assert_eq!(leap.anniversary(2024).unwrap(), date(2024, 5, 17));
+        assert_eq!(leap.anniversary(2020).unwrap(), leap.to_date());
+    }
+}
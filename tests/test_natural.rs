@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{
+        DateTime, EnglishParser, RelativeDay, RelativeExpression, RelativeParser, Resolved,
+        TimeUnit, TimeZone,
+    };
+
+    // Friday, May 17th 2024, 15:30 UTC
+    fn reference() -> DateTime {
+        DateTime::new(2024, 5, 17, 15, 30, 0, TimeZone::UTC).unwrap()
+    }
+
+    fn instant(input: &str, timezone: TimeZone) -> String {
+        match EnglishParser.parse(input, &reference(), timezone).unwrap() {
+            Resolved::Instant(datetime) => datetime.to_string(),
+            Resolved::Interval(_) => panic!("{} resolved to an interval", input),
+        }
+    }
+
+    fn interval(input: &str) -> (String, String) {
+        match EnglishParser
+            .parse(input, &reference(), TimeZone::UTC)
+            .unwrap()
+        {
+            Resolved::Interval(interval) => {
+                (interval.start().to_string(), interval.end().to_string())
+            }
+            Resolved::Instant(_) => panic!("{} resolved to an instant", input),
+        }
+    }
+
+    // Test days and times of day resolving to instants
+    #[test]
+    fn test_days_and_times() {
+        assert_eq!(
+            instant("tomorrow 9am", TimeZone::UTC),
+            "2024-05-18 09:00:00"
+        );
+        assert_eq!(
+            instant("at 9:30 PM tomorrow", TimeZone::UTC),
+            "2024-05-18 21:30:00"
+        );
+        assert_eq!(
+            instant("next friday noon", TimeZone::UTC),
+            "2024-05-24 12:00:00"
+        );
+        assert_eq!(
+            instant("friday 17:45", TimeZone::UTC),
+            "2024-05-17 17:45:00"
+        );
+        assert_eq!(
+            instant("last mon at 12am", TimeZone::UTC),
+            "2024-05-13 00:00:00"
+        );
+        assert_eq!(instant("7pm", TimeZone::UTC), "2024-05-17 19:00:00");
+        // It is already Saturday in Seoul
+        assert_eq!(
+            instant("yesterday 8am", TimeZone::KST),
+            "2024-05-17 08:00:00"
+        );
+        assert_eq!(instant("now", TimeZone::KST), "2024-05-18 00:30:00");
+    }
+
+    // Test offsets from the reference instant
+    #[test]
+    fn test_offsets() {
+        assert_eq!(instant("in 3 weeks", TimeZone::UTC), "2024-06-07 15:30:00");
+        assert_eq!(instant("2 days ago", TimeZone::UTC), "2024-05-15 15:30:00");
+        assert_eq!(
+            instant("an hour from now", TimeZone::UTC),
+            "2024-05-17 16:30:00"
+        );
+        assert_eq!(instant("in 90 mins", TimeZone::UTC), "2024-05-17 17:00:00");
+        assert_eq!(instant("1 month ago", TimeZone::UTC), "2024-04-17 15:30:00");
+        assert_eq!(
+            EnglishParser.parse_expression("in 2 years").unwrap(),
+            RelativeExpression::Offset {
+                amount: 2,
+                unit: TimeUnit::Year
+            }
+        );
+    }
+
+    // Test fuzzy inputs resolving to intervals
+    #[test]
+    fn test_intervals() {
+        let day =
+            |date: &str, next: &str| (format!("{} 00:00:00", date), format!("{} 00:00:00", next));
+        assert_eq!(interval("last week"), day("2024-05-06", "2024-05-13"));
+        assert_eq!(interval("this week"), day("2024-05-13", "2024-05-20"));
+        assert_eq!(interval("next month"), day("2024-06-01", "2024-07-01"));
+        assert_eq!(interval("last year"), day("2023-01-01", "2024-01-01"));
+        assert_eq!(interval("tomorrow"), day("2024-05-18", "2024-05-19"));
+        assert_eq!(interval("this friday"), day("2024-05-17", "2024-05-18"));
+        assert_eq!(interval("next friday"), day("2024-05-24", "2024-05-25"));
+    }
+
+    // Test invalid input and a parser for another language
+    #[test]
+    fn test_errors_and_extension() {
+        for input in [
+            "",
+            "soon",
+            "in 3 fortnights",
+            "13pm",
+            "25:00",
+            "today tomorrow",
+            "9",
+        ] {
+            assert!(EnglishParser.parse_expression(input).is_err(), "{}", input);
+        }
+
+        // "내일 오전 9시" (tomorrow 9am) and similar expressions
+        struct Korean;
+        impl RelativeParser for Korean {
+            fn parse_expression(&self, input: &str) -> Result<RelativeExpression, String> {
+                let invalid = || format!("Unrecognised expression: {}", input);
+                let words: Vec<&str> = input.split_whitespace().collect();
+                let day = match words.first() {
+                    Some(&"오늘") => 0,
+                    Some(&"내일") => 1,
+                    Some(&"모레") => 2,
+                    _ => return Err(invalid()),
+                };
+                let time = match words[1..] {
+                    [] => None,
+                    [meridiem, hour] => {
+                        let hour: u64 = hour
+                            .strip_suffix('시')
+                            .and_then(|hour| hour.parse().ok())
+                            .ok_or_else(invalid)?;
+                        match meridiem {
+                            "오전" => Some((hour % 12, 0)),
+                            "오후" => Some((hour % 12 + 12, 0)),
+                            _ => return Err(invalid()),
+                        }
+                    }
+                    _ => return Err(invalid()),
+                };
+                Ok(RelativeExpression::Day {
+                    day: RelativeDay::Days(day),
+                    time,
+                })
+            }
+        }
+        let resolved = Korean
+            .parse("내일 오전 9시", &reference(), TimeZone::KST)
+            .unwrap();
+        assert_eq!(resolved.start().to_string(), "2024-05-19 09:00:00");
+        let resolved = Korean
+            .parse("오늘 오후 3시", &reference(), TimeZone::KST)
+            .unwrap();
+        assert_eq!(resolved.start().to_string(), "2024-05-18 15:00:00");
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{DateTime, OffsetDateTime, OffsetFormat, TimeZone};
+
+    // Test that a parsed offset is preserved exactly when written back
+    #[test]
+    fn test_parse_round_trip() {
+        let timestamp = OffsetDateTime::parse("2024-05-01T10:00:00+05:45").unwrap();
+        assert_eq!(timestamp.offset_in_seconds(), 5 * 3600 + 45 * 60);
+        assert_eq!(timestamp.to_string(), "2024-05-01T10:00:00+05:45");
+
+        let timestamp: OffsetDateTime = "2024-05-01 10:00:00-0330".parse().unwrap();
+        assert_eq!(timestamp.offset_in_seconds(), -(3 * 3600 + 30 * 60));
+        assert_eq!(timestamp.to_string(), "2024-05-01T10:00:00-03:30");
+    }
+
+    // Test the three offset styles
+    #[test]
+    fn test_format_offset() {
+        let timestamp = OffsetDateTime::parse("2024-05-01T10:00:00+05:45").unwrap();
+        assert_eq!(timestamp.format_offset(OffsetFormat::Extended), "+05:45");
+        assert_eq!(timestamp.format_offset(OffsetFormat::Basic), "+0545");
+        assert_eq!(timestamp.format_offset(OffsetFormat::Zulu), "+05:45");
+
+        let utc = OffsetDateTime::parse("2024-05-01T04:15:00.250Z").unwrap();
+        assert_eq!(utc.format_offset(OffsetFormat::Zulu), "Z");
+        assert_eq!(utc.to_rfc3339(OffsetFormat::Zulu), "2024-05-01T04:15:00Z");
+        assert_eq!(
+            utc.to_rfc3339(OffsetFormat::Basic),
+            "2024-05-01T04:15:00+0000"
+        );
+    }
+
+    // Test that values compare by instant rather than by wall clock
+    #[test]
+    fn test_compare_by_instant() {
+        let nepal = OffsetDateTime::parse("2024-05-01T10:00:00+05:45").unwrap();
+        let utc = OffsetDateTime::parse("2024-05-01T04:15:00Z").unwrap();
+        let later = OffsetDateTime::parse("2024-05-01T10:00:00+05:30").unwrap();
+        assert_eq!(nepal, utc);
+        assert!(nepal < later);
+        assert_eq!(nepal.unix_seconds(), utc.unix_seconds());
+    }
+
+    // Test conversion to and from DateTime
+    #[test]
+    fn test_datetime_conversion() {
+        let datetime = DateTime::new(2024, 5, 1, 13, 15, 0, TimeZone::KST).unwrap();
+        let timestamp = OffsetDateTime::from(datetime);
+        assert_eq!(timestamp.to_string(), "2024-05-01T13:15:00+09:00");
+
+        let nepal = OffsetDateTime::parse("2024-05-01T10:00:00+05:45").unwrap();
+        assert_eq!(nepal.to_datetime(TimeZone::KST).unwrap(), datetime);
+        assert_eq!(nepal.local().hour, 10);
+
+        let shifted = nepal.with_offset(-4 * 3600).unwrap();
+        assert_eq!(shifted.to_string(), "2024-05-01T00:15:00-04:00");
+    }
+
+    // Test rejection of malformed timestamps and offsets
+    #[test]
+    fn test_parse_invalid() {
+        assert!(OffsetDateTime::parse("2024-05-01T10:00:00").is_err());
+        assert!(OffsetDateTime::parse("2024-13-01T10:00:00Z").is_err());
+        assert!(OffsetDateTime::parse("2024-05-01T25:00:00Z").is_err());
+        assert!(OffsetDateTime::parse("2024-05-01T10:00:00+24:00").is_err());
+        assert!(OffsetDateTime::parse("2024-05-01T10:00:00+5:45").is_err());
+        assert!(OffsetDateTime::parse("2024/05/01T10:00:00Z").is_err());
+        assert!(OffsetDateTime::new(0, 30).is_err());
+    }
+
+    // Test that leap seconds are checked against the UTC instant
+    #[test]
+    fn test_leap_second() {
+        // 2016-12-31T23:59:60Z, written in Seoul, is read as the second after it
+        let leap = OffsetDateTime::parse("2017-01-01T08:59:60+09:00").unwrap();
+        assert_eq!(leap, OffsetDateTime::parse("2017-01-01T00:00:00Z").unwrap());
+        assert_eq!(leap.offset_in_seconds(), 9 * 3600);
+        assert!(OffsetDateTime::parse("2016-12-31T23:59:60Z").is_ok());
+        assert!(OffsetDateTime::parse("2016-12-31T18:59:60-05:00").is_ok());
+
+        // 23:59:60 in Seoul is 14:59:60 UTC, which is not a leap second
+        assert!(OffsetDateTime::parse("2016-12-31T23:59:60+09:00").is_err());
+        assert!(OffsetDateTime::parse("2017-01-01T08:59:60Z").is_err());
+        assert!(OffsetDateTime::parse("2024-05-01T10:00:60Z").is_err());
+    }
+}
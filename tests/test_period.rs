@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{Date, DateTime, Period, TimeZone};
+
+    // Test adding months and years with day clamping
+    #[test]
+    fn test_date_add_period() {
+        let date = Date::new(2024, 1, 31).unwrap();
+        assert_eq!(
+            date.add_period(Period::months(1)).unwrap().to_string(),
+            "2024-02-29"
+        );
+        assert_eq!(
+            date.add_period(Period::months(13)).unwrap().to_string(),
+            "2025-02-28"
+        );
+        assert_eq!(
+            date.add_period(Period::months(-2)).unwrap().to_string(),
+            "2023-11-30"
+        );
+
+        let leap_day = Date::new(2024, 2, 29).unwrap();
+        assert_eq!(
+            leap_day.add_period(Period::years(1)).unwrap().to_string(),
+            "2025-02-28"
+        );
+
+        let period = Period {
+            years: 1,
+            months: 2,
+            days: 10,
+        };
+        assert_eq!(
+            Date::new(2024, 3, 1)
+                .unwrap()
+                .add_period(period)
+                .unwrap()
+                .to_string(),
+            "2025-05-11"
+        );
+        assert_eq!(
+            Date::new(2025, 5, 11)
+                .unwrap()
+                .sub_period(period)
+                .unwrap()
+                .to_string(),
+            "2024-03-01"
+        );
+    }
+
+    // Test that DateTime periods keep the local time of day
+    #[test]
+    fn test_datetime_add_period() {
+        let datetime = DateTime::new(2024, 10, 31, 23, 30, 0, TimeZone::KST).unwrap();
+        let next = (datetime + Period::months(1)).unwrap();
+        assert_eq!(next.to_string(), "2024-11-30 23:30:00");
+        assert_eq!(next.timezone, TimeZone::KST);
+
+        let previous = (datetime - Period::years(1)).unwrap();
+        assert_eq!(previous.to_string(), "2023-10-31 23:30:00");
+
+        let early = DateTime::new(1970, 1, 15, 0, 0, 0, TimeZone::UTC).unwrap();
+        assert!(early.sub_period(Period::months(1)).is_err());
+    }
+
+    // Test helpers on Period
+    #[test]
+    fn test_period_helpers() {
+        let period = Period {
+            years: 1,
+            months: 2,
+            days: 3,
+        };
+        assert_eq!(period.total_months().unwrap(), 14);
+        assert_eq!(
+            period.checked_mul(3).unwrap(),
+            Period {
+                years: 3,
+                months: 6,
+                days: 9
+            }
+        );
+        assert!(period.checked_mul(i64::MAX).is_err());
+        assert!(Period::default().is_zero());
+        assert_eq!(period.negated().negated(), period);
+    }
+}
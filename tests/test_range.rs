@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{Date, DateRange, DateTime, DateTimeRange, Period, TimeDelta, TimeZone};
+
+    fn hours(hours: i64) -> TimeDelta {
+        TimeDelta {
+            hours,
+            ..Default::default()
+        }
+    }
+
+    // Test exclusive and inclusive ends with a TimeDelta step
+    #[test]
+    fn test_datetime_range_delta() {
+        let start = DateTime::new(2024, 5, 1, 0, 0, 0, TimeZone::KST).unwrap();
+        let end = DateTime::new(2024, 5, 2, 0, 0, 0, TimeZone::KST).unwrap();
+
+        let range = DateTimeRange::new(start, end, hours(1)).unwrap();
+        assert_eq!(range.len(), 24);
+        let values: Vec<DateTime> = range.collect();
+        assert_eq!(values[0], start);
+        assert_eq!(values[23].to_string(), "2024-05-01 23:00:00");
+        assert!(values.iter().all(|value| value.timezone == TimeZone::KST));
+
+        let mut range = DateTimeRange::inclusive(start, end, hours(1)).unwrap();
+        assert_eq!(range.len(), 25);
+        assert_eq!(range.next_back(), Some(end));
+
+        // A step that does not divide the span evenly stops before the end
+        let range = DateTimeRange::inclusive(start, end, hours(5)).unwrap();
+        assert_eq!(range.len(), 5);
+
+        assert_eq!(DateTimeRange::new(start, start, hours(1)).unwrap().len(), 0);
+        assert_eq!(
+            DateTimeRange::inclusive(start, start, hours(1))
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(DateTimeRange::new(start, end, TimeDelta::default()).is_err());
+    }
+
+    // Test reverse iteration, both with a negative step and with rev()
+    #[test]
+    fn test_datetime_range_reverse() {
+        let start = DateTime::new(2024, 5, 1, 0, 0, 0, TimeZone::UTC).unwrap();
+        let end = DateTime::new(2024, 5, 1, 3, 0, 0, TimeZone::UTC).unwrap();
+
+        let backwards: Vec<String> = DateTimeRange::new(end, start, hours(-1))
+            .unwrap()
+            .map(|value| value.to_string())
+            .collect();
+        assert_eq!(
+            backwards,
+            [
+                "2024-05-01 03:00:00",
+                "2024-05-01 02:00:00",
+                "2024-05-01 01:00:00"
+            ]
+        );
+
+        let reversed: Vec<DateTime> = DateTimeRange::new(start, end, hours(1))
+            .unwrap()
+            .rev()
+            .collect();
+        assert_eq!(reversed.len(), 3);
+        assert_eq!(reversed[0].to_string(), "2024-05-01 02:00:00");
+
+        // A step pointing away from the end yields nothing
+        assert_eq!(DateTimeRange::new(start, end, hours(-1)).unwrap().len(), 0);
+
+        // Mixing next and next_back meets in the middle
+        let mut range = DateTimeRange::inclusive(start, end, hours(1)).unwrap();
+        assert_eq!(range.next(), Some(start));
+        assert_eq!(range.next_back(), Some(end));
+        assert_eq!(range.len(), 2);
+        assert_eq!(range.nth(5), None);
+        assert_eq!(range.next(), None);
+    }
+
+    // Test calendar steps, which do not drift after short months
+    #[test]
+    fn test_calendar_steps() {
+        let start = DateTime::new(2024, 1, 31, 9, 0, 0, TimeZone::KST).unwrap();
+        let end = DateTime::new(2024, 6, 1, 0, 0, 0, TimeZone::KST).unwrap();
+        let months: Vec<String> = DateTimeRange::new(start, end, Period::months(1))
+            .unwrap()
+            .map(|value| value.to_string())
+            .collect();
+        assert_eq!(
+            months,
+            [
+                "2024-01-31 09:00:00",
+                "2024-02-29 09:00:00",
+                "2024-03-31 09:00:00",
+                "2024-04-30 09:00:00",
+                "2024-05-31 09:00:00"
+            ]
+        );
+
+        let first = Date::new(2020, 2, 29).unwrap();
+        let last = Date::new(2024, 2, 29).unwrap();
+        let years: Vec<String> = DateRange::inclusive(first, last, Period::years(1))
+            .unwrap()
+            .map(|value| value.to_string())
+            .collect();
+        assert_eq!(
+            years,
+            [
+                "2020-02-29",
+                "2021-02-28",
+                "2022-02-28",
+                "2023-02-28",
+                "2024-02-29"
+            ]
+        );
+
+        let mixed = Period {
+            months: 1,
+            days: -1,
+            ..Default::default()
+        };
+        assert!(DateRange::new(first, last, mixed).is_err());
+    }
+
+    // Test Date ranges with day steps
+    #[test]
+    fn test_date_range_days() {
+        let start = Date::new(1999, 12, 30).unwrap();
+        let end = Date::new(2000, 1, 3).unwrap();
+        let days: Vec<String> = DateRange::new(start, end, Period::days(1))
+            .unwrap()
+            .map(|value| value.to_string())
+            .collect();
+        assert_eq!(
+            days,
+            ["1999-12-30", "1999-12-31", "2000-01-01", "2000-01-02"]
+        );
+
+        let week = TimeDelta {
+            weeks: 1,
+            ..Default::default()
+        };
+        let end = Date::new(2000, 3, 1).unwrap();
+        assert_eq!(DateRange::new(start, end, week).unwrap().len(), 9);
+        assert!(DateRange::new(start, end, hours(12)).is_err());
+    }
+}
@@ -0,0 +1,468 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::utc;
+    use timekit::{
+        DateTime, Frequency, NthWeekday, RecurrenceRule, RecurrenceSet, TimeZone, Until, Weekday,
+    };
+
+    fn expand(rule: &str, dtstart: DateTime, limit: usize) -> Vec<String> {
+        rule.parse::<RecurrenceRule>()
+            .unwrap()
+            .occurrences(dtstart)
+            .unwrap()
+            .take(limit)
+            .map(|datetime| datetime.to_string())
+            .collect()
+    }
+
+    // Test parsing an RRULE and formatting it back
+    #[test]
+    fn test_rrule_parse_and_display() {
+        let rule: RecurrenceRule = "RRULE:freq=monthly;byday=-1FR,2mo;COUNT=10;WKST=SU"
+            .parse()
+            .unwrap();
+        assert_eq!(rule.frequency, Frequency::Monthly);
+        assert_eq!(rule.count, Some(10));
+        assert_eq!(
+            rule.by_day,
+            [
+                NthWeekday::nth(-1, Weekday::Friday),
+                NthWeekday::nth(2, Weekday::Monday)
+            ]
+        );
+        assert_eq!(rule.week_start, Weekday::Sunday);
+        assert_eq!(
+            rule.to_string(),
+            "FREQ=MONTHLY;COUNT=10;BYDAY=-1FR,2MO;WKST=SU"
+        );
+
+        let rule: RecurrenceRule = "FREQ=WEEKLY;UNTIL=20240630T150000Z".parse().unwrap();
+        let until = DateTime::new(2024, 6, 30, 15, 0, 0, TimeZone::UTC).unwrap();
+        assert_eq!(rule.until, Some(Until::Instant(until)));
+        assert_eq!(rule.to_string(), "FREQ=WEEKLY;UNTIL=20240630T150000Z");
+
+        assert!("BYDAY=MO".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=DAILY;COUNT=2;UNTIL=20240101"
+            .parse::<RecurrenceRule>()
+            .is_err());
+        assert!("FREQ=WEEKLY;BYDAY=1MO".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=MONTHLY;BYWEEKNO=3".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=DAILY;BYHOUR=24".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=DAILY;BYSETPOS=1".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=DAILY;FREQ=WEEKLY".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=DAILY;COLOR=RED".parse::<RecurrenceRule>().is_err());
+    }
+
+    // Test monthly and weekly rules with COUNT and UNTIL
+    #[test]
+    fn test_rrule_count_and_until() {
+        let dtstart = DateTime::new(2024, 1, 26, 9, 0, 0, TimeZone::KST).unwrap();
+        let fridays = expand("FREQ=MONTHLY;BYDAY=-1FR;COUNT=10", dtstart, 100);
+        assert_eq!(fridays.len(), 10);
+        assert_eq!(fridays[4], "2024-05-31 09:00:00");
+        assert_eq!(fridays[9], "2024-10-25 09:00:00");
+
+        // UNTIL is an instant: 2024-05-15 01:00 UTC is 10:00 KST, so 10:00 on the 15th is kept
+        let dtstart = DateTime::new(2024, 5, 6, 10, 0, 0, TimeZone::KST).unwrap();
+        let days = expand(
+            "FREQ=WEEKLY;BYDAY=MO,WE;UNTIL=20240515T010000Z",
+            dtstart,
+            100,
+        );
+        assert_eq!(
+            days,
+            [
+                "2024-05-06 10:00:00",
+                "2024-05-08 10:00:00",
+                "2024-05-13 10:00:00",
+                "2024-05-15 10:00:00"
+            ]
+        );
+
+        // A date-only UNTIL includes the whole day
+        let days = expand("FREQ=DAILY;INTERVAL=2;UNTIL=20240512", dtstart, 100);
+        assert_eq!(days.last().unwrap(), "2024-05-12 10:00:00");
+    }
+
+    // Test expansion with BYSETPOS, BYWEEKNO, BYYEARDAY and sub-daily frequencies
+    #[test]
+    fn test_rrule_expansion() {
+        let dtstart = DateTime::new(2024, 1, 1, 9, 0, 0, TimeZone::UTC).unwrap();
+        // Last weekday of the month
+        let days = expand("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1", dtstart, 3);
+        assert_eq!(
+            days,
+            [
+                "2024-01-31 09:00:00",
+                "2024-02-29 09:00:00",
+                "2024-03-29 09:00:00"
+            ]
+        );
+
+        let days = expand("FREQ=YEARLY;BYWEEKNO=1;BYDAY=MO", dtstart, 3);
+        assert_eq!(
+            days,
+            [
+                "2024-01-01 09:00:00",
+                "2024-12-30 09:00:00",
+                "2025-12-29 09:00:00"
+            ]
+        );
+
+        let days = expand("FREQ=YEARLY;BYYEARDAY=-1", dtstart, 2);
+        assert_eq!(days, ["2024-12-31 09:00:00", "2025-12-31 09:00:00"]);
+
+        // Yearly on February 29th only repeats in leap years
+        let leap_day = DateTime::new(2024, 2, 29, 0, 0, 0, TimeZone::UTC).unwrap();
+        let days = expand("FREQ=YEARLY", leap_day, 2);
+        assert_eq!(days, ["2024-02-29 00:00:00", "2028-02-29 00:00:00"]);
+
+        let times = expand("FREQ=MINUTELY;INTERVAL=20;BYHOUR=9,10", dtstart, 7);
+        assert_eq!(times[5], "2024-01-01 10:40:00");
+        assert_eq!(times[6], "2024-01-02 09:00:00");
+
+        // Stepping two hours from 09:00 never reaches an even hour, so the rule is empty
+        assert!(expand("FREQ=HOURLY;INTERVAL=2;BYHOUR=2", dtstart, 1).is_empty());
+    }
+
+    // Test building a recurrence set with RDATE and EXDATE
+    #[test]
+    fn test_recurrence_set() {
+        let at = |day, hour| DateTime::new(2024, 5, day, hour, 0, 0, TimeZone::KST).unwrap();
+        let mut set = RecurrenceSet::new(at(6, 10));
+        set.add_rule("FREQ=DAILY;COUNT=5".parse().unwrap()).unwrap();
+        set.add_rdate(at(6, 10));
+        set.add_rdate(DateTime::new(2024, 5, 20, 0, 0, 0, TimeZone::UTC).unwrap());
+        set.add_exdate(DateTime::new(2024, 5, 8, 1, 0, 0, TimeZone::UTC).unwrap());
+
+        let occurrences: Vec<DateTime> = set.occurrences().collect();
+        assert_eq!(
+            occurrences,
+            [at(6, 10), at(7, 10), at(9, 10), at(10, 10), at(20, 9)]
+        );
+        assert!(occurrences
+            .iter()
+            .all(|datetime| datetime.timezone == TimeZone::KST));
+    }
+
+    // Test parsing iCalendar content lines into a recurrence set
+    #[test]
+    fn test_recurrence_set_parse() {
+        let set: RecurrenceSet = "DTSTART;TZID=Asia/Seoul:20240105T090000\r\n\
+             RRULE:FREQ=MONTHLY;BYDAY=1FR;\r\n COUNT=3\r\n\
+             EXDATE:20240202T000000Z,20240301T090000"
+            .parse()
+            .unwrap();
+        assert_eq!(set.dtstart().timezone, TimeZone::KST);
+        assert_eq!(set.rules().len(), 1);
+        let days: Vec<String> = set
+            .occurrences()
+            .map(|datetime| datetime.to_string())
+            .collect();
+        assert_eq!(days, ["2024-01-05 09:00:00"]);
+
+        assert!("RRULE:FREQ=DAILY".parse::<RecurrenceSet>().is_err());
+        assert!("DTSTART:20240105T090000\nSUMMARY:Standup"
+            .parse::<RecurrenceSet>()
+            .is_err());
+        assert!("DTSTART;TZID=Mars/Olympus:20240105T090000"
+            .parse::<RecurrenceSet>()
+            .is_err());
+    }
+
+    // Test rejecting a DTSTART before 1970
+    #[test]
+    fn test_recurrence_set_before_epoch() {
+        assert!("DTSTART:19690601T090000Z\nRRULE:FREQ=DAILY;COUNT=2"
+            .parse::<RecurrenceSet>()
+            .is_err());
+        assert!("FREQ=DAILY;UNTIL=19691231T000000Z"
+            .parse::<RecurrenceRule>()
+            .is_err());
+    }
+
+    // Test that DTSTART counts toward COUNT even when the rule does not match it
+    #[test]
+    fn test_recurrence_set_counts_dtstart() {
+        let set: RecurrenceSet = "DTSTART:20240102T090000Z\nRRULE:FREQ=WEEKLY;BYDAY=MO;COUNT=3"
+            .parse()
+            .unwrap();
+        let days: Vec<u64> = set.occurrences().map(|datetime| datetime.day).collect();
+        assert_eq!(days, [2, 8, 15]);
+
+        // The rule alone only returns the days it matches
+        let dtstart = DateTime::new(2024, 1, 2, 9, 0, 0, TimeZone::UTC).unwrap();
+        let days = expand("FREQ=WEEKLY;BYDAY=MO;COUNT=3", dtstart, 10);
+        assert_eq!(days.len(), 3);
+        assert_eq!(days[0], "2024-01-08 09:00:00");
+
+        // A matching DTSTART is counted once
+        let set: RecurrenceSet = "DTSTART:20240101T090000Z\nRRULE:FREQ=WEEKLY;BYDAY=MO;COUNT=3"
+            .parse()
+            .unwrap();
+        let days: Vec<u64> = set.occurrences().map(|datetime| datetime.day).collect();
+        assert_eq!(days, [1, 8, 15]);
+
+        // With COUNT=1 a non-matching DTSTART uses up the whole count
+        let set: RecurrenceSet = "DTSTART:20240102T090000Z\nRRULE:FREQ=WEEKLY;BYDAY=MO;COUNT=1"
+            .parse()
+            .unwrap();
+        assert_eq!(set.occurrences().count(), 1);
+
+        // Each rule counts DTSTART on its own, and an earlier RDATE does not change that
+        let set: RecurrenceSet = "DTSTART:20240102T090000Z\n\
+             RRULE:FREQ=WEEKLY;BYDAY=MO;COUNT=2\n\
+             RRULE:FREQ=WEEKLY;BYDAY=TU;COUNT=2\n\
+             RDATE:20240101T000000Z"
+            .parse()
+            .unwrap();
+        let days: Vec<u64> = set.occurrences().map(|datetime| datetime.day).collect();
+        assert_eq!(days, [1, 2, 8, 9]);
+
+        // An EXDATE on DTSTART removes it but it still counts
+        let set: RecurrenceSet = "DTSTART:20240102T090000Z\n\
+             RRULE:FREQ=WEEKLY;BYDAY=MO;COUNT=3\n\
+             EXDATE:20240102T090000Z"
+            .parse()
+            .unwrap();
+        let days: Vec<u64> = set.occurrences().map(|datetime| datetime.day).collect();
+        assert_eq!(days, [8, 15]);
+    }
+
+    // Test the RFC 5545 example of every other week on Monday, Wednesday and Friday
+    #[test]
+    fn test_rfc5545_biweekly_example() {
+        let set: RecurrenceSet = "DTSTART;TZID=America/New_York:19970902T090000\n\
+             RRULE:FREQ=WEEKLY;INTERVAL=2;UNTIL=19971224T000000Z;WKST=SU;BYDAY=MO,WE,FR"
+            .parse()
+            .unwrap();
+        let days: Vec<(u64, u64)> = set
+            .occurrences()
+            .map(|datetime| (datetime.month, datetime.day))
+            .collect();
+        assert_eq!(days.len(), 25);
+        assert_eq!(days[..4], [(9, 2), (9, 3), (9, 5), (9, 15)]);
+        assert_eq!(days[days.len() - 1], (12, 22));
+    }
+
+    // Test month-day rules that skip months without a matching day
+    #[test]
+    fn test_rrule_month_days() {
+        let dtstart = DateTime::new(2024, 1, 31, 8, 0, 0, TimeZone::UTC).unwrap();
+        let days = expand("FREQ=MONTHLY", dtstart, 3);
+        assert_eq!(
+            days,
+            [
+                "2024-01-31 08:00:00",
+                "2024-03-31 08:00:00",
+                "2024-05-31 08:00:00"
+            ]
+        );
+
+        let days = expand("FREQ=MONTHLY;BYMONTHDAY=-3;COUNT=2", dtstart, 10);
+        assert_eq!(days, ["2024-02-27 08:00:00", "2024-03-29 08:00:00"]);
+
+        // Friday the 13th
+        let dtstart = DateTime::new(2024, 1, 1, 0, 0, 0, TimeZone::UTC).unwrap();
+        let days = expand("FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13", dtstart, 3);
+        assert_eq!(
+            days,
+            [
+                "2024-09-13 00:00:00",
+                "2024-12-13 00:00:00",
+                "2025-06-13 00:00:00"
+            ]
+        );
+
+        // The third Tuesday, Wednesday or Thursday of the month
+        let days = expand(
+            "FREQ=MONTHLY;COUNT=3;BYDAY=TU,WE,TH;BYSETPOS=3",
+            dtstart,
+            10,
+        );
+        assert_eq!(
+            days,
+            [
+                "2024-01-04 00:00:00",
+                "2024-02-07 00:00:00",
+                "2024-03-07 00:00:00"
+            ]
+        );
+
+        // US presidential election day
+        let dtstart = DateTime::new(2024, 11, 5, 0, 0, 0, TimeZone::UTC).unwrap();
+        let days = expand(
+            "FREQ=YEARLY;INTERVAL=4;BYMONTH=11;BYDAY=TU;BYMONTHDAY=2,3,4,5,6,7,8",
+            dtstart,
+            2,
+        );
+        assert_eq!(days, ["2024-11-05 00:00:00", "2028-11-07 00:00:00"]);
+    }
+
+    // Test rules against occurrences computed independently with python-dateutil
+    #[test]
+    fn test_rrule_reference_expansions() {
+        let cases: &[(DateTime, &str, &[&str])] = &[
+            (
+                utc(2024, 1, 1, 0, 0),
+                "FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO",
+                &[
+                    "2024-05-13 00:00:00",
+                    "2025-05-12 00:00:00",
+                    "2026-05-11 00:00:00",
+                ],
+            ),
+            (
+                utc(2024, 1, 1, 0, 0),
+                "FREQ=YEARLY;BYYEARDAY=1,100,-1",
+                &[
+                    "2024-01-01 00:00:00",
+                    "2024-04-09 00:00:00",
+                    "2024-12-31 00:00:00",
+                    "2025-01-01 00:00:00",
+                ],
+            ),
+            (
+                utc(2024, 1, 1, 17, 0),
+                "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+                &[
+                    "2024-01-31 17:00:00",
+                    "2024-02-29 17:00:00",
+                    "2024-03-29 17:00:00",
+                    "2024-04-30 17:00:00",
+                ],
+            ),
+            (
+                utc(2024, 1, 10, 12, 0),
+                "FREQ=DAILY;INTERVAL=10;COUNT=5",
+                &[
+                    "2024-01-10 12:00:00",
+                    "2024-01-20 12:00:00",
+                    "2024-01-30 12:00:00",
+                    "2024-02-09 12:00:00",
+                    "2024-02-19 12:00:00",
+                ],
+            ),
+            (
+                utc(2024, 3, 1, 22, 0),
+                "FREQ=HOURLY;INTERVAL=3;UNTIL=20240302T100000Z",
+                &[
+                    "2024-03-01 22:00:00",
+                    "2024-03-02 01:00:00",
+                    "2024-03-02 04:00:00",
+                    "2024-03-02 07:00:00",
+                    "2024-03-02 10:00:00",
+                ],
+            ),
+            (
+                utc(2024, 1, 1, 9, 0),
+                "FREQ=MINUTELY;INTERVAL=20;BYHOUR=9,10",
+                &[
+                    "2024-01-01 09:00:00",
+                    "2024-01-01 09:20:00",
+                    "2024-01-01 09:40:00",
+                    "2024-01-01 10:00:00",
+                    "2024-01-01 10:20:00",
+                    "2024-01-01 10:40:00",
+                    "2024-01-02 09:00:00",
+                ],
+            ),
+            (
+                utc(2024, 2, 29, 0, 0),
+                "FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=29",
+                &[
+                    "2024-02-29 00:00:00",
+                    "2028-02-29 00:00:00",
+                    "2032-02-29 00:00:00",
+                ],
+            ),
+            (
+                utc(1997, 8, 5, 9, 0),
+                "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO",
+                &[
+                    "1997-08-05 09:00:00",
+                    "1997-08-10 09:00:00",
+                    "1997-08-19 09:00:00",
+                    "1997-08-24 09:00:00",
+                ],
+            ),
+            (
+                utc(1997, 8, 5, 9, 0),
+                "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU",
+                &[
+                    "1997-08-05 09:00:00",
+                    "1997-08-17 09:00:00",
+                    "1997-08-19 09:00:00",
+                    "1997-08-31 09:00:00",
+                ],
+            ),
+            (
+                utc(2024, 1, 1, 0, 0),
+                "FREQ=YEARLY;BYDAY=20MO",
+                &[
+                    "2024-05-13 00:00:00",
+                    "2025-05-19 00:00:00",
+                    "2026-05-18 00:00:00",
+                ],
+            ),
+            (
+                utc(2024, 1, 1, 0, 0),
+                "FREQ=YEARLY;BYMONTH=3;BYDAY=TH",
+                &[
+                    "2024-03-07 00:00:00",
+                    "2024-03-14 00:00:00",
+                    "2024-03-21 00:00:00",
+                    "2024-03-28 00:00:00",
+                    "2025-03-06 00:00:00",
+                    "2025-03-13 00:00:00",
+                ],
+            ),
+            (
+                utc(2024, 1, 1, 0, 0),
+                "FREQ=MONTHLY;BYMONTHDAY=1,-1",
+                &[
+                    "2024-01-01 00:00:00",
+                    "2024-01-31 00:00:00",
+                    "2024-02-01 00:00:00",
+                    "2024-02-29 00:00:00",
+                    "2024-03-01 00:00:00",
+                ],
+            ),
+            (
+                utc(2024, 1, 1, 0, 0),
+                "FREQ=YEARLY;BYMONTH=1,7;BYDAY=-1SU",
+                &[
+                    "2024-01-28 00:00:00",
+                    "2024-07-28 00:00:00",
+                    "2025-01-26 00:00:00",
+                    "2025-07-27 00:00:00",
+                ],
+            ),
+            (
+                utc(2024, 1, 5, 0, 0),
+                "FREQ=DAILY;BYMONTH=1;BYDAY=SA,SU;BYHOUR=8,20",
+                &[
+                    "2024-01-06 08:00:00",
+                    "2024-01-06 20:00:00",
+                    "2024-01-07 08:00:00",
+                    "2024-01-07 20:00:00",
+                    "2024-01-13 08:00:00",
+                ],
+            ),
+            (
+                utc(2020, 1, 1, 0, 0),
+                "FREQ=YEARLY;BYWEEKNO=53;BYDAY=TH",
+                &[
+                    "2020-12-31 00:00:00",
+                    "2026-12-31 00:00:00",
+                    "2032-12-30 00:00:00",
+                ],
+            ),
+        ];
+        for &(dtstart, rule, expected) in cases {
+            assert_eq!(expand(rule, dtstart, expected.len()), expected, "{}", rule);
+        }
+    }
+}
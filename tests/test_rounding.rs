@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{DateTime, TimeDelta, TimeUnit, TimeZone};
+
+    // Test truncation to each unit, relative to the local wall clock
+    #[test]
+    fn test_trunc() {
+        let datetime = DateTime::new(2024, 5, 17, 7, 45, 12, TimeZone::KST).unwrap();
+        let expected = [
+            (TimeUnit::Second, "2024-05-17 07:45:12"),
+            (TimeUnit::Minute, "2024-05-17 07:45:00"),
+            (TimeUnit::Hour, "2024-05-17 07:00:00"),
+            (TimeUnit::Day, "2024-05-17 00:00:00"),
+            (TimeUnit::Week, "2024-05-13 00:00:00"),
+            (TimeUnit::Month, "2024-05-01 00:00:00"),
+            (TimeUnit::Year, "2024-01-01 00:00:00"),
+        ];
+        for (unit, text) in expected {
+            let truncated = datetime.trunc(unit).unwrap();
+            assert_eq!(truncated.to_string(), text, "{:?}", unit);
+            assert_eq!(truncated.timezone, TimeZone::KST);
+        }
+
+        // 07:45 KST is still the previous day in UTC
+        let day_kst = datetime.trunc(TimeUnit::Day).unwrap();
+        assert_eq!(
+            day_kst.to_unix_seconds(),
+            DateTime::new(2024, 5, 16, 15, 0, 0, TimeZone::UTC)
+                .unwrap()
+                .to_unix_seconds()
+        );
+    }
+
+    // Test rounding to the nearest unit boundary
+    #[test]
+    fn test_round() {
+        let datetime = DateTime::new(2024, 12, 31, 11, 59, 30, TimeZone::UTC).unwrap();
+        assert_eq!(
+            datetime.round(TimeUnit::Minute).unwrap().to_string(),
+            "2024-12-31 12:00:00"
+        );
+        assert_eq!(
+            datetime.round(TimeUnit::Hour).unwrap().to_string(),
+            "2024-12-31 12:00:00"
+        );
+        assert_eq!(
+            datetime.round(TimeUnit::Day).unwrap().to_string(),
+            "2024-12-31 00:00:00"
+        );
+        assert_eq!(
+            datetime.round(TimeUnit::Month).unwrap().to_string(),
+            "2025-01-01 00:00:00"
+        );
+        assert_eq!(
+            datetime.round(TimeUnit::Year).unwrap().to_string(),
+            "2025-01-01 00:00:00"
+        );
+
+        let datetime = DateTime::new(2024, 5, 17, 12, 0, 0, TimeZone::EST).unwrap();
+        assert_eq!(
+            datetime.round(TimeUnit::Day).unwrap().to_string(),
+            "2024-05-18 00:00:00"
+        );
+    }
+
+    // Test floor_to and ceil_to with 15-minute buckets in a half-hour offset zone
+    #[test]
+    fn test_floor_and_ceil_to() {
+        let quarter = TimeDelta {
+            minutes: 15,
+            ..Default::default()
+        };
+        let datetime = DateTime::new(2024, 5, 17, 7, 44, 59, TimeZone::IST).unwrap();
+        assert_eq!(
+            datetime.floor_to(quarter).unwrap().to_string(),
+            "2024-05-17 07:30:00"
+        );
+        assert_eq!(
+            datetime.ceil_to(quarter).unwrap().to_string(),
+            "2024-05-17 07:45:00"
+        );
+
+        let aligned = DateTime::new(2024, 5, 17, 7, 45, 0, TimeZone::IST).unwrap();
+        assert_eq!(aligned.floor_to(quarter).unwrap(), aligned);
+        assert_eq!(aligned.ceil_to(quarter).unwrap(), aligned);
+
+        let six_hours = TimeDelta {
+            hours: 6,
+            ..Default::default()
+        };
+        let datetime = DateTime::new(2024, 5, 17, 23, 0, 0, TimeZone::KST).unwrap();
+        assert_eq!(
+            datetime.floor_to(six_hours).unwrap().to_string(),
+            "2024-05-17 18:00:00"
+        );
+        assert_eq!(
+            datetime.ceil_to(six_hours).unwrap().to_string(),
+            "2024-05-18 00:00:00"
+        );
+
+        assert!(datetime.floor_to(TimeDelta::default()).is_err());
+        assert!(datetime
+            .ceil_to(TimeDelta {
+                hours: -1,
+                ..Default::default()
+            })
+            .is_err());
+    }
+}
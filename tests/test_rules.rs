@@ -0,0 +1,217 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::date;
+    use timekit::{
+        Date, HolidayDate, HolidayProvider, HolidayRule, HolidayRules, Observance, Weekday,
+    };
+
+    // Test building rules in code and resolving them for a year
+    #[test]
+    fn test_rule_builder() {
+        let thanksgiving =
+            HolidayRule::nth_weekday("Thanksgiving", 11, Weekday::Thursday, 4).unwrap();
+        assert_eq!(
+            thanksgiving.holiday(2024).unwrap().unwrap().date,
+            date(2024, 11, 28)
+        );
+
+        let pentecost = HolidayRule::easter("Whit Monday", 50);
+        assert_eq!(
+            pentecost.holiday(2024).unwrap().unwrap().date,
+            date(2024, 5, 20)
+        );
+
+        // Boxing Day on a Saturday moves to Monday the 28th
+        let boxing = HolidayRule::fixed("Boxing Day", 12, 26)
+            .unwrap()
+            .observe(Observance::NextMonday);
+        assert_eq!(
+            boxing.holiday(2026).unwrap().unwrap().date,
+            date(2026, 12, 28)
+        );
+
+        let veterans = HolidayRule::nth_weekday("Veterans Day", 10, Weekday::Monday, 4)
+            .unwrap()
+            .from_year(1971)
+            .until_year(1977);
+        assert!(veterans.applies_in(1977));
+        assert_eq!(veterans.holiday(1978).unwrap(), None);
+
+        let leap_day = HolidayRule::fixed("Leap Day", 2, 29).unwrap();
+        assert_eq!(leap_day.holiday(2023).unwrap(), None);
+        assert!(HolidayRule::fixed("Invalid", 2, 30).is_err());
+        assert!(HolidayRule::nth_weekday("Invalid", 5, Weekday::Monday, 6).is_err());
+    }
+
+    // Test parsing and formatting rule lines
+    #[test]
+    fn test_rule_parse() {
+        let rule: HolidayRule = "Spring Bank Holiday = LAST monday OF may".parse().unwrap();
+        assert_eq!(
+            rule.date,
+            HolidayDate::NthWeekday {
+                month: 5,
+                weekday: Weekday::Monday,
+                n: -1
+            }
+        );
+        for line in [
+            "New Year's Day = 01-01 observe nearest-weekday",
+            "Labour Day = 05-01 from 1890",
+            "Thanksgiving = 4th Thursday of November",
+            "Second to last = 2nd last Friday of March",
+            "Good Friday = easter -2",
+            "Ascension Day = easter +39",
+            "Easter Sunday = easter",
+            "Veterans Day = 4th Monday of October from 1971 until 1977",
+        ] {
+            assert_eq!(line.parse::<HolidayRule>().unwrap().to_string(), line);
+        }
+        let rule: HolidayRule = "Labour Day = May 1".parse().unwrap();
+        assert_eq!(rule.to_string(), "Labour Day = 05-01");
+        let rule: HolidayRule = "First = first Monday of Sep observe none".parse().unwrap();
+        assert_eq!(rule.to_string(), "First = 1st Monday of September");
+
+        for invalid in [
+            "No date",
+            " = 01-01",
+            "Bad = 13-01",
+            "Bad = easter soon",
+            "Bad = 6th Monday of May",
+            "Bad = last Funday of May",
+            "Bad = 01-01 observe sometimes",
+            "Bad = 01-01 from",
+            "Bad = 01-01 from next year",
+        ] {
+            assert!(invalid.parse::<HolidayRule>().is_err(), "{}", invalid);
+        }
+    }
+
+    // Test a calendar of rules, including shifts across a year boundary
+    #[test]
+    fn test_holiday_rules() {
+        let rules: HolidayRules = "
+            # US-style observance
+            New Year's Day = 01-01 observe nearest-weekday
+            Independence Day = 07-04 observe nearest-weekday
+            Christmas Day = 12-25 observe nearest-weekday
+        "
+        .parse()
+        .unwrap();
+        assert_eq!(rules.rules().len(), 3);
+        // 2022-01-01 was a Saturday, observed on 2021-12-31
+        let dates: Vec<Date> = rules
+            .holidays(2021)
+            .unwrap()
+            .into_iter()
+            .map(|holiday| holiday.date)
+            .collect();
+        assert_eq!(
+            dates,
+            [
+                date(2021, 1, 1),
+                date(2021, 7, 5),
+                date(2021, 12, 24),
+                date(2021, 12, 31)
+            ]
+        );
+        assert_eq!(rules.holidays(2022).unwrap().len(), 2);
+
+        let error = "Fine = 01-01\n\nBroken = 02-31".parse::<HolidayRules>();
+        assert!(error.unwrap_err().starts_with("Line 3:"));
+    }
+
+    // Test loading rules from a file
+    #[test]
+    fn test_load() {
+        let rules = HolidayRules::new()
+            .with_rule(HolidayRule::fixed("Christmas Day", 12, 25).unwrap())
+            .with_rule(HolidayRule::easter("Easter Monday", 1));
+        let path = std::env::temp_dir().join("timekit_test_holiday_rules.txt");
+        std::fs::write(&path, rules.to_string()).unwrap();
+        let loaded = HolidayRules::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, rules);
+        assert!(HolidayRules::load("/nonexistent/holidays.txt").is_err());
+    }
+
+    // Test that shifted holidays skip days taken by other rules, as UK bank holidays do
+    #[test]
+    fn test_observance_collisions() {
+        let rules: HolidayRules = "
+            Christmas Day = 12-25 observe next-monday
+            Boxing Day = 12-26 observe next-monday
+        "
+        .parse()
+        .unwrap();
+        let observed = |year| -> Vec<(Date, String)> {
+            rules
+                .holidays(year)
+                .unwrap()
+                .into_iter()
+                .map(|holiday| (holiday.date, holiday.name))
+                .collect()
+        };
+        // Saturday and Sunday: Monday and Tuesday
+        assert_eq!(
+            observed(2021),
+            [
+                (date(2021, 12, 27), "Christmas Day".to_string()),
+                (date(2021, 12, 28), "Boxing Day".to_string())
+            ]
+        );
+        // Sunday and Monday: Boxing Day keeps its date and Christmas moves to Tuesday
+        assert_eq!(
+            observed(2022),
+            [
+                (date(2022, 12, 26), "Boxing Day".to_string()),
+                (date(2022, 12, 27), "Christmas Day".to_string())
+            ]
+        );
+        // Friday and Saturday: only Boxing Day moves
+        assert_eq!(
+            observed(2020),
+            [
+                (date(2020, 12, 25), "Christmas Day".to_string()),
+                (date(2020, 12, 28), "Boxing Day".to_string())
+            ]
+        );
+
+        // A shift back to Friday moves further back when Friday is taken
+        let rules = HolidayRules::new()
+            .with_rule(HolidayRule::fixed("Friday", 7, 3).unwrap())
+            .with_rule(
+                HolidayRule::fixed("Saturday", 7, 4)
+                    .unwrap()
+                    .observe(Observance::NearestWeekday),
+            );
+        let dates: Vec<Date> = rules
+            .holidays(2026)
+            .unwrap()
+            .into_iter()
+            .map(|holiday| holiday.date)
+            .collect();
+        assert_eq!(dates, [date(2026, 7, 2), date(2026, 7, 3)]);
+
+        // A rule on its own does not know about the others
+        let saturday = &rules.rules()[1];
+        assert_eq!(
+            saturday.holiday(2026).unwrap().unwrap().date,
+            date(2026, 7, 3)
+        );
+    }
+
+    // Test that neighbouring years outside a rule's range are skipped
+    #[test]
+    fn test_neighbouring_years() {
+        let rules = HolidayRules::new()
+            .with_rule(HolidayRule::easter("Good Friday", -2))
+            .with_rule(HolidayRule::fixed("New Year's Day", 1, 1).unwrap());
+        let holidays = rules.holidays(1583).unwrap();
+        assert_eq!(holidays.len(), 2);
+        assert_eq!(holidays[1].date, date(1583, 4, 8));
+        assert!(rules.holidays(1582).is_err());
+    }
+}
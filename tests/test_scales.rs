@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{DateTime, GpsTime, TimeZone};
+
+    // Test TAI and TT offsets before and after the 2016 leap second
+    #[test]
+    fn test_tai_and_tt() {
+        let datetime = DateTime::new(2016, 12, 31, 0, 0, 0, TimeZone::UTC).unwrap();
+        assert_eq!(datetime.to_tai_seconds(), datetime.to_unix_seconds() + 36);
+        assert_eq!(
+            datetime.to_tt_seconds(),
+            (datetime.to_unix_seconds() + 36) as f64 + 32.184
+        );
+
+        let datetime = DateTime::new(2024, 1, 1, 9, 0, 0, TimeZone::KST).unwrap();
+        assert_eq!(datetime.to_tai_seconds(), datetime.to_unix_seconds() + 37);
+        assert_eq!(
+            DateTime::from_tai_seconds(datetime.to_tai_seconds(), TimeZone::KST).unwrap(),
+            datetime
+        );
+        assert_eq!(
+            DateTime::from_tt_seconds(datetime.to_tt_seconds(), TimeZone::KST).unwrap(),
+            datetime
+        );
+    }
+
+    // Test GPS week and time of week, and the inverse conversion
+    #[test]
+    fn test_gps_time() {
+        let epoch = DateTime::new(1980, 1, 6, 0, 0, 0, TimeZone::UTC).unwrap();
+        assert_eq!(
+            epoch.to_gps_time().unwrap(),
+            GpsTime {
+                week: 0,
+                time_of_week: 0.0
+            }
+        );
+
+        let datetime = DateTime::new(2024, 1, 10, 12, 30, 0, TimeZone::UTC).unwrap();
+        let gps = datetime.to_gps_time().unwrap();
+        assert_eq!(gps.week, 2296);
+        assert_eq!(
+            gps.time_of_week,
+            (3 * 86400 + 12 * 3600 + 30 * 60 + 18) as f64
+        );
+        assert_eq!(
+            DateTime::from_gps_time(gps, TimeZone::UTC).unwrap(),
+            datetime
+        );
+
+        let before_epoch = DateTime::new(1980, 1, 5, 23, 59, 59, TimeZone::UTC).unwrap();
+        assert!(before_epoch.to_gps_time().is_err());
+    }
+
+    // Test Julian Date and Modified Julian Date conversions
+    #[test]
+    fn test_julian_dates() {
+        let epoch = DateTime::new(1970, 1, 1, 0, 0, 0, TimeZone::UTC).unwrap();
+        assert_eq!(epoch.to_julian_date(), 2_440_587.5);
+        assert_eq!(epoch.to_modified_julian_date(), 40_587.0);
+
+        let datetime = DateTime::new(2024, 3, 1, 6, 0, 0, TimeZone::UTC).unwrap();
+        assert_eq!(datetime.to_modified_julian_date(), 60_370.25);
+        assert_eq!(
+            DateTime::from_modified_julian_date(60_370.25, TimeZone::UTC).unwrap(),
+            datetime
+        );
+
+        let datetime = DateTime::new(2024, 3, 1, 15, 17, 43, TimeZone::KST).unwrap();
+        let julian_date = datetime.to_julian_date();
+        assert_eq!(
+            DateTime::from_julian_date(julian_date, TimeZone::KST).unwrap(),
+            datetime
+        );
+        assert!(DateTime::from_julian_date(f64::NAN, TimeZone::UTC).is_err());
+    }
+}
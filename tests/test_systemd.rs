@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{CalendarEvent, DateTime, TimeDelta, TimeZone};
+
+    fn next(event: &str, after: DateTime) -> String {
+        event
+            .parse::<CalendarEvent>()
+            .unwrap()
+            .next_elapse(&after)
+            .unwrap()
+            .to_string()
+    }
+
+    // Test parsing calendar events into their normalized form
+    #[test]
+    fn test_calendar_event_parse() {
+        let normalized = |event: &str| event.parse::<CalendarEvent>().unwrap().to_string();
+        assert_eq!(
+            normalized("Mon..Fri *-*-* 09:00:00 Asia/Seoul"),
+            "Mon..Fri *-*-* 09:00:00 Asia/Seoul"
+        );
+        assert_eq!(normalized("sat,sunday 9:30"), "Sat,Sun *-*-* 09:30:00");
+        assert_eq!(
+            normalized("Mon-Wed,Fri 2024-*-1"),
+            "Mon..Wed,Fri 2024-*-01 00:00:00"
+        );
+        assert_eq!(normalized("*:0/15"), "*-*-* *:00/15:00");
+        assert_eq!(normalized("12-25 8,18:00"), "*-12-25 08,18:00:00");
+        assert_eq!(normalized("*-02~01"), "*-02~01 00:00:00");
+        assert_eq!(normalized("daily"), "*-*-* 00:00:00");
+        assert_eq!(normalized("weekly UTC"), "Mon *-*-* 00:00:00 UTC");
+        assert_eq!(normalized("quarterly"), "*-01,04,07,10-01 00:00:00");
+
+        let event: CalendarEvent = "hourly Asia/Tokyo".parse().unwrap();
+        assert_eq!(event.timezone(), Some(TimeZone::JST));
+
+        for invalid in [
+            "",
+            "Funday *-*-*",
+            "*-13-01",
+            "*-*-32",
+            "24:00",
+            "*:0/0",
+            "10..5:00",
+            "*-*-* 09:00:00 Mars/Olympus",
+            "*-*-* 09:00 extra tokens",
+        ] {
+            assert!(invalid.parse::<CalendarEvent>().is_err(), "{}", invalid);
+        }
+    }
+
+    // Test computing the next elapse in the event's zone or the caller's zone
+    #[test]
+    fn test_calendar_event_next_elapse() {
+        let saturday = DateTime::new(2024, 5, 18, 12, 0, 0, TimeZone::UTC).unwrap();
+        assert_eq!(
+            next("Mon..Fri *-*-* 09:00:00 Asia/Seoul", saturday),
+            "2024-05-20 09:00:00"
+        );
+
+        // Without a zone, the event follows the wall clock of the argument
+        let kst = DateTime::new(2024, 5, 18, 8, 0, 0, TimeZone::KST).unwrap();
+        let elapse = "*-*-* 09:00"
+            .parse::<CalendarEvent>()
+            .unwrap()
+            .next_elapse(&kst)
+            .unwrap();
+        assert_eq!(elapse.to_string(), "2024-05-18 09:00:00");
+        assert_eq!(elapse.timezone, TimeZone::KST);
+
+        // next_elapse is strict
+        let nine = DateTime::new(2024, 5, 18, 9, 0, 0, TimeZone::UTC).unwrap();
+        assert_eq!(next("*-*-* 09:00", nine), "2024-05-19 09:00:00");
+
+        assert_eq!(next("*:0/15", nine), "2024-05-18 09:15:00");
+        assert_eq!(next("*-02~01", nine), "2025-02-28 00:00:00");
+        // The last Monday of May
+        assert_eq!(next("Mon *-05~07/1", nine), "2024-05-27 00:00:00");
+        assert_eq!(next("2028-02-29", nine), "2028-02-29 00:00:00");
+        let past: CalendarEvent = "2024-02-29".parse().unwrap();
+        assert_eq!(past.next_elapse(&nine), None);
+    }
+
+    // Test parsing systemd time spans
+    #[test]
+    fn test_parse_timespan() {
+        let seconds = |span: &str| TimeDelta::parse_timespan(span).unwrap().total_seconds();
+        assert_eq!(seconds("1h 30min"), 5400);
+        assert_eq!(seconds("1h30min"), 5400);
+        assert_eq!(seconds("2weeks"), 14 * 86_400);
+        assert_eq!(seconds("1.5d"), 129_600);
+        assert_eq!(seconds("90"), 90);
+        assert_eq!(seconds("1y"), 31_557_600);
+        assert_eq!(seconds("1 month"), 2_629_800);
+        assert_eq!(seconds("500ms 500ms"), 1);
+        assert_eq!(seconds("2000000us"), 2);
+        assert_eq!(
+            TimeDelta::parse_timespan("1d 2h").unwrap(),
+            TimeDelta {
+                days: 1,
+                hours: 2,
+                ..Default::default()
+            }
+        );
+
+        // Parts of a second are kept
+        let nanoseconds = |span: &str| TimeDelta::parse_timespan(span).unwrap().total_nanoseconds();
+        assert_eq!(nanoseconds("500ms"), 500_000_000);
+        assert_eq!(nanoseconds("1.5s"), 1_500_000_000);
+        assert_eq!(nanoseconds("100ms"), 100_000_000);
+        assert_eq!(nanoseconds("2s 250msec"), 2_250_000_000);
+        assert_eq!(nanoseconds("1.5 us"), 1_500);
+        assert_eq!(nanoseconds(".25min"), 15_000_000_000);
+        assert_eq!(seconds("2.9s"), 2);
+
+        for invalid in [
+            "",
+            "h",
+            "1x",
+            "1.2.3s",
+            "-1h",
+            "1..5h",
+            "1h,30min",
+            "1 fortnight",
+        ] {
+            assert!(TimeDelta::parse_timespan(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use timekit::{DateTime, TimeZone, Weekday};
+
+    // Test weekday computation on the local wall clock
+    #[test]
+    fn test_datetime_weekday() {
+        let epoch = DateTime::new(1970, 1, 1, 0, 0, 0, TimeZone::UTC).unwrap();
+        assert_eq!(epoch.weekday(), Weekday::Thursday);
+
+        // Friday 00:30 in Seoul is still Thursday in UTC
+        let kst = DateTime::new(2024, 5, 17, 0, 30, 0, TimeZone::KST).unwrap();
+        assert_eq!(kst.weekday(), Weekday::Friday);
+        let utc = DateTime::from_unix_seconds(kst.to_unix_seconds(), TimeZone::UTC).unwrap();
+        assert_eq!(utc.weekday(), Weekday::Thursday);
+
+        let leap = DateTime::new(2016, 12, 31, 23, 59, 60, TimeZone::UTC).unwrap();
+        assert_eq!(leap.weekday(), Weekday::Saturday);
+    }
+
+    // Test weekday numbering and navigation
+    #[test]
+    fn test_weekday_numbers() {
+        assert_eq!(Weekday::Monday.number_from_monday(), 1);
+        assert_eq!(Weekday::Sunday.number_from_monday(), 7);
+        assert_eq!(Weekday::Sunday.number_from_sunday(), 0);
+        assert_eq!(Weekday::Saturday.number_from_sunday(), 6);
+        assert_eq!(
+            Weekday::from_number_from_monday(3),
+            Some(Weekday::Wednesday)
+        );
+        assert_eq!(Weekday::from_number_from_monday(0), None);
+        assert_eq!(Weekday::Sunday.succ(), Weekday::Monday);
+        assert_eq!(Weekday::Monday.pred(), Weekday::Sunday);
+        assert_eq!(Weekday::Monday.days_since(Weekday::Sunday), 1);
+        assert_eq!(Weekday::all().count(), 7);
+    }
+
+    // Test parsing and displaying weekday names
+    #[test]
+    fn test_weekday_from_str() {
+        assert_eq!("Monday".parse::<Weekday>(), Ok(Weekday::Monday));
+        assert_eq!("fri".parse::<Weekday>(), Ok(Weekday::Friday));
+        assert_eq!("SUNDAY".parse::<Weekday>(), Ok(Weekday::Sunday));
+        assert!("Mo".parse::<Weekday>().is_err());
+        assert!("Funday".parse::<Weekday>().is_err());
+        assert_eq!(Weekday::Wednesday.to_string(), "Wednesday");
+    }
+}